@@ -31,6 +31,7 @@ use std::rc::Rc;
 use gpui::prelude::*;
 use gpui::{div, px, AnyElement, App, ClickEvent, Div, ElementId, IntoElement, SharedString, Stateful, Window};
 
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 
 /// Selection mode for the accordion.
@@ -155,7 +156,7 @@ impl ParentElement for AccordionItem {
 
 impl RenderOnce for AccordionItem {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let border_color = theme.colors.border;
 
         div()
@@ -213,7 +214,7 @@ impl ParentElement for AccordionTrigger {
 
 impl RenderOnce for AccordionTrigger {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let muted_fg = theme.colors.muted_foreground;
 
         let mut el = div()
@@ -222,14 +223,18 @@ impl RenderOnce for AccordionTrigger {
             .flex_row()
             .items_center()
             .justify_between()
-            .py(px(16.0))
+            .py(px(theme.spacing.lg))
+            .text_size(px(theme.typography.sm))
             .cursor_pointer()
             .hover(move |style| style.text_color(muted_fg))
             .children(self.children)
             .child(
-                div()
-                    .text_sm()
-                    .child(if self.open { "\u{25B2}" } else { "\u{25BC}" }),
+                Icon::new(if self.open {
+                    IconName::ChevronUp
+                } else {
+                    IconName::ChevronDown
+                })
+                .color(muted_fg),
             );
 
         if let Some(on_click) = self.on_click {
@@ -277,9 +282,12 @@ impl ParentElement for AccordionContent {
 }
 
 impl RenderOnce for AccordionContent {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div().when(self.active, |el: Div| {
-            el.pb(px(16.0)).children(self.children)
+            el.pb(px(theme.spacing.lg))
+                .text_size(px(theme.typography.sm))
+                .children(self.children)
         })
     }
 }
@@ -3,15 +3,21 @@
 //! A pure layout component for displaying callout messages with optional
 //! destructive styling.
 //!
+//! Colors can be overridden per variant through theme slots named
+//! `alert.<variant>.<part>`, where part is `bg`, `fg` or `border`, e.g.
+//! `alert.destructive.border`.
+//!
 //! # Example
 //!
 //! ```rust
 //! Alert::new()
 //!     .variant(AlertVariant::Destructive)
+//!     .icon(IconName::CircleAlert)
 //!     .child(AlertTitle::new("Error"))
 //!     .child(AlertDescription::new("Your session has expired."))
 //! ```
 
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 use gpui::{
     div, prelude::*, AnyElement, App, FontWeight, IntoElement, ParentElement, RenderOnce,
@@ -28,6 +34,16 @@ pub enum AlertVariant {
     Destructive,
 }
 
+impl AlertVariant {
+    /// The variant's name in theme slots, e.g. `destructive`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            AlertVariant::Default => "default",
+            AlertVariant::Destructive => "destructive",
+        }
+    }
+}
+
 /// Alert container component.
 ///
 /// Displays a themed container with border, rounded corners, and padding.
@@ -35,6 +51,7 @@ pub enum AlertVariant {
 #[derive(IntoElement)]
 pub struct Alert {
     variant: AlertVariant,
+    icon: Option<IconName>,
     children: Vec<AnyElement>,
 }
 
@@ -43,6 +60,7 @@ impl Alert {
     pub fn new() -> Self {
         Self {
             variant: AlertVariant::Default,
+            icon: None,
             children: Vec::new(),
         }
     }
@@ -52,6 +70,12 @@ impl Alert {
         self.variant = variant;
         self
     }
+
+    /// Show an icon to the left of the content, in the alert's text color.
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
 }
 
 impl ParentElement for Alert {
@@ -62,7 +86,7 @@ impl ParentElement for Alert {
 
 impl RenderOnce for Alert {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let (bg, text, border) = match self.variant {
@@ -72,17 +96,37 @@ impl RenderOnce for Alert {
             }
         };
 
-        div()
+        // Let the theme override individual colors, e.g. `alert.destructive.border`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: gpui::Hsla| {
+            theme.slot(&format!("alert.{}.{}", variant, part), fallback)
+        };
+        let (bg, text, border) = (slot("bg", bg), slot("fg", text), slot("border", border));
+
+        let el = div()
             .flex()
-            .flex_col()
-            .gap(gpui::px(4.0))
             .rounded_lg()
             .border_1()
             .border_color(border)
             .bg(bg)
             .text_color(text)
-            .p(gpui::px(16.0))
-            .children(self.children)
+            .p(gpui::px(theme.spacing.lg));
+
+        match self.icon {
+            Some(icon) => el
+                .flex_row()
+                .gap(gpui::px(theme.spacing.md))
+                .child(Icon::new(icon).color(text))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .flex_1()
+                        .gap(gpui::px(theme.spacing.xs))
+                        .children(self.children),
+                ),
+            None => el.flex_col().gap(gpui::px(theme.spacing.xs)).children(self.children),
+        }
     }
 }
 
@@ -102,9 +146,10 @@ impl AlertTitle {
 }
 
 impl RenderOnce for AlertTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.25))
             .child(self.text)
@@ -127,8 +172,9 @@ impl AlertDescription {
 }
 
 impl RenderOnce for AlertDescription {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        div().text_sm().child(self.text)
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
+        div().text_size(gpui::px(theme.typography.sm)).child(self.text)
     }
 }
 
@@ -140,9 +186,16 @@ mod tests {
     fn test_alert_defaults() {
         let alert = Alert::new();
         assert_eq!(alert.variant, AlertVariant::Default);
+        assert!(alert.icon.is_none());
         assert!(alert.children.is_empty());
     }
 
+    #[test]
+    fn test_alert_icon() {
+        let alert = Alert::new().icon(IconName::CircleAlert);
+        assert_eq!(alert.icon, Some(IconName::CircleAlert));
+    }
+
     #[test]
     fn test_alert_destructive() {
         let alert = Alert::new().variant(AlertVariant::Destructive);
@@ -170,21 +170,21 @@ impl ParentElement for AlertDialogContent {
 
 impl RenderOnce for AlertDialogContent {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .id("alert-dialog-content")
             .flex()
             .flex_col()
-            .gap(gpui::px(16.0))
+            .gap(gpui::px(theme.spacing.lg))
             .bg(colors.popover)
             .text_color(colors.popover_foreground)
             .border_1()
             .border_color(colors.border)
             .rounded_lg()
-            .shadow_lg()
-            .p(gpui::px(24.0))
+            .shadow(theme.shadows.lg.to_box_shadows())
+            .p(gpui::px(theme.spacing.xl))
             .max_w(gpui::px(512.0))
             .w_full()
             // Prevent click-through to backdrop
@@ -217,11 +217,12 @@ impl ParentElement for AlertDialogHeader {
 }
 
 impl RenderOnce for AlertDialogHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_col()
-            .gap(gpui::px(6.0))
+            .gap(gpui::px((theme.spacing.xs + theme.spacing.sm) / 2.0))
             .children(self.children)
     }
 }
@@ -242,9 +243,10 @@ impl AlertDialogTitle {
 }
 
 impl RenderOnce for AlertDialogTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_lg()
+            .text_size(gpui::px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .child(self.text)
@@ -268,10 +270,10 @@ impl AlertDialogDescription {
 
 impl RenderOnce for AlertDialogDescription {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -301,12 +303,13 @@ impl ParentElement for AlertDialogFooter {
 }
 
 impl RenderOnce for AlertDialogFooter {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_row()
             .justify_end()
-            .gap(gpui::px(8.0))
+            .gap(gpui::px(theme.spacing.sm))
             .children(self.children)
     }
 }
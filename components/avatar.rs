@@ -53,7 +53,7 @@ impl Avatar {
 
 impl RenderOnce for Avatar {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let (dimension, text_size) = match self.size {
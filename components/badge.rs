@@ -2,6 +2,10 @@
 //!
 //! An inline status label with multiple visual variants.
 //!
+//! Colors can be overridden per variant through theme slots named
+//! `badge.<variant>.<part>`, where part is `bg`, `fg` or `border`, e.g.
+//! `badge.outline.border`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -10,7 +14,7 @@
 //! ```
 
 use gpui::prelude::*;
-use gpui::{div, px, rems, App, FontWeight, IntoElement, SharedString, Window};
+use gpui::{div, px, App, FontWeight, IntoElement, SharedString, Window};
 
 use crate::theme::Theme;
 
@@ -28,6 +32,18 @@ pub enum BadgeVariant {
     Destructive,
 }
 
+impl BadgeVariant {
+    /// The variant's name in theme slots, e.g. `outline`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            BadgeVariant::Default => "default",
+            BadgeVariant::Secondary => "secondary",
+            BadgeVariant::Outline => "outline",
+            BadgeVariant::Destructive => "destructive",
+        }
+    }
+}
+
 /// An inline badge component for displaying short status labels.
 ///
 /// Renders as a pill-shaped container with themed colors based on the variant.
@@ -55,7 +71,7 @@ impl Badge {
 
 impl RenderOnce for Badge {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let (bg, fg, has_border) = match self.variant {
@@ -76,19 +92,28 @@ impl RenderOnce for Badge {
             }
         };
 
+        // Let the theme override individual colors, e.g. `badge.outline.border`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: gpui::Hsla| {
+            theme.slot(&format!("badge.{}.{}", variant, part), fallback)
+        };
+        let (bg, fg, border) = (slot("bg", bg), slot("fg", fg), slot("border", colors.border));
+
+        // Padding and text size follow the theme's density and type scale
+        let spacing = &theme.spacing;
         let mut el = div()
             .flex()
             .items_center()
             .rounded_full()
-            .px(px(10.0))
-            .py(px(2.0))
-            .text_size(rems(0.75))
+            .px(px((spacing.sm + spacing.md) / 2.0))
+            .py(px(spacing.xs / 2.0))
+            .text_size(px(theme.typography.xs))
             .font_weight(FontWeight::SEMIBOLD)
             .bg(bg)
             .text_color(fg);
 
         if has_border {
-            el = el.border_1().border_color(colors.border);
+            el = el.border_1().border_color(border);
         }
 
         el.child(self.label)
@@ -118,6 +143,12 @@ mod tests {
         assert_eq!(badge.variant, BadgeVariant::Destructive);
     }
 
+    #[test]
+    fn test_variant_slot_names() {
+        assert_eq!(BadgeVariant::Default.slot_name(), "default");
+        assert_eq!(BadgeVariant::Outline.slot_name(), "outline");
+    }
+
     #[test]
     fn test_badge_builder() {
         let badge = Badge::new("Status").variant(BadgeVariant::Destructive);
@@ -50,13 +50,13 @@ impl BreadcrumbItem {
 
 impl RenderOnce for BreadcrumbItem {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         if let Some(on_click) = self.on_click {
             div()
                 .id("breadcrumb-item")
-                .text_sm()
+                .text_size(px(theme.typography.sm))
                 .text_color(colors.muted_foreground)
                 .cursor_pointer()
                 .hover(|style: gpui::StyleRefinement| style.underline())
@@ -65,7 +65,7 @@ impl RenderOnce for BreadcrumbItem {
                 .into_any_element()
         } else {
             div()
-                .text_sm()
+                .text_size(px(theme.typography.sm))
                 .text_color(colors.foreground)
                 .child(self.label)
                 .into_any_element()
@@ -79,10 +79,10 @@ pub struct BreadcrumbSeparator;
 
 impl RenderOnce for BreadcrumbSeparator {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child("/")
     }
@@ -112,12 +112,13 @@ impl ParentElement for Breadcrumb {
 }
 
 impl RenderOnce for Breadcrumb {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_row()
             .items_center()
-            .gap(px(4.0))
+            .gap(px(theme.spacing.xs))
             .children(self.children)
     }
 }
@@ -2,6 +2,11 @@
 //!
 //! A versatile button component with multiple variants and sizes.
 //!
+//! Colors can be overridden per variant through theme slots named
+//! `button.<variant>.<part>`, where part is `bg`, `fg`, `border`, `hover_bg`,
+//! `hover_fg` or `active_bg`, e.g. `button.destructive.bg`. Hover, pressed,
+//! focus and disabled styling follow the theme's `states` tokens.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -15,7 +20,7 @@
 
 use gpui::prelude::*;
 use gpui::{
-    div, px, rems, App, ClickEvent, Div, ElementId, FontWeight, Hsla, IntoElement, SharedString,
+    div, px, App, ClickEvent, Div, ElementId, FontWeight, Hsla, IntoElement, SharedString,
     Stateful, Window,
 };
 
@@ -33,6 +38,20 @@ pub enum ButtonVariant {
     Destructive,
 }
 
+impl ButtonVariant {
+    /// The variant's name in theme slots, e.g. `destructive`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            ButtonVariant::Default => "default",
+            ButtonVariant::Secondary => "secondary",
+            ButtonVariant::Outline => "outline",
+            ButtonVariant::Ghost => "ghost",
+            ButtonVariant::Link => "link",
+            ButtonVariant::Destructive => "destructive",
+        }
+    }
+}
+
 /// Button size preset
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ButtonSize {
@@ -52,6 +71,7 @@ pub struct Button {
     variant: ButtonVariant,
     size: ButtonSize,
     disabled: bool,
+    focused: bool,
     #[allow(clippy::type_complexity)]
     on_click: Option<Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>>,
 }
@@ -65,6 +85,7 @@ impl Button {
             variant: ButtonVariant::Default,
             size: ButtonSize::Default,
             disabled: false,
+            focused: false,
             on_click: None,
         }
     }
@@ -89,6 +110,12 @@ impl Button {
         self
     }
 
+    /// Draw the theme's focus ring, e.g. while the button has keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     pub fn on_click(
         mut self,
         handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
@@ -100,68 +127,102 @@ impl Button {
 
 impl RenderOnce for Button {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
+        let states = &theme.states;
         let radius = theme.radius;
 
         // Determine colors based on variant
-        let (bg, fg, border, hover_bg) = match self.variant {
+        let (bg, fg, border, hover_bg, active_bg) = match self.variant {
             ButtonVariant::Default => (
                 colors.primary,
                 colors.primary_foreground,
                 colors.primary,
-                with_opacity(colors.primary, 0.9),
+                states.primary.hovered(colors.primary),
+                states.primary.pressed(colors.primary),
             ),
             ButtonVariant::Secondary => (
                 colors.secondary,
                 colors.secondary_foreground,
                 colors.secondary,
-                with_opacity(colors.secondary, 0.8),
+                states.secondary.hovered(colors.secondary),
+                states.secondary.pressed(colors.secondary),
             ),
             ButtonVariant::Outline => (
                 colors.background,
                 colors.foreground,
                 colors.border,
-                colors.accent,
+                states.accent.hovered(colors.accent),
+                states.accent.pressed(colors.accent),
             ),
             ButtonVariant::Ghost => (
                 transparent(),
                 colors.foreground,
                 transparent(),
-                colors.accent,
+                states.accent.hovered(colors.accent),
+                states.accent.pressed(colors.accent),
             ),
             ButtonVariant::Link => (
                 transparent(),
                 colors.primary,
                 transparent(),
                 transparent(),
+                transparent(),
             ),
             ButtonVariant::Destructive => (
                 colors.destructive,
                 colors.destructive_foreground,
                 colors.destructive,
-                with_opacity(colors.destructive, 0.9),
+                states.destructive.hovered(colors.destructive),
+                states.destructive.pressed(colors.destructive),
             ),
         };
 
-        // Determine padding and text size based on button size
-        let (px_val, py_val, text_size) = match self.size {
-            ButtonSize::Xs => (px(8.0), px(4.0), rems(0.75)),
-            ButtonSize::Sm => (px(12.0), px(6.0), rems(0.8125)),
-            ButtonSize::Default => (px(16.0), px(8.0), rems(0.875)),
-            ButtonSize::Lg => (px(24.0), px(10.0), rems(1.0)),
-            ButtonSize::Icon => (px(8.0), px(8.0), rems(0.875)),
+        // Let the theme override individual colors, e.g. `button.destructive.bg`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: Hsla| {
+            theme.slot(&format!("button.{}.{}", variant, part), fallback)
         };
+        let (bg, fg, border, hover_bg, active_bg) = (
+            slot("bg", bg),
+            slot("fg", fg),
+            slot("border", border),
+            slot("hover_bg", hover_bg),
+            slot("active_bg", active_bg),
+        );
 
-        let hover_fg = if self.variant == ButtonVariant::Link {
-            with_underline_color(colors.primary)
-        } else if self.variant == ButtonVariant::Ghost {
-            colors.accent_foreground
-        } else {
-            fg
+        // Determine height, padding and text size from the theme's density tokens
+        let (controls, spacing, text) = (&theme.controls, &theme.spacing, &theme.typography);
+        let (height, px_val, text_size) = match self.size {
+            ButtonSize::Xs => (controls.xs, spacing.sm, text.xs),
+            ButtonSize::Sm => (controls.sm, spacing.md, text.sm),
+            ButtonSize::Default => (controls.md, spacing.lg, text.sm),
+            ButtonSize::Lg => (controls.lg, spacing.xl, text.base),
+            ButtonSize::Icon => (controls.md, 0.0, text.sm),
         };
 
+        let hover_fg = slot(
+            "hover_fg",
+            if self.variant == ButtonVariant::Link {
+                with_underline_color(fg)
+            } else if self.variant == ButtonVariant::Ghost {
+                colors.accent_foreground
+            } else {
+                fg
+            },
+        );
+
         let disabled = self.disabled;
+        // Variants without a filled surface dim their label when disabled
+        let filled = matches!(
+            self.variant,
+            ButtonVariant::Default | ButtonVariant::Secondary | ButtonVariant::Destructive
+        );
+        let fg = if disabled && !filled {
+            colors.disabled_foreground
+        } else {
+            fg
+        };
 
         let mut el = apply_radius(
             div().id(self.id).flex().items_center().justify_center(),
@@ -169,24 +230,31 @@ impl RenderOnce for Button {
         )
         .bg(bg)
         .text_color(fg)
-        .text_size(text_size)
-        .px(px_val)
-        .py(py_val)
+        .text_size(px(text_size))
+        .h(px(height))
+        .px(px(px_val))
         .whitespace_nowrap()
         .font_weight(FontWeight::MEDIUM);
 
+        if self.size == ButtonSize::Icon {
+            el = el.w(px(height));
+        }
+
         // Apply border for Outline variant
         if self.variant == ButtonVariant::Outline {
             el = el.border_1().border_color(border);
         }
 
         if disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(states.disabled_opacity).cursor_default();
         } else {
+            if self.focused {
+                el = el.shadow(theme.focus_ring());
+            }
             el = el
                 .cursor_pointer()
                 .hover(move |s| s.bg(hover_bg).text_color(hover_fg))
-                .active(move |s| s.opacity(0.8));
+                .active(move |s| s.bg(active_bg));
 
             if let Some(handler) = self.on_click {
                 el = el.on_click(handler);
@@ -218,11 +286,6 @@ fn transparent() -> Hsla {
     }
 }
 
-/// Apply a different opacity to an existing color.
-fn with_opacity(color: Hsla, a: f32) -> Hsla {
-    Hsla { a, ..color }
-}
-
 /// For Link variant hover, we just return the same color
 /// (underline styling is limited in GPUI, so we darken slightly).
 fn with_underline_color(color: Hsla) -> Hsla {
@@ -241,12 +304,14 @@ mod tests {
         let btn = Button::new("Test")
             .variant(ButtonVariant::Outline)
             .size(ButtonSize::Lg)
-            .disabled(true);
+            .disabled(true)
+            .focused(true);
 
         assert_eq!(btn.label, SharedString::from("Test"));
         assert_eq!(btn.variant, ButtonVariant::Outline);
         assert_eq!(btn.size, ButtonSize::Lg);
         assert!(btn.disabled);
+        assert!(btn.focused);
     }
 
     #[test]
@@ -255,6 +320,14 @@ mod tests {
         assert_eq!(btn.variant, ButtonVariant::Default);
         assert_eq!(btn.size, ButtonSize::Default);
         assert!(!btn.disabled);
+        assert!(!btn.focused);
         assert!(btn.on_click.is_none());
     }
+
+    #[test]
+    fn test_variant_slot_names() {
+        assert_eq!(ButtonVariant::Default.slot_name(), "default");
+        assert_eq!(ButtonVariant::Destructive.slot_name(), "destructive");
+        assert_eq!(ButtonVariant::Outline.slot_name(), "outline");
+    }
 }
@@ -2,6 +2,9 @@
 //!
 //! A container with header, content, and footer sections.
 //!
+//! The theme slots `card.bg`, `card.fg` and `card.border` override the card's
+//! colors.
+//!
 //! # Example
 //!
 //! ```rust
@@ -52,7 +55,7 @@ impl ParentElement for Card {
 
 impl RenderOnce for Card {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
@@ -60,10 +63,10 @@ impl RenderOnce for Card {
             .flex_col()
             .rounded_lg()
             .border_1()
-            .border_color(colors.border)
-            .bg(colors.card)
-            .text_color(colors.card_foreground)
-            .shadow_sm()
+            .border_color(theme.slot("card.border", colors.border))
+            .bg(theme.slot("card.bg", colors.card))
+            .text_color(theme.slot("card.fg", colors.card_foreground))
+            .shadow(theme.shadows.sm.to_box_shadows())
             .children(self.children)
     }
 }
@@ -92,12 +95,13 @@ impl ParentElement for CardHeader {
 }
 
 impl RenderOnce for CardHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_col()
-            .gap(gpui::px(6.0))
-            .p(gpui::px(24.0))
+            .gap(gpui::px((theme.spacing.xs + theme.spacing.sm) / 2.0))
+            .p(gpui::px(theme.spacing.xl))
             .children(self.children)
     }
 }
@@ -118,9 +122,10 @@ impl CardTitle {
 }
 
 impl RenderOnce for CardTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_lg()
+            .text_size(gpui::px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .child(self.text)
@@ -144,10 +149,10 @@ impl CardDescription {
 
 impl RenderOnce for CardDescription {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -177,10 +182,11 @@ impl ParentElement for CardContent {
 }
 
 impl RenderOnce for CardContent {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .px(gpui::px(24.0))
-            .pb(gpui::px(24.0))
+            .px(gpui::px(theme.spacing.xl))
+            .pb(gpui::px(theme.spacing.xl))
             .children(self.children)
     }
 }
@@ -209,12 +215,13 @@ impl ParentElement for CardFooter {
 }
 
 impl RenderOnce for CardFooter {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .items_center()
-            .px(gpui::px(24.0))
-            .pb(gpui::px(24.0))
+            .px(gpui::px(theme.spacing.xl))
+            .pb(gpui::px(theme.spacing.xl))
             .children(self.children)
     }
 }
@@ -2,6 +2,10 @@
 //!
 //! A checkbox input with checked/unchecked states and theme-aware styling.
 //!
+//! The theme slots `checkbox.bg`, `checkbox.border`, `checkbox.checked_bg`,
+//! `checkbox.check` and `checkbox.hover_border` override the box and check
+//! mark colors.
+//!
 //! # Usage
 //!
 //! ```rust
@@ -13,8 +17,9 @@
 //! ```
 
 use gpui::prelude::*;
-use gpui::{div, px, rems, App, ElementId, IntoElement, Window};
+use gpui::{div, px, App, ElementId, IntoElement, Window};
 
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 
 /// A themed checkbox component.
@@ -23,6 +28,7 @@ pub struct Checkbox {
     id: ElementId,
     checked: bool,
     disabled: bool,
+    focused: bool,
     #[allow(clippy::type_complexity)]
     on_toggle: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
 }
@@ -33,6 +39,7 @@ impl Checkbox {
             id: id.into(),
             checked: false,
             disabled: false,
+            focused: false,
             on_toggle: None,
         }
     }
@@ -47,6 +54,13 @@ impl Checkbox {
         self
     }
 
+    /// Draw the theme's focus ring, e.g. while the checkbox has keyboard
+    /// focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     pub fn on_toggle(
         mut self,
         handler: impl Fn(bool, &mut Window, &mut App) + 'static,
@@ -58,20 +72,29 @@ impl Checkbox {
 
 impl RenderOnce for Checkbox {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let checked = self.checked;
         let disabled = self.disabled;
 
+        let checked_bg = theme.slot("checkbox.checked_bg", colors.primary);
         let (bg, border_col) = if checked {
-            (colors.primary, colors.primary)
+            (checked_bg, checked_bg)
         } else {
-            (colors.background, colors.border)
+            (
+                theme.slot("checkbox.bg", colors.background),
+                theme.slot("checkbox.border", colors.border),
+            )
         };
 
-        let check_color = colors.primary_foreground;
-        let hover_border = colors.ring;
+        let check_color = theme.slot("checkbox.check", colors.primary_foreground);
+        let hover_border = theme.slot("checkbox.hover_border", colors.ring);
+        let active_bg = if checked {
+            theme.states.primary.pressed(checked_bg)
+        } else {
+            theme.states.accent.pressed(colors.accent)
+        };
 
         let mut el = div()
             .id(self.id)
@@ -85,22 +108,19 @@ impl RenderOnce for Checkbox {
             .bg(bg);
 
         if checked {
-            // Render a checkmark using a Unicode character
-            el = el
-                .child(
-                    div()
-                        .text_color(check_color)
-                        .text_size(rems(0.625))
-                        .child("✓"),
-                );
+            el = el.child(Icon::new(IconName::Check).size(px(12.0)).color(check_color));
         }
 
         if disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(theme.states.disabled_opacity).cursor_default();
         } else {
+            if self.focused {
+                el = el.shadow(theme.focus_ring());
+            }
             el = el
                 .cursor_pointer()
-                .hover(move |s| s.border_color(hover_border));
+                .hover(move |s| s.border_color(hover_border))
+                .active(move |s| s.bg(active_bg));
 
             if let Some(handler) = self.on_toggle {
                 let next_checked = !checked;
@@ -122,10 +142,12 @@ mod tests {
     fn test_checkbox_builder() {
         let cb = Checkbox::new("test-cb")
             .checked(true)
-            .disabled(true);
+            .disabled(true)
+            .focused(true);
 
         assert!(cb.checked);
         assert!(cb.disabled);
+        assert!(cb.focused);
     }
 
     #[test]
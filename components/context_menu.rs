@@ -140,7 +140,7 @@ impl ContextMenu {
 
 impl RenderOnce for ContextMenu {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
@@ -151,6 +151,17 @@ impl RenderOnce for ContextMenu {
         let accent_fg = colors.accent_foreground;
         let muted_foreground = colors.muted_foreground;
         let destructive = colors.destructive;
+        let disabled_fg = colors.disabled_foreground;
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            states.accent.hovered(accent),
+            states.accent.pressed(accent),
+        );
+
+        // Item padding and text follow the theme's density and type scale
+        let spacing = theme.spacing;
+        let item_py = (spacing.xs + spacing.sm) / 2.0;
+        let (text_sm, text_xs) = (theme.typography.sm, theme.typography.xs);
 
         let open = self.open;
         let (pos_x, pos_y) = self.position;
@@ -194,8 +205,8 @@ impl RenderOnce for ContextMenu {
                             .border_1()
                             .border_color(border)
                             .bg(popover_bg)
-                            .shadow_lg()
-                            .py(px(4.0))
+                            .shadow(theme.shadows.lg.to_box_shadows())
+                            .py(px(spacing.xs))
                             // Close when clicking outside
                             .on_mouse_down_out({
                                 let on_close = on_close.clone();
@@ -229,21 +240,23 @@ impl RenderOnce for ContextMenu {
 
                                             div()
                                                 .id(("context-item", i))
-                                                .px(px(12.0))
-                                                .py(px(6.0))
-                                                .text_sm()
-                                                .text_color(if is_destructive {
+                                                .px(px(spacing.md))
+                                                .py(px(item_py))
+                                                .text_size(px(text_sm))
+                                                .text_color(if is_disabled {
+                                                    disabled_fg
+                                                } else if is_destructive {
                                                     destructive
                                                 } else {
                                                     popover_fg
                                                 })
                                                 .when(is_disabled, |el: Stateful<Div>| {
-                                                    el.opacity(0.5)
+                                                    el.opacity(states.disabled_opacity)
                                                 })
                                                 .when(!is_disabled, |el: Stateful<Div>| {
                                                     el.cursor_pointer()
                                                         .hover(|style| {
-                                                            style.bg(accent).text_color(
+                                                            style.bg(hover_bg).text_color(
                                                                 if is_destructive {
                                                                     destructive
                                                                 } else {
@@ -251,6 +264,7 @@ impl RenderOnce for ContextMenu {
                                                                 },
                                                             )
                                                         })
+                                                        .active(|style| style.bg(active_bg))
                                                         .on_click({
                                                             let val = item_value.clone();
                                                             let on_select = on_select.clone();
@@ -275,13 +289,13 @@ impl RenderOnce for ContextMenu {
                                         DropdownMenuEntry::Separator => div()
                                             .h(px(1.0))
                                             .bg(border)
-                                            .mx(px(8.0))
-                                            .my(px(4.0))
+                                            .mx(px(spacing.sm))
+                                            .my(px(spacing.xs))
                                             .into_any_element(),
                                         DropdownMenuEntry::Label(text) => div()
-                                            .px(px(12.0))
-                                            .py(px(6.0))
-                                            .text_xs()
+                                            .px(px(spacing.md))
+                                            .py(px(item_py))
+                                            .text_size(px(text_xs))
                                             .font_weight(FontWeight::SEMIBOLD)
                                             .text_color(muted_foreground)
                                             .child(text)
@@ -55,6 +55,7 @@ use gpui::{
     AnyElement, App, ClickEvent, ElementId, FontWeight, IntoElement, KeyDownEvent, ParentElement,
     RenderOnce, SharedString, Styled, Window, deferred, div, prelude::*,
 };
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 
 /// Dialog root component.
@@ -225,21 +226,21 @@ impl ParentElement for DialogContent {
 
 impl RenderOnce for DialogContent {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .id("dialog-content")
             .flex()
             .flex_col()
-            .gap(gpui::px(16.0))
+            .gap(gpui::px(theme.spacing.lg))
             .bg(colors.popover)
             .text_color(colors.popover_foreground)
             .border_1()
             .border_color(colors.border)
             .rounded_lg()
-            .shadow_lg()
-            .p(gpui::px(24.0))
+            .shadow(theme.shadows.lg.to_box_shadows())
+            .p(gpui::px(theme.spacing.xl))
             .max_w(gpui::px(512.0))
             .w_full()
             // Prevent click-through to backdrop (stops propagation)
@@ -272,11 +273,12 @@ impl ParentElement for DialogHeader {
 }
 
 impl RenderOnce for DialogHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_col()
-            .gap(gpui::px(6.0))
+            .gap(gpui::px((theme.spacing.xs + theme.spacing.sm) / 2.0))
             .children(self.children)
     }
 }
@@ -297,9 +299,10 @@ impl DialogTitle {
 }
 
 impl RenderOnce for DialogTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_lg()
+            .text_size(gpui::px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .child(self.text)
@@ -323,10 +326,10 @@ impl DialogDescription {
 
 impl RenderOnce for DialogDescription {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -357,19 +360,21 @@ impl ParentElement for DialogFooter {
 }
 
 impl RenderOnce for DialogFooter {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_row()
             .justify_end()
-            .gap(gpui::px(8.0))
+            .gap(gpui::px(theme.spacing.sm))
             .children(self.children)
     }
 }
 
 /// Dialog close button.
 ///
-/// A wrapper that closes the dialog when clicked.
+/// A wrapper that closes the dialog when clicked. Shows an X icon when it
+/// has no children.
 #[derive(IntoElement)]
 pub struct DialogClose {
     #[allow(clippy::type_complexity)]
@@ -403,7 +408,8 @@ impl ParentElement for DialogClose {
 }
 
 impl RenderOnce for DialogClose {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         let mut el = div().id("dialog-close").cursor_pointer();
 
         if let Some(on_click) = self.on_click {
@@ -414,7 +420,11 @@ impl RenderOnce for DialogClose {
             );
         }
 
-        el.children(self.children)
+        if self.children.is_empty() {
+            el.child(Icon::new(IconName::X).color(theme.colors.muted_foreground))
+        } else {
+            el.children(self.children)
+        }
     }
 }
 
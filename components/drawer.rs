@@ -106,7 +106,7 @@ impl ParentElement for Drawer {
 impl RenderOnce for Drawer {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let backdrop_color = gpui::hsla(0.0, 0.0, 0.0, 0.8);
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let popover_bg = colors.popover;
@@ -151,7 +151,7 @@ impl RenderOnce for Drawer {
                                 .text_color(popover_fg)
                                 .border_t_1()
                                 .border_color(border_color)
-                                .shadow_lg()
+                                .shadow(theme.shadows.lg.to_box_shadows())
                                 .children(self.children),
                         ),
                 )
@@ -186,15 +186,15 @@ impl ParentElement for DrawerContent {
 
 impl RenderOnce for DrawerContent {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .id("drawer-content")
             .flex()
             .flex_col()
-            .gap(px(16.0))
-            .p(px(24.0))
+            .gap(px(theme.spacing.lg))
+            .p(px(theme.spacing.xl))
             .h_full()
             .overflow_y_scroll()
             .bg(colors.popover)
@@ -205,7 +205,7 @@ impl RenderOnce for DrawerContent {
             .child(
                 div()
                     .mx_auto()
-                    .mt(px(8.0))
+                    .mt(px(theme.spacing.sm))
                     .w(px(40.0))
                     .h(px(4.0))
                     .rounded_full()
@@ -239,11 +239,12 @@ impl ParentElement for DrawerHeader {
 }
 
 impl RenderOnce for DrawerHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_col()
-            .gap(px(6.0))
+            .gap(px((theme.spacing.xs + theme.spacing.sm) / 2.0))
             .children(self.children)
     }
 }
@@ -264,9 +265,10 @@ impl DrawerTitle {
 }
 
 impl RenderOnce for DrawerTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_lg()
+            .text_size(px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .child(self.text)
@@ -290,10 +292,10 @@ impl DrawerDescription {
 
 impl RenderOnce for DrawerDescription {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -323,12 +325,13 @@ impl ParentElement for DrawerFooter {
 }
 
 impl RenderOnce for DrawerFooter {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_row()
             .justify_end()
-            .gap(px(8.0))
+            .gap(px(theme.spacing.sm))
             .children(self.children)
     }
 }
@@ -53,7 +53,7 @@ impl Empty {
 
 impl RenderOnce for Empty {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let mut el = div()
@@ -61,11 +61,11 @@ impl RenderOnce for Empty {
             .flex_col()
             .items_center()
             .justify_center()
-            .gap(gpui::px(8.0))
-            .py(gpui::px(40.0))
+            .gap(gpui::px(theme.spacing.sm))
+            .py(gpui::px(theme.spacing.xl + theme.spacing.lg))
             .child(
                 div()
-                    .text_lg()
+                    .text_size(gpui::px(theme.typography.lg))
                     .font_weight(FontWeight::SEMIBOLD)
                     .text_color(colors.foreground)
                     .child(self.title),
@@ -74,14 +74,14 @@ impl RenderOnce for Empty {
         if let Some(description) = self.description {
             el = el.child(
                 div()
-                    .text_sm()
+                    .text_size(gpui::px(theme.typography.sm))
                     .text_color(colors.muted_foreground)
                     .child(description),
             );
         }
 
         if let Some(action) = self.action {
-            el = el.child(div().mt(gpui::px(16.0)).child(action));
+            el = el.child(div().mt(gpui::px(theme.spacing.lg)).child(action));
         }
 
         el
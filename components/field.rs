@@ -66,15 +66,15 @@ impl ParentElement for Field {
 
 impl RenderOnce for Field {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
-        let mut el = div().flex().flex_col().gap(px(8.0));
+        let mut el = div().flex().flex_col().gap(px(theme.spacing.sm));
 
         if let Some(label) = self.label {
             el = el.child(
                 div()
-                    .text_sm()
+                    .text_size(px(theme.typography.sm))
                     .font_weight(FontWeight::MEDIUM)
                     .child(label),
             );
@@ -85,7 +85,7 @@ impl RenderOnce for Field {
         if let Some(description) = self.description {
             el = el.child(
                 div()
-                    .text_sm()
+                    .text_size(px(theme.typography.sm))
                     .text_color(colors.muted_foreground)
                     .child(description),
             );
@@ -94,7 +94,7 @@ impl RenderOnce for Field {
         if let Some(error) = self.error {
             el = el.child(
                 div()
-                    .text_sm()
+                    .text_size(px(theme.typography.sm))
                     .text_color(colors.destructive)
                     .child(error),
             );
@@ -86,7 +86,7 @@ impl HoverCard {
 
 impl RenderOnce for HoverCard {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let popover_bg = colors.popover;
@@ -106,14 +106,14 @@ impl RenderOnce for HoverCard {
                     .border_1()
                     .border_color(border_color)
                     .rounded_md()
-                    .shadow_md()
-                    .p(px(16.0))
+                    .shadow(theme.shadows.md.to_box_shadows())
+                    .p(px(theme.spacing.lg))
                     .w(px(300.0))
                     .children(self.content);
 
                 let positioned = match self.side {
-                    HoverCardSide::Top => overlay.bottom_full().mb(px(4.0)),
-                    HoverCardSide::Bottom => overlay.top_full().mt(px(4.0)),
+                    HoverCardSide::Top => overlay.bottom_full().mb(px(theme.spacing.xs)),
+                    HoverCardSide::Bottom => overlay.top_full().mt(px(theme.spacing.xs)),
                     HoverCardSide::Left => overlay.right_full().mr(px(4.0)),
                     HoverCardSide::Right => overlay.left_full().ml(px(4.0)),
                 };
@@ -0,0 +1,200 @@
+//! Icon component for shadcn-ui-rs
+//!
+//! Draws a Lucide icon as an SVG tinted with a theme color. Components use
+//! it for chevrons, checks, close buttons and alerts.
+//!
+//! The SVG files are loaded from the app's asset source at
+//! `icons/<name>.svg`. `shadcn_ui_theme::Assets` embeds every icon listed in
+//! [`IconName`]; apps with their own asset source can serve those paths
+//! through `shadcn_ui_theme::icon_svg` or ship the Lucide files themselves.
+//! When the asset source has no SVG at an icon's path, the icon draws a text
+//! glyph instead and prints a warning naming the missing path once.
+//!
+//! # Example
+//!
+//! ```rust
+//! Icon::new(IconName::ChevronDown)
+//!
+//! Icon::new(IconName::CircleAlert)
+//!     .size(px(20.0))
+//!     .color(theme.colors.destructive)
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use gpui::{div, prelude::*, px, svg, App, Hsla, IntoElement, Pixels, RenderOnce, Styled, Window};
+
+use crate::theme::Theme;
+
+/// Icons available to components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconName {
+    Check,
+    ChevronDown,
+    ChevronLeft,
+    ChevronRight,
+    ChevronUp,
+    CircleAlert,
+    CircleCheck,
+    Ellipsis,
+    Info,
+    Minus,
+    TriangleAlert,
+    X,
+}
+
+impl IconName {
+    /// The icon's asset path, e.g. `icons/chevron-down.svg`.
+    pub fn path(self) -> &'static str {
+        match self {
+            IconName::Check => "icons/check.svg",
+            IconName::ChevronDown => "icons/chevron-down.svg",
+            IconName::ChevronLeft => "icons/chevron-left.svg",
+            IconName::ChevronRight => "icons/chevron-right.svg",
+            IconName::ChevronUp => "icons/chevron-up.svg",
+            IconName::CircleAlert => "icons/circle-alert.svg",
+            IconName::CircleCheck => "icons/circle-check.svg",
+            IconName::Ellipsis => "icons/ellipsis.svg",
+            IconName::Info => "icons/info.svg",
+            IconName::Minus => "icons/minus.svg",
+            IconName::TriangleAlert => "icons/triangle-alert.svg",
+            IconName::X => "icons/x.svg",
+        }
+    }
+
+    /// The text drawn when the app's asset source has no SVG at [`Self::path`].
+    pub fn glyph(self) -> &'static str {
+        match self {
+            IconName::Check => "\u{2713}",
+            IconName::ChevronDown => "\u{25BE}",
+            IconName::ChevronLeft => "\u{25C2}",
+            IconName::ChevronRight => "\u{25B8}",
+            IconName::ChevronUp => "\u{25B4}",
+            IconName::CircleAlert => "!",
+            IconName::CircleCheck => "\u{2713}",
+            IconName::Ellipsis => "\u{2026}",
+            IconName::Info => "i",
+            IconName::Minus => "\u{2212}",
+            IconName::TriangleAlert => "\u{26A0}",
+            IconName::X => "\u{2715}",
+        }
+    }
+}
+
+/// Whether the app's asset source serves `path`, checked once per path.
+///
+/// Warns on stderr the first time a path is missing.
+fn is_served(path: &'static str, cx: &App) -> bool {
+    static SERVED: OnceLock<Mutex<HashMap<&'static str, bool>>> = OnceLock::new();
+    let mut served = SERVED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    *served.entry(path).or_insert_with(|| {
+        let found = matches!(cx.asset_source().load(path), Ok(Some(_)));
+        if !found {
+            eprintln!(
+                "shadcn-ui: {} is not served by the app's asset source, drawing a text glyph \
+                 instead; serve icons/*.svg, e.g. with shadcn_ui_theme::Assets",
+                path
+            );
+        }
+        found
+    })
+}
+
+/// A square SVG icon.
+///
+/// Defaults to 16px in the theme's foreground color.
+#[derive(IntoElement)]
+pub struct Icon {
+    name: IconName,
+    size: Pixels,
+    color: Option<Hsla>,
+}
+
+impl Icon {
+    /// Create a new icon.
+    pub fn new(name: IconName) -> Self {
+        Self {
+            name,
+            size: px(16.0),
+            color: None,
+        }
+    }
+
+    /// Set the width and height.
+    pub fn size(mut self, size: Pixels) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the color.
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+impl RenderOnce for Icon {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
+        let color = self.color.unwrap_or(theme.colors.foreground);
+        let path = self.name.path();
+
+        if is_served(path, cx) {
+            svg()
+                .path(path)
+                .flex_none()
+                .size(self.size)
+                .text_color(color)
+                .into_any_element()
+        } else {
+            div()
+                .flex_none()
+                .size(self.size)
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_size(self.size)
+                .line_height(self.size)
+                .text_color(color)
+                .child(self.name.glyph())
+                .into_any_element()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_defaults() {
+        let icon = Icon::new(IconName::Check);
+        assert_eq!(icon.name, IconName::Check);
+        assert_eq!(icon.size, px(16.0));
+        assert!(icon.color.is_none());
+    }
+
+    #[test]
+    fn test_icon_builder() {
+        let color = gpui::hsla(0.0, 0.0, 0.0, 1.0);
+        let icon = Icon::new(IconName::X).size(px(12.0)).color(color);
+        assert_eq!(icon.size, px(12.0));
+        assert_eq!(icon.color, Some(color));
+    }
+
+    #[test]
+    fn test_icon_paths() {
+        assert_eq!(IconName::ChevronDown.path(), "icons/chevron-down.svg");
+        assert_eq!(IconName::TriangleAlert.path(), "icons/triangle-alert.svg");
+    }
+
+    #[test]
+    fn test_icon_glyphs() {
+        assert_eq!(IconName::Check.glyph(), "\u{2713}");
+        assert_eq!(IconName::X.glyph(), "\u{2715}");
+    }
+}
@@ -3,6 +3,10 @@
 //! A styled text input display with theme-aware styling, placeholder support,
 //! and disabled state.
 //!
+//! The theme slots `input.bg`, `input.fg`, `input.placeholder`,
+//! `input.border` and `input.hover_border` override the input's colors.
+//! Focus and disabled styling follow the theme's `states` tokens.
+//!
 //! **Note:** This component renders a visual representation of a text input.
 //! GPUI does not provide a built-in editable text field widget, so actual text
 //! editing requires platform-specific integration (e.g. `gpui::TextInput` or a
@@ -30,6 +34,7 @@ pub struct Input {
     placeholder: Option<SharedString>,
     value: Option<SharedString>,
     disabled: bool,
+    focused: bool,
 }
 
 impl Input {
@@ -39,6 +44,7 @@ impl Input {
             placeholder: None,
             value: None,
             disabled: false,
+            focused: false,
         }
     }
 
@@ -56,11 +62,17 @@ impl Input {
         self.disabled = disabled;
         self
     }
+
+    /// Draw the theme's focus ring, e.g. while the input has keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
 }
 
 impl RenderOnce for Input {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius;
 
@@ -75,28 +87,36 @@ impl RenderOnce for Input {
         };
 
         let is_placeholder = self.value.as_ref().is_none_or(|v| v.is_empty());
-        let text_col = if is_placeholder {
-            colors.muted_foreground
+        let text_col = if self.disabled {
+            colors.disabled_foreground
+        } else if is_placeholder {
+            theme.slot("input.placeholder", colors.muted_foreground)
         } else {
-            colors.foreground
+            theme.slot("input.fg", colors.foreground)
         };
 
-        let focus_border = colors.ring;
-        let border_color = colors.input;
+        let focus_border = theme.slot("input.hover_border", colors.ring);
+        let border_color = theme.slot("input.border", colors.input);
+
+        // Halfway between the xs and sm steps: 6px at default density
+        let padding_y = (theme.spacing.xs + theme.spacing.sm) / 2.0;
 
         let mut el = apply_radius(div().id(self.id).flex().items_center().w_full(), radius)
-            .h(px(36.0))
+            .h(px(theme.controls.md))
             .border_1()
             .border_color(border_color)
-            .bg(colors.background)
-            .px(px(12.0))
-            .py(px(6.0))
-            .text_sm()
+            .bg(theme.slot("input.bg", colors.background))
+            .px(px(theme.spacing.md))
+            .py(px(padding_y))
+            .text_size(px(theme.typography.sm))
             .text_color(text_col);
 
         if self.disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(theme.states.disabled_opacity).cursor_default();
         } else {
+            if self.focused {
+                el = el.border_color(focus_border).shadow(theme.focus_ring());
+            }
             el = el
                 .cursor_text()
                 .hover(move |s| s.border_color(focus_border));
@@ -126,11 +146,13 @@ mod tests {
         let input = Input::new("test-input")
             .placeholder("Enter text")
             .value("hello")
-            .disabled(true);
+            .disabled(true)
+            .focused(true);
 
         assert_eq!(input.placeholder, Some(SharedString::from("Enter text")));
         assert_eq!(input.value, Some(SharedString::from("hello")));
         assert!(input.disabled);
+        assert!(input.focused);
     }
 
     #[test]
@@ -139,5 +161,6 @@ mod tests {
         assert!(input.placeholder.is_none());
         assert!(input.value.is_none());
         assert!(!input.disabled);
+        assert!(!input.focused);
     }
 }
@@ -85,31 +85,40 @@ impl Item {
 
 impl RenderOnce for Item {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let accent = colors.accent;
         let accent_fg = colors.accent_foreground;
         let muted_fg = colors.muted_foreground;
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            states.accent.hovered(accent),
+            states.accent.pressed(accent),
+        );
         let is_clickable = self.on_click.is_some() && !self.disabled;
 
+        let (spacing, text) = (&theme.spacing, &theme.typography);
         let mut el = div()
             .id(self.id)
             .flex()
             .flex_row()
             .items_center()
-            .gap(px(12.0))
-            .px(px(12.0))
-            .py(px(8.0));
+            .gap(px(spacing.md))
+            .px(px(spacing.md))
+            .py(px(spacing.sm));
 
         if self.disabled {
-            el = el.opacity(0.5);
+            el = el
+                .opacity(states.disabled_opacity)
+                .text_color(colors.disabled_foreground);
         }
 
         if is_clickable {
             el = el
                 .cursor_pointer()
-                .hover(|style: gpui::StyleRefinement| style.bg(accent).text_color(accent_fg));
+                .hover(|style: gpui::StyleRefinement| style.bg(hover_bg).text_color(accent_fg))
+                .active(|style: gpui::StyleRefinement| style.bg(active_bg));
         }
 
         if let Some(on_click) = self.on_click {
@@ -125,11 +134,11 @@ impl RenderOnce for Item {
 
         // Label and description column
         let mut label_col = div().flex_1().flex().flex_col();
-        label_col = label_col.child(div().text_sm().child(self.label));
+        label_col = label_col.child(div().text_size(px(text.sm)).child(self.label));
         if let Some(description) = self.description {
             label_col = label_col.child(
                 div()
-                    .text_xs()
+                    .text_size(px(text.xs))
                     .text_color(muted_fg)
                     .child(description),
             );
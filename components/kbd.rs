@@ -29,7 +29,7 @@ impl Kbd {
 
 impl RenderOnce for Kbd {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
@@ -39,7 +39,8 @@ impl RenderOnce for Kbd {
             .rounded_md()
             .px(gpui::px(6.0))
             .py(gpui::px(2.0))
-            .text_xs()
+            .text_size(gpui::px(theme.typography.xs))
+            .font_family(theme.typography.mono_font_family.clone())
             .text_color(colors.muted_foreground)
             .child(self.keys)
     }
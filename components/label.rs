@@ -9,7 +9,7 @@
 //! ```
 
 use gpui::prelude::*;
-use gpui::{div, App, FontWeight, IntoElement, SharedString, Window};
+use gpui::{div, px, App, FontWeight, IntoElement, SharedString, Window};
 
 use crate::theme::Theme;
 
@@ -37,10 +37,10 @@ impl Label {
 
 impl RenderOnce for Label {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         let mut el = div()
-            .text_sm()
+            .text_size(px(theme.typography.sm))
             .font_weight(FontWeight::MEDIUM)
             .text_color(theme.colors.foreground);
 
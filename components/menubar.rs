@@ -60,7 +60,7 @@ impl ParentElement for Menubar {
 
 impl RenderOnce for Menubar {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let background = colors.background;
@@ -71,11 +71,11 @@ impl RenderOnce for Menubar {
             .flex()
             .flex_row()
             .items_center()
-            .h(px(40.0))
+            .h(px(theme.controls.lg))
             .border_b_1()
             .border_color(border)
             .bg(background)
-            .px(px(8.0))
+            .px(px(theme.spacing.sm))
             .children(self.children)
     }
 }
@@ -161,7 +161,7 @@ impl MenubarMenu {
 
 impl RenderOnce for MenubarMenu {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
@@ -172,6 +172,17 @@ impl RenderOnce for MenubarMenu {
         let accent_fg = colors.accent_foreground;
         let muted_foreground = colors.muted_foreground;
         let destructive = colors.destructive;
+        let disabled_fg = colors.disabled_foreground;
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            states.accent.hovered(accent),
+            states.accent.pressed(accent),
+        );
+
+        // Item padding and text follow the theme's density and type scale
+        let spacing = theme.spacing;
+        let item_py = (spacing.xs + spacing.sm) / 2.0;
+        let (text_sm, text_xs) = (theme.typography.sm, theme.typography.xs);
 
         let open = self.open;
         let on_open_change = self.on_open_change;
@@ -184,9 +195,11 @@ impl RenderOnce for MenubarMenu {
             .child(
                 div()
                     .id("menubar-trigger")
-                    .px(px(12.0))
-                    .py(px(4.0))
-                    .text_sm()
+                    .h(px(theme.controls.sm))
+                    .flex()
+                    .items_center()
+                    .px(px(spacing.md))
+                    .text_size(px(text_sm))
                     .font_weight(FontWeight::MEDIUM)
                     .rounded(px(radius))
                     .cursor_pointer()
@@ -194,7 +207,8 @@ impl RenderOnce for MenubarMenu {
                         el.bg(accent).text_color(accent_fg)
                     })
                     .when(!open, |el: Stateful<Div>| {
-                        el.hover(|style| style.bg(accent))
+                        el.hover(|style| style.bg(hover_bg))
+                            .active(|style| style.bg(active_bg))
                     })
                     .on_click({
                         let on_open_change = on_open_change.clone();
@@ -213,15 +227,15 @@ impl RenderOnce for MenubarMenu {
                         div()
                             .id("menubar-popover")
                             .absolute()
-                            .top(px(32.0))
+                            .top(px(theme.controls.sm))
                             .left_0()
                             .min_w(px(160.0))
                             .rounded(px(radius))
                             .border_1()
                             .border_color(border)
                             .bg(popover_bg)
-                            .shadow_lg()
-                            .py(px(4.0))
+                            .shadow(theme.shadows.lg.to_box_shadows())
+                            .py(px(spacing.xs))
                             // Close when clicking outside
                             .on_mouse_down_out({
                                 let on_open_change = on_open_change.clone();
@@ -256,21 +270,23 @@ impl RenderOnce for MenubarMenu {
 
                                             div()
                                                 .id(("menubar-item", i))
-                                                .px(px(12.0))
-                                                .py(px(6.0))
-                                                .text_sm()
-                                                .text_color(if is_destructive {
+                                                .px(px(spacing.md))
+                                                .py(px(item_py))
+                                                .text_size(px(text_sm))
+                                                .text_color(if is_disabled {
+                                                    disabled_fg
+                                                } else if is_destructive {
                                                     destructive
                                                 } else {
                                                     popover_fg
                                                 })
                                                 .when(is_disabled, |el: Stateful<Div>| {
-                                                    el.opacity(0.5)
+                                                    el.opacity(states.disabled_opacity)
                                                 })
                                                 .when(!is_disabled, |el: Stateful<Div>| {
                                                     el.cursor_pointer()
                                                         .hover(|style| {
-                                                            style.bg(accent).text_color(
+                                                            style.bg(hover_bg).text_color(
                                                                 if is_destructive {
                                                                     destructive
                                                                 } else {
@@ -278,6 +294,7 @@ impl RenderOnce for MenubarMenu {
                                                                 },
                                                             )
                                                         })
+                                                        .active(|style| style.bg(active_bg))
                                                         .on_click({
                                                             let val = item_value.clone();
                                                             let on_select = on_select.clone();
@@ -309,13 +326,13 @@ impl RenderOnce for MenubarMenu {
                                         DropdownMenuEntry::Separator => div()
                                             .h(px(1.0))
                                             .bg(border)
-                                            .mx(px(8.0))
-                                            .my(px(4.0))
+                                            .mx(px(spacing.sm))
+                                            .my(px(spacing.xs))
                                             .into_any_element(),
                                         DropdownMenuEntry::Label(text) => div()
-                                            .px(px(12.0))
-                                            .py(px(6.0))
-                                            .text_xs()
+                                            .px(px(spacing.md))
+                                            .py(px(item_py))
+                                            .text_size(px(text_xs))
                                             .font_weight(FontWeight::SEMIBOLD)
                                             .text_color(muted_foreground)
                                             .child(text)
@@ -346,14 +363,14 @@ impl MenubarSeparator {
 
 impl RenderOnce for MenubarSeparator {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let border = theme.colors.border;
 
         div()
             .w(px(1.0))
             .h(px(16.0))
             .bg(border)
-            .mx(px(4.0))
+            .mx(px(theme.spacing.xs))
     }
 }
 
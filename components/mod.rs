@@ -102,6 +102,7 @@ pub mod button_group;
 pub mod collapsible;
 pub mod context_menu;
 pub mod field;
+pub mod icon;
 pub mod item;
 pub mod menubar;
 pub mod navigation_menu;
@@ -116,6 +117,7 @@ pub use button_group::{ButtonGroup, ButtonGroupOrientation};
 pub use collapsible::Collapsible;
 pub use context_menu::ContextMenu;
 pub use field::Field;
+pub use icon::{Icon, IconName};
 pub use item::Item;
 pub use menubar::{Menubar, MenubarMenu, MenubarSeparator};
 pub use navigation_menu::{NavigationMenu, NavigationMenuItem, NavigationMenuLink};
@@ -57,13 +57,13 @@ impl ParentElement for NavigationMenu {
 }
 
 impl RenderOnce for NavigationMenu {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         div()
             .id(self.id)
             .flex()
             .flex_row()
             .items_center()
-            .gap(px(4.0))
+            .gap(px(Theme::of(cx).spacing.xs))
             .children(self.children)
     }
 }
@@ -125,7 +125,7 @@ impl ParentElement for NavigationMenuItem {
 
 impl RenderOnce for NavigationMenuItem {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
@@ -134,6 +134,13 @@ impl RenderOnce for NavigationMenuItem {
         let popover_fg = colors.popover_foreground;
         let accent = colors.accent;
         let accent_fg = colors.accent_foreground;
+        let (hover_bg, active_bg) = (
+            theme.states.accent.hovered(accent),
+            theme.states.accent.pressed(accent),
+        );
+
+        // Trigger and panel sizes follow the theme's density and type scale
+        let (controls, spacing) = (theme.controls, theme.spacing);
 
         let open = self.open;
         let on_open_change = self.on_open_change;
@@ -145,9 +152,11 @@ impl RenderOnce for NavigationMenuItem {
             .child(
                 div()
                     .id("nav-trigger")
-                    .px(px(12.0))
-                    .py(px(4.0))
-                    .text_sm()
+                    .h(px(controls.sm))
+                    .flex()
+                    .items_center()
+                    .px(px(spacing.md))
+                    .text_size(px(theme.typography.sm))
                     .font_weight(FontWeight::MEDIUM)
                     .rounded(px(radius))
                     .cursor_pointer()
@@ -155,7 +164,8 @@ impl RenderOnce for NavigationMenuItem {
                         el.bg(accent).text_color(accent_fg)
                     })
                     .when(!open, |el: Stateful<Div>| {
-                        el.hover(|style| style.bg(accent))
+                        el.hover(|style| style.bg(hover_bg))
+                            .active(|style| style.bg(active_bg))
                     })
                     .on_click({
                         let on_open_change = on_open_change.clone();
@@ -174,16 +184,16 @@ impl RenderOnce for NavigationMenuItem {
                         div()
                             .id("nav-panel")
                             .absolute()
-                            .top(px(44.0))
+                            .top(px(controls.sm + spacing.md))
                             .left_0()
                             .min_w(px(400.0))
-                            .p(px(16.0))
+                            .p(px(spacing.lg))
                             .rounded(px(radius))
                             .border_1()
                             .border_color(border)
                             .bg(popover_bg)
                             .text_color(popover_fg)
-                            .shadow_lg()
+                            .shadow(theme.shadows.lg.to_box_shadows())
                             // Close when clicking outside
                             .on_mouse_down_out({
                                 let on_open_change = on_open_change.clone();
@@ -255,21 +265,27 @@ impl NavigationMenuLink {
 
 impl RenderOnce for NavigationMenuLink {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
         let accent = colors.accent;
         let muted_foreground = colors.muted_foreground;
+        let (hover_bg, active_bg) = (
+            theme.states.accent.hovered(accent),
+            theme.states.accent.pressed(accent),
+        );
+
+        let (spacing, text_sm) = (theme.spacing, theme.typography.sm);
 
         let label_el = div()
-            .text_sm()
+            .text_size(px(text_sm))
             .font_weight(FontWeight::MEDIUM)
             .child(self.label);
 
         let desc_el = self.description.map(|desc| {
             div()
-                .text_sm()
+                .text_size(px(text_sm))
                 .text_color(muted_foreground)
                 .child(desc)
         });
@@ -279,11 +295,12 @@ impl RenderOnce for NavigationMenuLink {
                 .id("nav-link")
                 .flex()
                 .flex_col()
-                .gap(px(2.0))
-                .p(px(12.0))
+                .gap(px(spacing.xs / 2.0))
+                .p(px(spacing.md))
                 .rounded(px(radius))
                 .cursor_pointer()
-                .hover(|style| style.bg(accent))
+                .hover(|style| style.bg(hover_bg))
+                .active(|style| style.bg(active_bg))
                 .on_click(move |event: &ClickEvent, window, cx| {
                     handler(event, window, cx);
                 })
@@ -296,10 +313,10 @@ impl RenderOnce for NavigationMenuLink {
             let mut el = div()
                 .flex()
                 .flex_col()
-                .gap(px(2.0))
-                .p(px(12.0))
+                .gap(px(spacing.xs / 2.0))
+                .p(px(spacing.md))
                 .rounded(px(radius))
-                .hover(|style| style.bg(accent))
+                .hover(|style| style.bg(hover_bg))
                 .child(label_el);
             if let Some(desc) = desc_el {
                 el = el.child(desc);
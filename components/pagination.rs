@@ -19,6 +19,7 @@ use gpui::prelude::*;
 use gpui::{
     div, px, App, ClickEvent, ElementId, IntoElement, RenderOnce, Styled, Window,
 };
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 
 /// Page navigation controls.
@@ -117,14 +118,21 @@ fn compute_page_window(current: usize, total: usize) -> Vec<Option<usize>> {
 
 impl RenderOnce for Pagination {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let primary = colors.primary;
         let primary_fg = colors.primary_foreground;
         let fg = colors.foreground;
-        let accent = colors.accent;
         let muted_fg = colors.muted_foreground;
+        let disabled_fg = colors.disabled_foreground;
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            states.accent.hovered(colors.accent),
+            states.accent.pressed(colors.accent),
+        );
+        let size = px(theme.controls.md);
+        let text_size = px(theme.typography.sm);
 
         let current = self.current_page;
         let total = self.total_pages;
@@ -137,30 +145,37 @@ impl RenderOnce for Pagination {
             .flex()
             .flex_row()
             .items_center()
-            .gap(px(4.0));
+            .gap(px(theme.spacing.xs));
 
         // Previous button
         let prev_disabled = current <= 1;
         {
             let mut prev_btn = div()
                 .id("pagination-prev")
-                .w(px(36.0))
-                .h(px(36.0))
+                .w(size)
+                .h(size)
                 .flex()
                 .items_center()
                 .justify_center()
                 .rounded(px(6.0))
-                .text_sm()
-                .child("\u{2190}");
+                .text_size(text_size)
+                .child(
+                    Icon::new(IconName::ChevronLeft)
+                        .color(if prev_disabled { disabled_fg } else { fg }),
+                );
 
             if prev_disabled {
-                prev_btn = prev_btn.opacity(0.5).cursor_default();
+                prev_btn = prev_btn
+                    .opacity(states.disabled_opacity)
+                    .text_color(disabled_fg)
+                    .cursor_default();
             } else {
                 let handler = on_page_change.clone();
                 prev_btn = prev_btn
                     .cursor_pointer()
                     .text_color(fg)
-                    .hover(|s: gpui::StyleRefinement| s.bg(accent))
+                    .hover(|s: gpui::StyleRefinement| s.bg(hover_bg))
+                    .active(|s: gpui::StyleRefinement| s.bg(active_bg))
                     .on_click(move |_event: &ClickEvent, window: &mut Window, cx: &mut App| {
                         if let Some(ref cb) = handler {
                             cb(current - 1, window, cx);
@@ -180,13 +195,13 @@ impl RenderOnce for Pagination {
 
                     let mut btn = div()
                         .id(ElementId::NamedInteger("page".into(), idx as u64))
-                        .w(px(36.0))
-                        .h(px(36.0))
+                        .w(size)
+                        .h(size)
                         .flex()
                         .items_center()
                         .justify_center()
                         .rounded(px(6.0))
-                        .text_sm()
+                        .text_size(text_size)
                         .cursor_pointer()
                         .child(format!("{}", page));
 
@@ -196,7 +211,8 @@ impl RenderOnce for Pagination {
                         let handler = on_page_change.clone();
                         btn = btn
                             .text_color(fg)
-                            .hover(|s: gpui::StyleRefinement| s.bg(accent))
+                            .hover(|s: gpui::StyleRefinement| s.bg(hover_bg))
+                            .active(|s: gpui::StyleRefinement| s.bg(active_bg))
                             .on_click(
                                 move |_event: &ClickEvent, window: &mut Window, cx: &mut App| {
                                     if let Some(ref cb) = handler {
@@ -210,13 +226,12 @@ impl RenderOnce for Pagination {
                 }
                 None => {
                     let ellipsis = div()
-                        .w(px(36.0))
-                        .h(px(36.0))
+                        .w(size)
+                        .h(size)
                         .flex()
                         .items_center()
                         .justify_center()
-                        .text_color(muted_fg)
-                        .child("\u{2026}");
+                        .child(Icon::new(IconName::Ellipsis).color(muted_fg));
                     container = container.child(ellipsis);
                 }
             }
@@ -227,23 +242,30 @@ impl RenderOnce for Pagination {
         {
             let mut next_btn = div()
                 .id("pagination-next")
-                .w(px(36.0))
-                .h(px(36.0))
+                .w(size)
+                .h(size)
                 .flex()
                 .items_center()
                 .justify_center()
                 .rounded(px(6.0))
-                .text_sm()
-                .child("\u{2192}");
+                .text_size(text_size)
+                .child(
+                    Icon::new(IconName::ChevronRight)
+                        .color(if next_disabled { disabled_fg } else { fg }),
+                );
 
             if next_disabled {
-                next_btn = next_btn.opacity(0.5).cursor_default();
+                next_btn = next_btn
+                    .opacity(states.disabled_opacity)
+                    .text_color(disabled_fg)
+                    .cursor_default();
             } else {
                 let handler = on_page_change;
                 next_btn = next_btn
                     .cursor_pointer()
                     .text_color(fg)
-                    .hover(|s: gpui::StyleRefinement| s.bg(accent))
+                    .hover(|s: gpui::StyleRefinement| s.bg(hover_bg))
+                    .active(|s: gpui::StyleRefinement| s.bg(active_bg))
                     .on_click(move |_event: &ClickEvent, window: &mut Window, cx: &mut App| {
                         if let Some(ref cb) = handler {
                             cb(current + 1, window, cx);
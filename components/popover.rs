@@ -123,7 +123,7 @@ impl Popover {
 
 impl RenderOnce for Popover {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let popover_bg = colors.popover;
@@ -155,8 +155,8 @@ impl RenderOnce for Popover {
                     .border_1()
                     .border_color(border_color)
                     .rounded_md()
-                    .shadow_lg()
-                    .p(px(16.0))
+                    .shadow(theme.shadows.lg.to_box_shadows())
+                    .p(px(theme.spacing.lg))
                     .min_w(px(220.0))
                     .when_some(on_open_change.clone(), |el: Stateful<Div>, on_open_change| {
                         let on_open_change_key = on_open_change.clone();
@@ -176,8 +176,8 @@ impl RenderOnce for Popover {
                     .children(self.content);
 
                 let positioned = match self.side {
-                    PopoverSide::Top => overlay.bottom_full().mb(px(4.0)),
-                    PopoverSide::Bottom => overlay.top_full().mt(px(4.0)),
+                    PopoverSide::Top => overlay.bottom_full().mb(px(theme.spacing.xs)),
+                    PopoverSide::Bottom => overlay.top_full().mt(px(theme.spacing.xs)),
                     PopoverSide::Left => overlay.right_full().mr(px(4.0)),
                     PopoverSide::Right => overlay.left_full().ml(px(4.0)),
                 };
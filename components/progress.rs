@@ -2,6 +2,9 @@
 //!
 //! A horizontal progress bar with a track and fill indicator.
 //!
+//! The theme slots `progress.track` and `progress.indicator` override the
+//! track and fill colors.
+//!
 //! # Example
 //!
 //! ```rust
@@ -41,7 +44,7 @@ impl Progress {
 
 impl RenderOnce for Progress {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let fraction = self.value / 100.0;
@@ -51,14 +54,14 @@ impl RenderOnce for Progress {
             .w_full()
             .h(px(8.0))
             .rounded_full()
-            .bg(colors.secondary)
+            .bg(theme.slot("progress.track", colors.secondary))
             .overflow_hidden()
             // Inner fill
             .child(
                 div()
                     .h_full()
                     .rounded_full()
-                    .bg(colors.primary)
+                    .bg(theme.slot("progress.indicator", colors.primary))
                     .w(relative(fraction)),
             )
     }
@@ -2,6 +2,9 @@
 //!
 //! A set of mutually exclusive radio buttons where only one can be selected at a time.
 //!
+//! The theme slots `radio.border`, `radio.checked_border`, `radio.indicator`
+//! and `radio.fg` override the circle, dot and label colors.
+//!
 //! # Example
 //! ```rust
 //! RadioGroup::new("size-group")
@@ -117,11 +120,13 @@ impl RadioGroup {
 
 impl RenderOnce for RadioGroup {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
-        let primary = theme.colors.primary;
-        let border = theme.colors.border;
-        let foreground = theme.colors.foreground;
-        let muted_fg = theme.colors.muted_foreground;
+        let theme = Theme::of(cx);
+        let checked_border = theme.slot("radio.checked_border", theme.colors.primary);
+        let indicator = theme.slot("radio.indicator", theme.colors.primary);
+        let border = theme.slot("radio.border", theme.colors.border);
+        let foreground = theme.slot("radio.fg", theme.colors.foreground);
+        let disabled_fg = theme.colors.disabled_foreground;
+        let disabled_opacity = theme.states.disabled_opacity;
 
         let selected = self.value;
         let on_change = self.on_change;
@@ -131,7 +136,7 @@ impl RenderOnce for RadioGroup {
             .id(self.id)
             .flex()
             .flex_col()
-            .gap(px(8.0))
+            .gap(px(theme.spacing.sm))
             .children(self.items.into_iter().enumerate().map(move |(i, item)| {
                 let is_selected = selected
                     .as_ref()
@@ -145,9 +150,9 @@ impl RenderOnce for RadioGroup {
                     .flex()
                     .flex_row()
                     .items_center()
-                    .gap(px(8.0))
+                    .gap(px(theme.spacing.sm))
                     .when(!is_disabled, |el| el.cursor_pointer())
-                    .when(is_disabled, |el| el.opacity(0.5))
+                    .when(is_disabled, |el| el.opacity(disabled_opacity))
                     .when(!is_disabled, |el| {
                         el.on_click({
                             let val = item_value.clone();
@@ -167,21 +172,21 @@ impl RenderOnce for RadioGroup {
                             .size(px(16.0))
                             .rounded(px(9999.0))
                             .border_1()
-                            .border_color(if is_selected { primary } else { border })
+                            .border_color(if is_selected { checked_border } else { border })
                             .when(is_selected, |el| {
                                 el.child(
                                     // Inner filled dot
                                     div()
                                         .size(px(8.0))
                                         .rounded(px(9999.0))
-                                        .bg(primary),
+                                        .bg(indicator),
                                 )
                             }),
                     )
                     .child(
                         div()
-                            .text_sm()
-                            .text_color(if is_disabled { muted_fg } else { foreground })
+                            .text_size(px(theme.typography.sm))
+                            .text_color(if is_disabled { disabled_fg } else { foreground })
                             .child(item.label.clone()),
                     )
             }))
@@ -2,6 +2,11 @@
 //!
 //! A dropdown menu for selecting a single value from a list of options.
 //!
+//! The theme slots `select.bg`, `select.fg`, `select.placeholder`,
+//! `select.border`, `select.popover_bg`, `select.popover_fg`,
+//! `select.selected_bg`, `select.selected_fg` and `select.hover_bg` override
+//! the trigger and list colors.
+//!
 //! # Example
 //! ```rust
 //! Select::new("fruit-select")
@@ -27,6 +32,7 @@ use gpui::{
     Window,
 };
 
+use super::icon::{Icon, IconName};
 use crate::theme::Theme;
 
 // ---------------------------------------------------------------------------
@@ -161,18 +167,29 @@ impl Select {
 
 impl RenderOnce for Select {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
-        let bg = colors.background;
-        let fg = colors.foreground;
-        let border = colors.border;
-        let popover_bg = colors.popover;
-        let popover_fg = colors.popover_foreground;
+        let bg = theme.slot("select.bg", colors.background);
+        let fg = theme.slot("select.fg", colors.foreground);
+        let border = theme.slot("select.border", colors.border);
+        let popover_bg = theme.slot("select.popover_bg", colors.popover);
+        let popover_fg = theme.slot("select.popover_fg", colors.popover_foreground);
         let muted_fg = colors.muted_foreground;
-        let accent = colors.accent;
-        let accent_fg = colors.accent_foreground;
+        let placeholder_fg = theme.slot("select.placeholder", muted_fg);
+        let accent = theme.slot("select.selected_bg", colors.accent);
+        let accent_fg = theme.slot("select.selected_fg", colors.accent_foreground);
+        let disabled_fg = colors.disabled_foreground;
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            theme.slot("select.hover_bg", states.accent.hovered(colors.accent)),
+            states.accent.pressed(colors.accent),
+        );
+        let focus_ring = theme.focus_ring();
+        let control_height = theme.controls.md;
+        let control_px = theme.spacing.md;
+        let text_size = theme.typography.sm;
 
         // Resolve display text before destructuring
         let display_text = self
@@ -201,16 +218,24 @@ impl RenderOnce for Select {
                     .flex_row()
                     .items_center()
                     .justify_between()
-                    .h(px(36.0))
-                    .px(px(12.0))
+                    .h(px(control_height))
+                    .px(px(control_px))
                     .rounded(px(radius))
                     .border_1()
                     .border_color(border)
                     .bg(bg)
-                    .text_sm()
-                    .text_color(if has_value { fg } else { muted_fg })
+                    .text_size(px(text_size))
+                    .text_color(if disabled {
+                        disabled_fg
+                    } else if has_value {
+                        fg
+                    } else {
+                        placeholder_fg
+                    })
                     .when(!disabled, |el| el.cursor_pointer())
-                    .when(disabled, |el| el.opacity(0.5))
+                    .when(disabled, |el| el.opacity(states.disabled_opacity))
+                    // The trigger keeps focus while the list is open
+                    .when(open && !disabled, |el| el.shadow(focus_ring))
                     .when(!disabled, |el| {
                         let on_open_change = on_open_change.clone();
                         el.on_click(move |_event: &ClickEvent, window, cx| {
@@ -222,10 +247,12 @@ impl RenderOnce for Select {
                     .child(display_text)
                     // Chevron indicator
                     .child(
-                        div()
-                            .text_xs()
-                            .text_color(muted_fg)
-                            .child(if open { "\u{25B2}" } else { "\u{25BC}" }),
+                        Icon::new(if open {
+                            IconName::ChevronUp
+                        } else {
+                            IconName::ChevronDown
+                        })
+                        .color(muted_fg),
                     ),
             )
             // Dropdown popover (only visible when open)
@@ -234,7 +261,7 @@ impl RenderOnce for Select {
                     div()
                         .id("select-popover")
                         .absolute()
-                        .top(px(40.0))
+                        .top(px(control_height + 4.0))
                         .left_0()
                         .w_full()
                         .min_w(px(160.0))
@@ -242,7 +269,7 @@ impl RenderOnce for Select {
                         .border_1()
                         .border_color(border)
                         .bg(popover_bg)
-                        .py(px(4.0))
+                        .py(px(theme.spacing.xs))
                         .overflow_y_scroll()
                         // Close when clicking outside
                         .on_mouse_down_out({
@@ -267,17 +294,22 @@ impl RenderOnce for Select {
                                 .flex()
                                 .flex_row()
                                 .items_center()
-                                .px(px(12.0))
-                                .py(px(6.0))
-                                .text_sm()
-                                .text_color(if is_disabled { muted_fg } else { popover_fg })
+                                .justify_between()
+                                .px(px(theme.spacing.md))
+                                .py(px((theme.spacing.xs + theme.spacing.sm) / 2.0))
+                                .text_size(px(theme.typography.sm))
+                                .text_color(if is_disabled { disabled_fg } else { popover_fg })
                                 .when(is_selected, |el: Stateful<Div>| {
                                     el.bg(accent).text_color(accent_fg)
                                 })
                                 .when(!is_disabled, |el: Stateful<Div>| {
-                                    el.cursor_pointer().hover(|style| style.bg(accent))
+                                    el.cursor_pointer()
+                                        .hover(move |style| style.bg(hover_bg))
+                                        .active(move |style| style.bg(active_bg))
+                                })
+                                .when(is_disabled, |el: Stateful<Div>| {
+                                    el.opacity(states.disabled_opacity)
                                 })
-                                .when(is_disabled, |el: Stateful<Div>| el.opacity(0.5))
                                 .when(!is_disabled, |el: Stateful<Div>| {
                                     el.on_click({
                                         let val = item_value.clone();
@@ -295,6 +327,9 @@ impl RenderOnce for Select {
                                     })
                                 })
                                 .child(item.label.clone())
+                                .when(is_selected, |el: Stateful<Div>| {
+                                    el.child(Icon::new(IconName::Check).color(accent_fg))
+                                })
                         })),
                 )
             })
@@ -52,7 +52,7 @@ impl Separator {
 
 impl RenderOnce for Separator {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let el = div().flex_shrink_0().bg(colors.border);
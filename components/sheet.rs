@@ -128,7 +128,7 @@ impl ParentElement for Sheet {
 impl RenderOnce for Sheet {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let backdrop_color = gpui::hsla(0.0, 0.0, 0.0, 0.8);
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let popover_bg = colors.popover;
@@ -167,7 +167,7 @@ impl RenderOnce for Sheet {
                                 .absolute()
                                 .bg(popover_bg)
                                 .text_color(popover_fg)
-                                .shadow_lg();
+                                .shadow(theme.shadows.lg.to_box_shadows());
 
                             inner = match side {
                                 SheetSide::Right => inner
@@ -234,15 +234,15 @@ impl ParentElement for SheetContent {
 
 impl RenderOnce for SheetContent {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .id("sheet-content")
             .flex()
             .flex_col()
-            .gap(px(16.0))
-            .p(px(24.0))
+            .gap(px(theme.spacing.lg))
+            .p(px(theme.spacing.xl))
             .h_full()
             .overflow_y_scroll()
             .bg(colors.popover)
@@ -277,11 +277,12 @@ impl ParentElement for SheetHeader {
 }
 
 impl RenderOnce for SheetHeader {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_col()
-            .gap(px(6.0))
+            .gap(px((theme.spacing.xs + theme.spacing.sm) / 2.0))
             .children(self.children)
     }
 }
@@ -302,9 +303,10 @@ impl SheetTitle {
 }
 
 impl RenderOnce for SheetTitle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
-            .text_lg()
+            .text_size(px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .child(self.text)
@@ -328,10 +330,10 @@ impl SheetDescription {
 
 impl RenderOnce for SheetDescription {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_sm()
+            .text_size(px(theme.typography.sm))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -361,12 +363,13 @@ impl ParentElement for SheetFooter {
 }
 
 impl RenderOnce for SheetFooter {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex()
             .flex_row()
             .justify_end()
-            .gap(px(8.0))
+            .gap(px(theme.spacing.sm))
             .children(self.children)
     }
 }
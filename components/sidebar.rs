@@ -90,7 +90,7 @@ impl ParentElement for Sidebar {
 
 impl RenderOnce for Sidebar {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let border_color = colors.border;
@@ -144,14 +144,14 @@ impl ParentElement for SidebarHeader {
 
 impl RenderOnce for SidebarHeader {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .flex()
             .items_center()
-            .h(px(56.0))
-            .px(px(16.0))
+            .h(px(theme.controls.lg + theme.spacing.lg))
+            .px(px(theme.spacing.lg))
             .border_b_1()
             .border_color(colors.border)
             .children(self.children)
@@ -182,13 +182,14 @@ impl ParentElement for SidebarContent {
 }
 
 impl RenderOnce for SidebarContent {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .id("sidebar-content")
             .flex_1()
             .flex()
             .flex_col()
-            .py(px(8.0))
+            .py(px(theme.spacing.sm))
             .overflow_y_scroll()
             .children(self.children)
     }
@@ -219,14 +220,14 @@ impl ParentElement for SidebarFooter {
 
 impl RenderOnce for SidebarFooter {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .flex()
             .items_center()
-            .h(px(56.0))
-            .px(px(16.0))
+            .h(px(theme.controls.lg + theme.spacing.lg))
+            .px(px(theme.spacing.lg))
             .border_t_1()
             .border_color(colors.border)
             .children(self.children)
@@ -10,6 +10,9 @@
 //! you would need to implement a custom GPUI element with hit-testing and
 //! drag handling.
 //!
+//! The theme slots `slider.track`, `slider.range`, `slider.thumb` and
+//! `slider.thumb_border` override the track, filled range and thumb colors.
+//!
 //! # Example
 //! ```rust
 //! Slider::new("volume")
@@ -138,11 +141,12 @@ impl Slider {
 
 impl RenderOnce for Slider {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
-        let primary = theme.colors.primary;
-        let muted = theme.colors.muted;
-        let background = theme.colors.background;
-        let border = theme.colors.border;
+        let theme = Theme::of(cx);
+        let primary = theme.slot("slider.range", theme.colors.primary);
+        let muted = theme.slot("slider.track", theme.colors.muted);
+        let background = theme.slot("slider.thumb", theme.colors.background);
+        let border = theme.slot("slider.thumb_border", theme.colors.border);
+        let disabled_opacity = theme.states.disabled_opacity;
 
         let fraction = self.fraction();
         let track_width = self.width;
@@ -159,7 +163,7 @@ impl RenderOnce for Slider {
             .w(px(track_width))
             .h(px(THUMB_SIZE))
             .when(!disabled, |el: Stateful<Div>| el.cursor_pointer())
-            .when(disabled, |el: Stateful<Div>| el.opacity(0.5))
+            .when(disabled, |el: Stateful<Div>| el.opacity(disabled_opacity))
             // Unfilled track (full width background)
             .child(
                 div()
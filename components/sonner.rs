@@ -3,6 +3,8 @@
 //! A stacked toast notification manager that renders multiple toasts at a
 //! configurable screen position.
 //!
+//! Toasts share the Toast's theme slots, `toast.<variant>.<part>`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -38,6 +40,16 @@ pub enum SonnerVariant {
     Destructive,
 }
 
+impl SonnerVariant {
+    /// The variant's name in theme slots, e.g. `destructive`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            SonnerVariant::Default => "default",
+            SonnerVariant::Destructive => "destructive",
+        }
+    }
+}
+
 /// Position for the sonner toast stack on screen.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum SonnerPosition {
@@ -113,7 +125,7 @@ impl Sonner {
 
 impl RenderOnce for Sonner {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = theme.colors.clone();
 
         if self.toasts.is_empty() {
@@ -125,7 +137,7 @@ impl RenderOnce for Sonner {
             .absolute()
             .flex()
             .flex_col()
-            .gap(px(8.0));
+            .gap(px(theme.spacing.sm));
 
         // Apply position
         container = match self.position {
@@ -154,6 +166,12 @@ impl RenderOnce for Sonner {
                     colors.destructive,
                 ),
             };
+            let variant = toast.variant.slot_name();
+            let slot = |part: &str, fallback: gpui::Hsla| {
+                theme.slot(&format!("toast.{}.{}", variant, part), fallback)
+            };
+            let (bg, fg, border_color) =
+                (slot("bg", bg), slot("fg", fg), slot("border", border_color));
 
             let toast_id = toast.id.clone();
             let on_dismiss = self.on_dismiss.clone();
@@ -163,8 +181,8 @@ impl RenderOnce for Sonner {
                 .rounded_lg()
                 .border_1()
                 .border_color(border_color)
-                .shadow_md()
-                .p(px(16.0))
+                .shadow(theme.shadows.md.to_box_shadows())
+                .p(px(theme.spacing.lg))
                 .bg(bg)
                 .text_color(fg)
                 .flex()
@@ -174,15 +192,20 @@ impl RenderOnce for Sonner {
                     div()
                         .flex()
                         .flex_col()
-                        .gap(px(4.0))
+                        .gap(px(theme.spacing.xs))
                         .child(
                             div()
-                                .text_sm()
+                                .text_size(px(theme.typography.sm))
                                 .font_weight(FontWeight::SEMIBOLD)
                                 .child(toast.title),
                         )
                         .when_some(toast.description, |el: Div, desc| {
-                            el.child(div().text_sm().opacity(0.9).child(desc))
+                            el.child(
+                                div()
+                                    .text_size(px(theme.typography.sm))
+                                    .opacity(0.9)
+                                    .child(desc),
+                            )
                         }),
                 );
 
@@ -2,6 +2,9 @@
 //!
 //! A binary on/off control styled as a sliding toggle.
 //!
+//! The theme slots `switch.checked_bg`, `switch.unchecked_bg` and
+//! `switch.thumb` override the track and thumb colors.
+//!
 //! # Example
 //! ```rust
 //! Switch::new("notifications")
@@ -37,6 +40,7 @@ pub struct Switch {
     id: ElementId,
     checked: bool,
     disabled: bool,
+    focused: bool,
     #[allow(clippy::type_complexity)]
     on_change: Option<Rc<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
 }
@@ -48,6 +52,7 @@ impl Switch {
             id: id.into(),
             checked: false,
             disabled: false,
+            focused: false,
             on_change: None,
         }
     }
@@ -64,6 +69,12 @@ impl Switch {
         self
     }
 
+    /// Draw the theme's focus ring, e.g. while the switch has keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Register a callback for when the switch is toggled.
     /// The callback receives the new checked state.
     pub fn on_change(
@@ -77,16 +88,23 @@ impl Switch {
 
 impl RenderOnce for Switch {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
-        let primary = theme.colors.primary;
-        let muted = theme.colors.muted;
-        let background = theme.colors.background;
+        let theme = Theme::of(cx);
+        let primary = theme.slot("switch.checked_bg", theme.colors.primary);
+        let muted = theme.slot("switch.unchecked_bg", theme.colors.muted);
+        let background = theme.slot("switch.thumb", theme.colors.background);
+        let states = theme.states;
         let checked = self.checked;
         let disabled = self.disabled;
+        let focused = self.focused && !disabled;
         let on_change = self.on_change;
 
         // Track background: primary when on, muted when off
         let track_bg = if checked { primary } else { muted };
+        let (hover_bg, active_bg) = if checked {
+            (states.primary.hovered(primary), states.primary.pressed(primary))
+        } else {
+            (states.accent.hovered(muted), states.accent.pressed(muted))
+        };
 
         // Thumb offset: left when off, right when on
         let thumb_offset = if checked {
@@ -103,14 +121,17 @@ impl RenderOnce for Switch {
             .h(px(TRACK_HEIGHT))
             .rounded(px(TRACK_HEIGHT / 2.0))
             .bg(track_bg)
-            .when(!disabled, |el: Stateful<Div>| el.cursor_pointer())
-            .when(disabled, |el: Stateful<Div>| el.opacity(0.5))
+            .when(focused, |el: Stateful<Div>| el.shadow(theme.focus_ring()))
+            .when(disabled, |el: Stateful<Div>| el.opacity(states.disabled_opacity))
             .when(!disabled, |el: Stateful<Div>| {
-                el.on_click(move |_event: &ClickEvent, window, cx| {
-                    if let Some(ref handler) = on_change {
-                        handler(!checked, window, cx);
-                    }
-                })
+                el.cursor_pointer()
+                    .hover(move |s| s.bg(hover_bg))
+                    .active(move |s| s.bg(active_bg))
+                    .on_click(move |_event: &ClickEvent, window, cx| {
+                        if let Some(ref handler) = on_change {
+                            handler(!checked, window, cx);
+                        }
+                    })
             })
             .child(
                 // Thumb
@@ -136,6 +157,7 @@ mod tests {
         let switch = Switch::new("test");
         assert!(!switch.checked);
         assert!(!switch.disabled);
+        assert!(!switch.focused);
         assert!(switch.on_change.is_none());
     }
 
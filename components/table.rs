@@ -48,12 +48,13 @@ impl ParentElement for Table {
 }
 
 impl RenderOnce for Table {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .w_full()
             .flex()
             .flex_col()
-            .text_sm()
+            .text_size(px(theme.typography.sm))
             .children(self.children)
     }
 }
@@ -141,10 +142,10 @@ impl ParentElement for TableRow {
 
 impl RenderOnce for TableRow {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
-        let hover_bg = colors.muted;
+        let hover_bg = theme.states.accent.hovered(colors.muted);
 
         div()
             .flex()
@@ -174,15 +175,15 @@ impl TableHead {
 
 impl RenderOnce for TableHead {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
             .flex_1()
             .flex()
             .items_center()
-            .h(px(48.0))
-            .px(px(16.0))
+            .h(px(theme.controls.lg + theme.spacing.sm))
+            .px(px(theme.spacing.lg))
             .text_color(colors.muted_foreground)
             .font_weight(FontWeight::MEDIUM)
             .child(self.text)
@@ -213,13 +214,14 @@ impl ParentElement for TableCell {
 }
 
 impl RenderOnce for TableCell {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .flex_1()
             .flex()
             .items_center()
-            .py(px(16.0))
-            .px(px(16.0))
+            .py(px(theme.spacing.lg))
+            .px(px(theme.spacing.lg))
             .children(self.children)
     }
 }
@@ -241,12 +243,12 @@ impl TableCaption {
 
 impl RenderOnce for TableCaption {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         div()
-            .mt(px(16.0))
-            .text_sm()
+            .mt(px(theme.spacing.lg))
+            .text_size(px(theme.typography.sm))
             .text_color(colors.muted_foreground)
             .child(self.text)
     }
@@ -2,6 +2,10 @@
 //!
 //! Tabbed content panels with a tab list and switchable content areas.
 //!
+//! The theme slots `tabs.list_bg`, `tabs.fg`, `tabs.active_bg`,
+//! `tabs.active_fg` and `tabs.hover_bg` override the tab list and trigger
+//! colors.
+//!
 //! # Example
 //!
 //! ```rust
@@ -110,17 +114,17 @@ impl ParentElement for TabsList {
 
 impl RenderOnce for TabsList {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let radius = theme.radius.to_px();
 
         div()
             .flex()
             .flex_row()
             .items_center()
-            .h(px(40.0))
+            .h(px(theme.controls.lg))
             .rounded(px(radius))
-            .bg(theme.colors.muted)
-            .p(px(4.0))
+            .bg(theme.slot("tabs.list_bg", theme.colors.muted))
+            .p(px(theme.spacing.xs))
             .children(self.children)
     }
 }
@@ -177,23 +181,27 @@ impl TabsTrigger {
 
 impl RenderOnce for TabsTrigger {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius.to_px();
 
-        let bg_color = colors.background;
-        let fg_color = colors.foreground;
-        let muted_fg = colors.muted_foreground;
-        let hover_bg = colors.accent;
+        let bg_color = theme.slot("tabs.active_bg", colors.background);
+        let fg_color = theme.slot("tabs.active_fg", colors.foreground);
+        let muted_fg = theme.slot("tabs.fg", colors.muted_foreground);
+        let states = theme.states;
+        let (hover_bg, active_bg) = (
+            theme.slot("tabs.hover_bg", states.accent.hovered(colors.accent)),
+            states.accent.pressed(colors.accent),
+        );
 
         let mut el = div()
             .id(self.id)
             .flex()
             .items_center()
             .justify_center()
-            .px(px(12.0))
-            .py(px(6.0))
-            .text_sm()
+            .px(px(theme.spacing.md))
+            .py(px((theme.spacing.xs + theme.spacing.sm) / 2.0))
+            .text_size(px(theme.typography.sm))
             .font_weight(FontWeight::MEDIUM)
             .cursor_pointer();
 
@@ -201,17 +209,19 @@ impl RenderOnce for TabsTrigger {
             el = el
                 .bg(bg_color)
                 .text_color(fg_color)
-                .shadow_sm()
+                .shadow(theme.shadows.sm.to_box_shadows())
                 .rounded(px(radius - 2.0));
+        } else if self.disabled {
+            el = el.text_color(colors.disabled_foreground);
         } else {
-            el = el.text_color(muted_fg);
-            if !self.disabled {
-                el = el.hover(move |style| style.bg(hover_bg));
-            }
+            el = el
+                .text_color(muted_fg)
+                .hover(move |style| style.bg(hover_bg))
+                .active(move |style| style.bg(active_bg));
         }
 
         if self.disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(states.disabled_opacity).cursor_default();
         }
 
         if !self.disabled {
@@ -262,9 +272,10 @@ impl ParentElement for TabsContent {
 }
 
 impl RenderOnce for TabsContent {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div().when(self.active, |el: Div| {
-            el.mt(px(8.0)).children(self.children)
+            el.mt(px(theme.spacing.sm)).children(self.children)
         })
     }
 }
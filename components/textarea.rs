@@ -31,6 +31,7 @@ pub struct Textarea {
     placeholder: Option<SharedString>,
     value: Option<SharedString>,
     disabled: bool,
+    focused: bool,
     min_rows: u32,
 }
 
@@ -42,6 +43,7 @@ impl Textarea {
             placeholder: None,
             value: None,
             disabled: false,
+            focused: false,
             min_rows: 3,
         }
     }
@@ -64,6 +66,13 @@ impl Textarea {
         self
     }
 
+    /// Draw the theme's focus ring, e.g. while the textarea has keyboard
+    /// focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Set the minimum number of visible rows.
     pub fn min_rows(mut self, min_rows: u32) -> Self {
         self.min_rows = min_rows;
@@ -73,7 +82,7 @@ impl Textarea {
 
 impl RenderOnce for Textarea {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
         let radius = theme.radius;
 
@@ -88,7 +97,9 @@ impl RenderOnce for Textarea {
         };
 
         let is_placeholder = self.value.as_ref().is_none_or(|v| v.is_empty());
-        let text_col = if is_placeholder {
+        let text_col = if self.disabled {
+            colors.disabled_foreground
+        } else if is_placeholder {
             colors.muted_foreground
         } else {
             colors.foreground
@@ -97,22 +108,27 @@ impl RenderOnce for Textarea {
         let focus_border = colors.ring;
         let border_color = colors.input;
 
-        // Line height ~20px, plus vertical padding (6px top + 6px bottom = 12px)
-        let min_height = px(self.min_rows as f32 * 20.0 + 12.0);
+        // Halfway between the xs and sm spacing steps: 6px at default density
+        let padding_y = (theme.spacing.xs + theme.spacing.sm) / 2.0;
+        // Line height ~20px, plus vertical padding top and bottom
+        let min_height = px(self.min_rows as f32 * 20.0 + padding_y * 2.0);
 
         let mut el = apply_radius(div().id(self.id).flex().w_full(), radius)
             .min_h(min_height)
             .border_1()
             .border_color(border_color)
             .bg(colors.background)
-            .px(px(12.0))
-            .py(px(6.0))
-            .text_sm()
+            .px(px(theme.spacing.md))
+            .py(px(padding_y))
+            .text_size(px(theme.typography.sm))
             .text_color(text_col);
 
         if self.disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(theme.states.disabled_opacity).cursor_default();
         } else {
+            if self.focused {
+                el = el.border_color(focus_border).shadow(theme.focus_ring());
+            }
             el = el
                 .cursor_text()
                 .hover(move |s| s.border_color(focus_border));
@@ -143,6 +159,7 @@ mod tests {
         assert!(textarea.placeholder.is_none());
         assert!(textarea.value.is_none());
         assert!(!textarea.disabled);
+        assert!(!textarea.focused);
         assert_eq!(textarea.min_rows, 3);
     }
 
@@ -2,6 +2,10 @@
 //!
 //! A temporary notification that appears at the edge of the screen.
 //!
+//! Colors can be overridden per variant through theme slots named
+//! `toast.<variant>.<part>`, where part is `bg`, `fg` or `border`, e.g.
+//! `toast.destructive.bg`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -28,6 +32,16 @@ pub enum ToastVariant {
     Destructive,
 }
 
+impl ToastVariant {
+    /// The variant's name in theme slots, e.g. `destructive`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            ToastVariant::Default => "default",
+            ToastVariant::Destructive => "destructive",
+        }
+    }
+}
+
 /// A toast notification component.
 ///
 /// Renders as a card-like overlay at the bottom-right of the screen.
@@ -91,7 +105,7 @@ impl Toast {
 
 impl RenderOnce for Toast {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let (bg, fg, border_color) = match self.variant {
@@ -103,6 +117,13 @@ impl RenderOnce for Toast {
             ),
         };
 
+        // Let the theme override individual colors, e.g. `toast.destructive.bg`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: gpui::Hsla| {
+            theme.slot(&format!("toast.{}.{}", variant, part), fallback)
+        };
+        let (bg, fg, border_color) = (slot("bg", bg), slot("fg", fg), slot("border", border_color));
+
         div().when(self.open, move |el: Div| {
             el.child(
                 deferred(
@@ -115,13 +136,13 @@ impl RenderOnce for Toast {
                         .rounded_lg()
                         .border_1()
                         .border_color(border_color)
-                        .shadow_lg()
-                        .p(px(16.0))
+                        .shadow(theme.shadows.lg.to_box_shadows())
+                        .p(px(theme.spacing.lg))
                         .bg(bg)
                         .text_color(fg)
                         .flex()
                         .flex_col()
-                        .gap(px(8.0))
+                        .gap(px(theme.spacing.sm))
                         .child(
                             div()
                                 .flex()
@@ -131,16 +152,19 @@ impl RenderOnce for Toast {
                                     div()
                                         .flex()
                                         .flex_col()
-                                        .gap(px(4.0))
+                                        .gap(px(theme.spacing.xs))
                                         .child(
                                             div()
-                                                .text_sm()
+                                                .text_size(px(theme.typography.sm))
                                                 .font_weight(FontWeight::SEMIBOLD)
                                                 .child(self.title),
                                         )
                                         .when_some(self.description, |el, desc| {
                                             el.child(
-                                                div().text_sm().opacity(0.9).child(desc),
+                                                div()
+                                                    .text_size(px(theme.typography.sm))
+                                                    .opacity(0.9)
+                                                    .child(desc),
                                             )
                                         }),
                                 )
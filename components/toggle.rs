@@ -2,6 +2,11 @@
 //!
 //! A two-state button that can be either on or off.
 //!
+//! Colors can be overridden per variant through theme slots named
+//! `toggle.<variant>.<part>`, where part is `bg`, `fg`, `on_bg`, `on_fg`,
+//! `border` or `hover_bg`, e.g. `toggle.outline.on_bg`. `on_` parts apply
+//! while the toggle is pressed.
+//!
 //! # Example
 //!
 //! ```rust
@@ -31,6 +36,16 @@ pub enum ToggleVariant {
     Outline,
 }
 
+impl ToggleVariant {
+    /// The variant's name in theme slots, e.g. `outline`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            ToggleVariant::Default => "default",
+            ToggleVariant::Outline => "outline",
+        }
+    }
+}
+
 /// Toggle size presets
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ToggleSize {
@@ -54,6 +69,7 @@ pub struct Toggle {
     variant: ToggleVariant,
     size: ToggleSize,
     disabled: bool,
+    focused: bool,
     #[allow(clippy::type_complexity)]
     on_press_change: Option<Box<dyn Fn(bool, &mut Window, &mut App) + 'static>>,
     children: Vec<AnyElement>,
@@ -68,6 +84,7 @@ impl Toggle {
             variant: ToggleVariant::Default,
             size: ToggleSize::Default,
             disabled: false,
+            focused: false,
             on_press_change: None,
             children: Vec::new(),
         }
@@ -97,6 +114,12 @@ impl Toggle {
         self
     }
 
+    /// Draw the theme's focus ring, e.g. while the toggle has keyboard focus.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Set the callback for when the pressed state changes.
     pub fn on_press_change(
         mut self,
@@ -115,14 +138,24 @@ impl ParentElement for Toggle {
 
 impl RenderOnce for Toggle {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
+        let states = theme.states;
+
+        // Let the theme override individual colors, e.g. `toggle.outline.on_bg`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: Hsla| {
+            theme.slot(&format!("toggle.{}.{}", variant, part), fallback)
+        };
+        let on_bg = slot("on_bg", colors.accent);
 
         // Determine background and text colors based on pressed state
         let (bg_color, text_color): (Hsla, Hsla) = if self.pressed {
-            (colors.accent, colors.accent_foreground)
+            (on_bg, slot("on_fg", colors.accent_foreground))
+        } else if self.disabled {
+            (slot("bg", gpui::transparent_black()), colors.disabled_foreground)
         } else {
-            (gpui::transparent_black(), colors.foreground)
+            (slot("bg", gpui::transparent_black()), slot("fg", colors.foreground))
         };
 
         // Build the base element
@@ -132,62 +165,47 @@ impl RenderOnce for Toggle {
             .items_center()
             .justify_center()
             .rounded_md()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .font_weight(FontWeight::MEDIUM)
             .cursor_pointer()
             .bg(bg_color)
             .text_color(text_color);
 
-        // Apply size
-        match self.size {
-            ToggleSize::Sm => {
-                el = el
-                    .h(gpui::px(36.0))
-                    .px(gpui::px(10.0))
-                    .min_w(gpui::px(36.0));
-            }
-            ToggleSize::Default => {
-                el = el
-                    .h(gpui::px(40.0))
-                    .px(gpui::px(12.0))
-                    .min_w(gpui::px(40.0));
-            }
-            ToggleSize::Lg => {
-                el = el
-                    .h(gpui::px(44.0))
-                    .px(gpui::px(20.0))
-                    .min_w(gpui::px(44.0));
-            }
-        }
+        // Apply size from the theme's toggle scale
+        let toggles = &theme.toggles;
+        let (height, padding) = match self.size {
+            ToggleSize::Sm => (toggles.sm, toggles.padding_sm),
+            ToggleSize::Default => (toggles.md, toggles.padding_md),
+            ToggleSize::Lg => (toggles.lg, toggles.padding_lg),
+        };
+        el = el
+            .h(gpui::px(height))
+            .px(gpui::px(padding))
+            .min_w(gpui::px(height));
 
         // Apply variant-specific styles
         match self.variant {
             ToggleVariant::Default => {}
             ToggleVariant::Outline => {
-                el = el.border_1().border_color(colors.input);
-                if self.pressed {
-                    el = el.bg(colors.accent);
-                }
+                el = el.border_1().border_color(slot("border", colors.input));
             }
         }
 
         // Apply disabled styling
         if self.disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(states.disabled_opacity).cursor_default();
+        } else if self.focused {
+            el = el.shadow(theme.focus_ring());
         }
 
-        // Hover effect (only when not disabled)
-        let hover_bg = colors.muted;
-        let accent_bg = colors.accent;
-        let pressed = self.pressed;
+        // Hover and pressed effects (only when not disabled)
+        let hover_surface = if self.pressed { on_bg } else { colors.muted };
+        let hover_bg = slot("hover_bg", states.accent.hovered(hover_surface));
+        let active_bg = states.accent.pressed(on_bg);
         if !self.disabled {
-            el = el.hover(move |style| {
-                if pressed {
-                    style.bg(accent_bg)
-                } else {
-                    style.bg(hover_bg)
-                }
-            });
+            el = el
+                .hover(move |style| style.bg(hover_bg))
+                .active(move |style| style.bg(active_bg));
         }
 
         // Click handler
@@ -215,12 +233,14 @@ mod tests {
             .pressed(true)
             .variant(ToggleVariant::Outline)
             .size(ToggleSize::Lg)
-            .disabled(false);
+            .disabled(false)
+            .focused(true);
 
         assert!(toggle.pressed);
         assert_eq!(toggle.variant, ToggleVariant::Outline);
         assert_eq!(toggle.size, ToggleSize::Lg);
         assert!(!toggle.disabled);
+        assert!(toggle.focused);
     }
 
     #[test]
@@ -3,6 +3,8 @@
 //! A set of two-state buttons that can be toggled on or off.
 //! Supports single selection (radio-like) and multiple selection modes.
 //!
+//! Items share the Toggle's theme slots, `toggle.<variant>.<part>`.
+//!
 //! # Example
 //!
 //! ```rust
@@ -52,6 +54,16 @@ pub enum ToggleGroupVariant {
     Outline,
 }
 
+impl ToggleGroupVariant {
+    /// The variant's name in theme slots, e.g. `outline`.
+    fn slot_name(self) -> &'static str {
+        match self {
+            ToggleGroupVariant::Default => "default",
+            ToggleGroupVariant::Outline => "outline",
+        }
+    }
+}
+
 /// Toggle group size (applied to all items)
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ToggleGroupSize {
@@ -140,13 +152,14 @@ impl ParentElement for ToggleGroup {
 }
 
 impl RenderOnce for ToggleGroup {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = Theme::of(cx);
         div()
             .id(self.id)
             .flex()
             .items_center()
             .justify_center()
-            .gap(gpui::px(4.0))
+            .gap(gpui::px(theme.spacing.xs))
             .children(self.children)
     }
 }
@@ -229,14 +242,24 @@ impl ParentElement for ToggleGroupItem {
 
 impl RenderOnce for ToggleGroupItem {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
+        let states = theme.states;
+
+        // Let the theme override individual colors, e.g. `toggle.outline.on_bg`
+        let variant = self.variant.slot_name();
+        let slot = |part: &str, fallback: Hsla| {
+            theme.slot(&format!("toggle.{}.{}", variant, part), fallback)
+        };
+        let on_bg = slot("on_bg", colors.accent);
 
         // Determine colors based on pressed state
         let (bg_color, text_color): (Hsla, Hsla) = if self.pressed {
-            (colors.accent, colors.accent_foreground)
+            (on_bg, slot("on_fg", colors.accent_foreground))
+        } else if self.disabled {
+            (slot("bg", gpui::transparent_black()), colors.disabled_foreground)
         } else {
-            (gpui::transparent_black(), colors.foreground)
+            (slot("bg", gpui::transparent_black()), slot("fg", colors.foreground))
         };
 
         let mut el = div()
@@ -245,62 +268,45 @@ impl RenderOnce for ToggleGroupItem {
             .items_center()
             .justify_center()
             .rounded_md()
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
             .font_weight(FontWeight::MEDIUM)
             .cursor_pointer()
             .bg(bg_color)
             .text_color(text_color);
 
-        // Apply size
-        match self.size {
-            ToggleGroupSize::Sm => {
-                el = el
-                    .h(gpui::px(36.0))
-                    .px(gpui::px(10.0))
-                    .min_w(gpui::px(36.0));
-            }
-            ToggleGroupSize::Default => {
-                el = el
-                    .h(gpui::px(40.0))
-                    .px(gpui::px(12.0))
-                    .min_w(gpui::px(40.0));
-            }
-            ToggleGroupSize::Lg => {
-                el = el
-                    .h(gpui::px(44.0))
-                    .px(gpui::px(20.0))
-                    .min_w(gpui::px(44.0));
-            }
-        }
+        // Apply size from the theme's toggle scale
+        let toggles = &theme.toggles;
+        let (height, padding) = match self.size {
+            ToggleGroupSize::Sm => (toggles.sm, toggles.padding_sm),
+            ToggleGroupSize::Default => (toggles.md, toggles.padding_md),
+            ToggleGroupSize::Lg => (toggles.lg, toggles.padding_lg),
+        };
+        el = el
+            .h(gpui::px(height))
+            .px(gpui::px(padding))
+            .min_w(gpui::px(height));
 
         // Apply variant
         match self.variant {
             ToggleGroupVariant::Default => {}
             ToggleGroupVariant::Outline => {
-                el = el.border_1().border_color(colors.input);
-                if self.pressed {
-                    el = el.bg(colors.accent);
-                }
+                el = el.border_1().border_color(slot("border", colors.input));
             }
         }
 
         // Disabled state
         if self.disabled {
-            el = el.opacity(0.5).cursor_default();
+            el = el.opacity(states.disabled_opacity).cursor_default();
         }
 
-        // Hover effect
-        let hover_bg = colors.muted;
-        let accent_bg = colors.accent;
-        let pressed = self.pressed;
+        // Hover and pressed effects
+        let hover_surface = if self.pressed { on_bg } else { colors.muted };
+        let hover_bg = slot("hover_bg", states.accent.hovered(hover_surface));
+        let active_bg = states.accent.pressed(on_bg);
         if !self.disabled {
-            el = el.hover(move |style| {
-                if pressed {
-                    style.bg(accent_bg)
-                } else {
-                    style.bg(hover_bg)
-                }
-            });
+            el = el
+                .hover(move |style| style.bg(hover_bg))
+                .active(move |style| style.bg(active_bg));
         }
 
         // Click handler
@@ -86,7 +86,7 @@ impl ParentElement for Tooltip {
 
 impl RenderOnce for Tooltip {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
         let colors = &theme.colors;
 
         let popover_bg = colors.popover;
@@ -105,16 +105,16 @@ impl RenderOnce for Tooltip {
                     .border_1()
                     .border_color(border_color)
                     .rounded_md()
-                    .shadow_md()
-                    .px(px(12.0))
-                    .py(px(6.0))
-                    .text_sm()
+                    .shadow(theme.shadows.md.to_box_shadows())
+                    .px(px(theme.spacing.md))
+                    .py(px((theme.spacing.xs + theme.spacing.sm) / 2.0))
+                    .text_size(px(theme.typography.sm))
                     .whitespace_nowrap()
                     .child(self.text);
 
                 let positioned = match self.side {
-                    TooltipSide::Top => overlay.bottom_full().mb(px(4.0)),
-                    TooltipSide::Bottom => overlay.top_full().mt(px(4.0)),
+                    TooltipSide::Top => overlay.bottom_full().mb(px(theme.spacing.xs)),
+                    TooltipSide::Bottom => overlay.top_full().mt(px(theme.spacing.xs)),
                     TooltipSide::Left => overlay.right_full().mr(px(4.0)),
                     TooltipSide::Right => overlay.left_full().ml(px(4.0)),
                 };
@@ -41,7 +41,7 @@ impl H1 {
 
 impl RenderOnce for H1 {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
             .text_3xl()
@@ -73,10 +73,10 @@ impl H2 {
 
 impl RenderOnce for H2 {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_2xl()
+            .text_size(gpui::px(theme.typography.xxl))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(2.0))
             .text_color(theme.colors.foreground)
@@ -105,10 +105,10 @@ impl H3 {
 
 impl RenderOnce for H3 {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_xl()
+            .text_size(gpui::px(theme.typography.xl))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .text_color(theme.colors.foreground)
@@ -137,10 +137,10 @@ impl H4 {
 
 impl RenderOnce for H4 {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_lg()
+            .text_size(gpui::px(theme.typography.lg))
             .font_weight(FontWeight::SEMIBOLD)
             .line_height(gpui::rems(1.75))
             .text_color(theme.colors.foreground)
@@ -180,10 +180,10 @@ impl ParentElement for Paragraph {
 
 impl RenderOnce for Paragraph {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_base()
+            .text_size(gpui::px(theme.typography.base))
             .line_height(gpui::rems(1.75))
             .text_color(theme.colors.foreground)
             .child(self.text)
@@ -221,12 +221,12 @@ impl ParentElement for Blockquote {
 
 impl RenderOnce for Blockquote {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
             .border_l_2()
             .border_color(theme.colors.border)
-            .pl(gpui::px(16.0))
+            .pl(gpui::px(theme.spacing.lg))
             .italic()
             .text_color(theme.colors.muted_foreground)
             .children(self.children)
@@ -239,8 +239,8 @@ impl RenderOnce for Blockquote {
 
 /// Inline code element.
 ///
-/// Renders with muted background, small rounded corners, and monospace-style
-/// smaller text.
+/// Renders with muted background, small rounded corners, and smaller text in
+/// the theme's monospace font.
 #[derive(IntoElement)]
 pub struct InlineCode {
     text: SharedString,
@@ -255,14 +255,15 @@ impl InlineCode {
 
 impl RenderOnce for InlineCode {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
             .bg(theme.colors.muted)
             .rounded_sm()
             .px(gpui::px(4.0))
             .py(gpui::px(1.0))
-            .text_sm()
+            .text_size(gpui::px(theme.typography.sm))
+            .font_family(theme.typography.mono_font_family.clone())
             .font_weight(FontWeight::MEDIUM)
             .text_color(theme.colors.foreground)
             .child(self.text)
@@ -291,10 +292,10 @@ impl Lead {
 
 impl RenderOnce for Lead {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let theme = cx.global::<Theme>();
+        let theme = Theme::of(cx);
 
         div()
-            .text_xl()
+            .text_size(gpui::px(theme.typography.xl))
             .text_color(theme.colors.muted_foreground)
             .child(self.text)
     }
@@ -0,0 +1,40 @@
+//! Shared helpers for shadcn-ui-rs components
+//!
+//! Several components carry private copies of these helpers. When the
+//! `shared_utils` install transform is enabled, `shadcn-ui add` installs this
+//! module next to the components and replaces the copies with
+//! `use super::utils::...` imports. The module is only declared in a
+//! project's `mod.rs` once `add` has written it there.
+
+#![allow(dead_code)]
+
+use gpui::prelude::*;
+use gpui::{Div, Hsla, Stateful};
+
+use crate::theme::Radius;
+
+/// Apply the theme's border radius to a styled element.
+pub fn apply_radius(el: Stateful<Div>, radius: Radius) -> Stateful<Div> {
+    match radius {
+        Radius::None => el.rounded_none(),
+        Radius::Sm => el.rounded_sm(),
+        Radius::Md => el.rounded_md(),
+        Radius::Lg => el.rounded_lg(),
+        Radius::Full => el.rounded_full(),
+    }
+}
+
+/// Create a transparent Hsla color.
+pub fn transparent() -> Hsla {
+    Hsla {
+        h: 0.0,
+        s: 0.0,
+        l: 0.0,
+        a: 0.0,
+    }
+}
+
+/// Apply a different opacity to an existing color.
+pub fn with_opacity(color: Hsla, a: f32) -> Hsla {
+    Hsla { a, ..color }
+}
@@ -0,0 +1,1228 @@
+//! Code generation for the project's `theme.rs`.
+//!
+//! `init` and `theme apply` both emit the file through [`generate_theme_rs`].
+//! The color fields come from the token list in
+//! [`shadcn_ui_theme::PaletteColors`], and the typography, spacing, control
+//! height, shadow and state values from the theme crate's defaults, so new
+//! tokens need no template changes.
+//!
+//! With `codegen = "const"` in `[theme]`, palettes are emitted as `const`
+//! items, `ThemeColors::LIGHT` and `ThemeColors::DARK`, instead of being
+//! built in the constructor, and a `themes()` list holds every custom theme
+//! in `themes/` for theme pickers.
+//!
+//! With `theme_crate = true`, the project depends on `shadcn-ui-theme`, and
+//! the file also converts the crate's themes into its own types, re-exports
+//! its embedded fonts and icons, and provides `watch_theme_file` for hot
+//! reloading theme files.
+
+use std::collections::BTreeMap;
+
+use shadcn_ui_theme::{
+    ControlHeights, Density, Hsl, InteractionStates, PaletteColors, PaletteVariant, Radius, Shadow,
+    Shadows, Spacing, StateOpacity, ThemeMode, ToggleSizes, Typography,
+};
+
+use crate::config::ThemeConfig;
+
+/// A theme to emit as a `Theme::<fn_name>(mode)` constructor.
+pub struct ThemeSource<'a> {
+    /// Constructor name, e.g. `zinc` or a custom theme's name.
+    pub fn_name: &'a str,
+    pub light: &'a PaletteColors,
+    pub dark: &'a PaletteColors,
+    /// Component slot colors for light and dark mode, e.g.
+    /// `button.destructive.bg`.
+    pub overrides: [&'a BTreeMap<String, Hsl>; 2],
+    /// Radius set by the theme, replacing the configured one.
+    pub radius: Option<Radius>,
+}
+
+/// Generate the `theme.rs` source file.
+///
+/// The default mode, radius and density come from `config`; unknown values
+/// fall back to the defaults. The configured accessibility variant, if any,
+/// is applied to every palette. With const codegen, `themes` are listed in
+/// `themes()` and registered by name. With `theme_crate`, conversions from
+/// the theme crate are appended. `command` names the CLI command in the file
+/// header, e.g. `"init"`.
+pub fn generate_theme_rs(
+    theme: &ThemeSource,
+    themes: &[ThemeSource],
+    config: &ThemeConfig,
+    command: &str,
+) -> String {
+    let mode = if config.dark_mode { "Dark" } else { "Light" };
+    let config_radius = Radius::from_name(&config.radius).unwrap_or_default();
+    let radius = theme.radius.unwrap_or(config_radius);
+    let density = Density::from_name(&config.density).unwrap_or_default();
+    let variant = config
+        .variant
+        .as_deref()
+        .and_then(PaletteVariant::from_name);
+    let (light, dark) = palettes(theme, variant);
+    let is_const = config.codegen == "const";
+    let typography = Typography::default();
+    let shadows = Shadows::default();
+    let states = InteractionStates::default();
+
+    let fields: String = PaletteColors::TOKENS
+        .iter()
+        .map(|token| format!("    pub {token}: Hsla,\n"))
+        .collect();
+    let colors = |indent: usize, palette: &PaletteColors| -> String {
+        palette
+            .tokens()
+            .into_iter()
+            .map(|(token, value)| format!("{:indent$}{token}: {},\n", "", fmt_hsl(value)))
+            .collect()
+    };
+    let (color_arms, palettes) = if is_const {
+        (
+            "            ThemeMode::Light | ThemeMode::System => ThemeColors::LIGHT,\n            ThemeMode::Dark => ThemeColors::DARK,\n".to_string(),
+            format!(
+                "\nimpl ThemeColors {{\n    /// The {name} palette in light mode.\n    pub const LIGHT: ThemeColors = ThemeColors {{\n{}    }};\n\n    /// The {name} palette in dark mode.\n    pub const DARK: ThemeColors = ThemeColors {{\n{}    }};\n}}\n",
+                colors(8, &light),
+                colors(8, &dark),
+                name = theme.fn_name,
+            ),
+        )
+    } else {
+        (
+            format!(
+                "            ThemeMode::Light | ThemeMode::System => ThemeColors {{\n{}            }},\n            ThemeMode::Dark => ThemeColors {{\n{}            }},\n",
+                colors(16, &light),
+                colors(16, &dark),
+            ),
+            String::new(),
+        )
+    };
+    let mut catalog = if is_const && !themes.is_empty() {
+        catalog(themes, config_radius, density, variant)
+    } else {
+        String::new()
+    };
+    if config.theme_crate {
+        catalog.push_str(&bridge());
+    }
+    let register_catalog = if !is_const || themes.is_empty() {
+        ""
+    } else {
+        "        for theme in themes() {\n            registry.register(theme.name, move |mode| theme.theme(mode));\n        }\n"
+    };
+    let const_fn = if is_const { "const " } else { "" };
+
+    format!(
+        r#"//! Theme configuration for your GPUI project.
+//!
+//! Generated by `shadcn-ui {command}`. Feel free to customize.
+
+use std::collections::HashMap;
+
+use gpui::{{
+    AnyElement, App, Bounds, BoxShadow, Element, ElementId, Global, GlobalElementId, Hsla,
+    InspectorElementId, IntoElement, LayoutId, Pixels, SharedString, TextStyleRefinement, Window,
+    WindowAppearance, point, px,
+}};
+
+/// Theme mode (light, dark, or following the system).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeMode {{
+    Light,
+    Dark,
+    /// Follow the OS appearance. Theme constructors treat it as light; use
+    /// [`ThemeSwitch::set_theme`] to resolve it.
+    System,
+}}
+
+impl ThemeMode {{
+    /// Resolve `System` against a window appearance; other modes are
+    /// returned unchanged.
+    pub fn resolve(self, appearance: WindowAppearance) -> ThemeMode {{
+        match (self, appearance) {{
+            (ThemeMode::System, WindowAppearance::Dark | WindowAppearance::VibrantDark) => {{
+                ThemeMode::Dark
+            }}
+            (ThemeMode::System, _) => ThemeMode::Light,
+            (mode, _) => mode,
+        }}
+    }}
+}}
+
+/// Border radius presets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Radius {{
+    None,
+    Sm,
+    Md,
+    Lg,
+    Full,
+}}
+
+impl Radius {{
+    pub fn to_px(&self) -> f32 {{
+        match self {{
+            Radius::None => 0.0,
+            Radius::Sm => 4.0,
+            Radius::Md => 6.0,
+            Radius::Lg => 8.0,
+            Radius::Full => 9999.0,
+        }}
+    }}
+}}
+
+/// Layout density. Scales spacing and control heights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Density {{
+    Compact,
+    Default,
+    Comfortable,
+}}
+
+/// Font families and type scale. Sizes are in pixels.
+#[derive(Debug, Clone)]
+pub struct Typography {{
+    pub font_family: SharedString,
+    pub mono_font_family: SharedString,
+    pub xs: f32,
+    pub sm: f32,
+    pub base: f32,
+    pub lg: f32,
+    pub xl: f32,
+    pub xxl: f32,
+}}
+
+impl Default for Typography {{
+    fn default() -> Self {{
+        Self {{
+            font_family: {font_family:?}.into(),
+            mono_font_family: {mono_font_family:?}.into(),
+{type_scale}        }}
+    }}
+}}
+
+/// Spacing scale in pixels, used for padding and gaps.
+#[derive(Debug, Clone, Copy)]
+pub struct Spacing {{
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}}
+
+impl Spacing {{
+    /// The spacing scale for a density.
+    pub fn for_density(density: Density) -> Self {{
+        match density {{
+{spacing}        }}
+    }}
+}}
+
+/// Heights of interactive controls such as buttons, inputs and selects,
+/// in pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct ControlHeights {{
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+}}
+
+impl ControlHeights {{
+    /// The control heights for a density.
+    pub fn for_density(density: Density) -> Self {{
+        match density {{
+{controls}        }}
+    }}
+}}
+
+/// Heights and horizontal padding of toggles and toggle group items, in
+/// pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct ToggleSizes {{
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub padding_sm: f32,
+    pub padding_md: f32,
+    pub padding_lg: f32,
+}}
+
+impl ToggleSizes {{
+    /// The toggle sizes for a density.
+    pub fn for_density(density: Density) -> Self {{
+        match density {{
+{toggles}        }}
+    }}
+}}
+
+/// A drop shadow. Offsets and sizes are in pixels; the color is black at
+/// `opacity`.
+#[derive(Debug, Clone, Copy)]
+pub struct Shadow {{
+    pub offset_y: f32,
+    pub blur: f32,
+    pub spread: f32,
+    pub opacity: f32,
+}}
+
+impl Shadow {{
+    /// The shadow as gpui box shadows, for `Styled::shadow`.
+    pub fn to_box_shadows(self) -> Vec<BoxShadow> {{
+        vec![BoxShadow {{
+            color: hsla(0.0, 0.0, 0.0, self.opacity),
+            offset: point(px(0.0), px(self.offset_y)),
+            blur_radius: px(self.blur),
+            spread_radius: px(self.spread),
+        }}]
+    }}
+}}
+
+/// Elevation shadows, from cards (`sm`) to dialogs and menus (`lg`).
+#[derive(Debug, Clone, Copy)]
+pub struct Shadows {{
+    pub sm: Shadow,
+    pub md: Shadow,
+    pub lg: Shadow,
+}}
+
+impl Default for Shadows {{
+    fn default() -> Self {{
+        Self {{
+{shadows}        }}
+    }}
+}}
+
+/// Opacities of a surface color while hovered and while pressed.
+#[derive(Debug, Clone, Copy)]
+pub struct StateOpacity {{
+    pub hover: f32,
+    pub active: f32,
+}}
+
+impl StateOpacity {{
+    /// `color` as drawn while hovered.
+    pub fn hovered(self, color: Hsla) -> Hsla {{
+        Hsla {{
+            a: color.a * self.hover,
+            ..color
+        }}
+    }}
+
+    /// `color` as drawn while pressed.
+    pub fn pressed(self, color: Hsla) -> Hsla {{
+        Hsla {{
+            a: color.a * self.active,
+            ..color
+        }}
+    }}
+}}
+
+/// Ring drawn around focused controls in the `ring` color, separated from
+/// the control by `offset` pixels of the background color.
+#[derive(Debug, Clone, Copy)]
+pub struct FocusRing {{
+    pub width: f32,
+    pub offset: f32,
+}}
+
+impl FocusRing {{
+    /// The ring as gpui box shadows, for `Styled::shadow`.
+    pub fn to_box_shadows(self, color: Hsla, background: Hsla) -> Vec<BoxShadow> {{
+        let ring = |color, spread: f32| BoxShadow {{
+            color,
+            offset: point(px(0.0), px(0.0)),
+            blur_radius: px(0.0),
+            spread_radius: px(spread),
+        }};
+        // Shadows paint first to last, so the gap covers the ring's inner part
+        vec![
+            ring(color, self.offset + self.width),
+            ring(background, self.offset),
+        ]
+    }}
+}}
+
+/// How interactive controls look while hovered, pressed, focused or
+/// disabled.
+///
+/// Filled surfaces fade to their state opacity, e.g. a default button's
+/// `primary` background is drawn at `primary.hover` opacity while hovered.
+/// Transparent controls such as ghost buttons and menu items show `accent`
+/// at the `accent` opacities.
+#[derive(Debug, Clone, Copy)]
+pub struct InteractionStates {{
+    pub primary: StateOpacity,
+    pub secondary: StateOpacity,
+    pub destructive: StateOpacity,
+    pub accent: StateOpacity,
+    pub focus_ring: FocusRing,
+    /// Opacity of disabled controls.
+    pub disabled_opacity: f32,
+}}
+
+impl Default for InteractionStates {{
+    fn default() -> Self {{
+        Self {{
+{states}        }}
+    }}
+}}
+
+/// Complete theme definition.
+#[derive(Clone)]
+pub struct Theme {{
+    pub mode: ThemeMode,
+    pub colors: ThemeColors,
+    pub radius: Radius,
+    pub density: Density,
+    pub typography: Typography,
+    pub spacing: Spacing,
+    pub controls: ControlHeights,
+    pub toggles: ToggleSizes,
+    pub shadows: Shadows,
+    pub states: InteractionStates,
+    /// Per-component colors that take precedence over `colors`; see
+    /// [`Theme::slot`].
+    pub overrides: ComponentOverrides,
+}}
+
+impl Global for Theme {{}}
+
+/// Themes provided by the [`ThemeScope`]s being drawn, innermost last.
+#[derive(Default)]
+struct ThemeScopes(Vec<Theme>);
+
+impl Global for ThemeScopes {{}}
+
+/// Provides a different theme to the elements inside it, e.g. a dark sidebar
+/// in a light window. Components read their theme with [`Theme::of`], which
+/// returns the innermost scope's theme. Text inside the scope uses the
+/// theme's `typography.font_family`.
+///
+/// Content drawn in a deferred layer, such as an open popover, is laid out
+/// after the scope and uses the global theme.
+pub struct ThemeScope {{
+    theme: Option<Theme>,
+    child: AnyElement,
+}}
+
+impl ThemeScope {{
+    /// Draw `child` with `theme`.
+    pub fn new(theme: Theme, child: impl IntoElement) -> Self {{
+        Self {{
+            theme: Some(theme),
+            child: child.into_any_element(),
+        }}
+    }}
+
+    /// Draw `child` with the current theme changed by `f`, e.g. to override
+    /// a few slots.
+    pub fn modify(cx: &App, f: impl FnOnce(&mut Theme), child: impl IntoElement) -> Self {{
+        let mut theme = Theme::of(cx).clone();
+        f(&mut theme);
+        Self::new(theme, child)
+    }}
+
+    /// Run `f` on the child with this scope's theme pushed and its UI font
+    /// applied to text.
+    fn scoped<R>(
+        &mut self,
+        window: &mut Window,
+        cx: &mut App,
+        f: impl FnOnce(&mut AnyElement, &mut Window, &mut App) -> R,
+    ) -> R {{
+        let Some(theme) = self.theme.take() else {{
+            return f(&mut self.child, window, cx);
+        }};
+        let text_style = TextStyleRefinement {{
+            font_family: Some(theme.typography.font_family.clone()),
+            ..Default::default()
+        }};
+        cx.default_global::<ThemeScopes>().0.push(theme);
+        let result =
+            window.with_text_style(Some(text_style), |window| f(&mut self.child, window, cx));
+        self.theme = cx.global_mut::<ThemeScopes>().0.pop();
+        result
+    }}
+}}
+
+impl IntoElement for ThemeScope {{
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {{
+        self
+    }}
+}}
+
+impl Element for ThemeScope {{
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {{
+        None
+    }}
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {{
+        None
+    }}
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {{
+        // Components render during layout, so they see the scoped theme here
+        let layout_id = self.scoped(window, cx, |child, window, cx| {{
+            child.request_layout(window, cx)
+        }});
+        (layout_id, ())
+    }}
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {{
+        self.scoped(window, cx, |child, window, cx| {{
+            child.prepaint(window, cx);
+        }});
+    }}
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {{
+        self.scoped(window, cx, |child, window, cx| child.paint(window, cx));
+    }}
+}}
+
+/// Colors for component slots such as `button.destructive.bg` or
+/// `card.border`. Components look their colors up here first and fall back
+/// to the theme's tokens.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentOverrides {{
+    slots: HashMap<String, Hsla>,
+}}
+
+impl ComponentOverrides {{
+    /// The color set for `slot`, if any.
+    pub fn get(&self, slot: &str) -> Option<Hsla> {{
+        self.slots.get(slot).copied()
+    }}
+
+    /// Set the color for `slot`.
+    pub fn set(&mut self, slot: impl Into<String>, color: Hsla) {{
+        self.slots.insert(slot.into(), color);
+    }}
+
+    /// Builder form of [`ComponentOverrides::set`].
+    pub fn with(mut self, slot: impl Into<String>, color: Hsla) -> Self {{
+        self.set(slot, color);
+        self
+    }}
+}}
+
+/// Theme color palette.
+#[derive(Debug, Clone)]
+pub struct ThemeColors {{
+{fields}}}
+
+/// Helper to convert HSL values to GPUI's Hsla.
+{const_fn}fn hsl(h: f32, s: f32, l: f32) -> Hsla {{
+    hsla(h, s, l, 1.0)
+}}
+
+/// Helper to convert HSL values with alpha to GPUI's Hsla.
+{const_fn}fn hsla(h: f32, s: f32, l: f32, a: f32) -> Hsla {{
+    Hsla {{
+        h: h / 360.0,
+        s: s / 100.0,
+        l: l / 100.0,
+        a,
+    }}
+}}
+{palettes}
+impl Theme {{
+    /// Create the default theme.
+    pub fn default_theme() -> Self {{
+        Self::{name}(ThemeMode::{mode})
+    }}
+
+    /// The theme to draw with: the innermost [`ThemeScope`]'s theme, or the
+    /// global theme outside any scope.
+    pub fn of(cx: &App) -> &Theme {{
+        cx.try_global::<ThemeScopes>()
+            .and_then(|scopes| scopes.0.last())
+            .unwrap_or_else(|| cx.global::<Theme>())
+    }}
+
+    /// Switch density, rescaling spacing, control heights and toggle sizes.
+    pub fn set_density(&mut self, density: Density) {{
+        self.density = density;
+        self.spacing = Spacing::for_density(density);
+        self.controls = ControlHeights::for_density(density);
+        self.toggles = ToggleSizes::for_density(density);
+    }}
+
+    /// The color of a component slot, such as `button.destructive.bg`: the
+    /// override if the theme sets one, otherwise `fallback`.
+    pub fn slot(&self, slot: &str, fallback: Hsla) -> Hsla {{
+        self.overrides.get(slot).unwrap_or(fallback)
+    }}
+
+    /// The focus ring in the theme's `ring` color, for `Styled::shadow`.
+    pub fn focus_ring(&self) -> Vec<BoxShadow> {{
+        self.states
+            .focus_ring
+            .to_box_shadows(self.colors.ring, self.colors.background)
+    }}
+
+    /// Create a theme with the {name} color preset.
+    pub fn {name}(mode: ThemeMode) -> Self {{
+        let colors = match mode {{
+{color_arms}        }};
+
+        Self {{
+            mode,
+            colors,
+            radius: Radius::{radius:?},
+            density: Density::{density:?},
+            typography: Typography::default(),
+            spacing: Spacing::for_density(Density::{density:?}),
+            controls: ControlHeights::for_density(Density::{density:?}),
+            toggles: ToggleSizes::for_density(Density::{density:?}),
+            shadows: Shadows::default(),
+            states: InteractionStates::default(),
+            overrides: {overrides},
+        }}
+    }}
+}}
+
+/// Builds one mode of a theme, e.g. `Theme::{name}`.
+type ThemeBuilder = Box<dyn Fn(ThemeMode) -> Theme>;
+
+/// Named themes available for switching at runtime.
+///
+/// The default registry contains this project's theme; register more with
+/// [`ThemeRegistry::register`].
+pub struct ThemeRegistry {{
+    themes: Vec<(SharedString, ThemeBuilder)>,
+    active: Option<(SharedString, ThemeMode)>,
+}}
+
+impl Global for ThemeRegistry {{}}
+
+impl Default for ThemeRegistry {{
+    fn default() -> Self {{
+        let mut registry = Self {{
+            themes: Vec::new(),
+            active: None,
+        }};
+        registry.register("{name}", Theme::{name});
+{register_catalog}        registry
+    }}
+}}
+
+impl ThemeRegistry {{
+    /// Add a theme, replacing any theme with the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<SharedString>,
+        build: impl Fn(ThemeMode) -> Theme + 'static,
+    ) {{
+        let name = name.into();
+        self.themes.retain(|(existing, _)| *existing != name);
+        self.themes.push((name, Box::new(build)));
+    }}
+
+    /// Names of all registered themes, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &SharedString> {{
+        self.themes.iter().map(|(name, _)| name)
+    }}
+
+    /// Build one mode of a registered theme.
+    pub fn theme(&self, name: &str, mode: ThemeMode) -> Option<Theme> {{
+        self.themes
+            .iter()
+            .find(|(existing, _)| existing.as_ref() == name)
+            .map(|(_, build)| build(mode))
+    }}
+
+    /// The theme and mode last applied with [`ThemeSwitch::set_theme`].
+    ///
+    /// The mode is the one requested, so it may be [`ThemeMode::System`].
+    pub fn active(&self) -> Option<(&SharedString, ThemeMode)> {{
+        self.active.as_ref().map(|(name, mode)| (name, *mode))
+    }}
+}}
+
+/// Theme switching for gpui apps.
+pub trait ThemeSwitch {{
+    /// Apply a registered theme and refresh every window. Returns `false` if
+    /// no theme with that name is registered.
+    ///
+    /// [`ThemeMode::System`] resolves against the current OS appearance. The
+    /// density and typography of the current theme, if any, are kept.
+    fn set_theme(&mut self, name: &str, mode: ThemeMode) -> bool;
+
+    /// Re-apply the active theme when `window`'s appearance changes while its
+    /// mode is [`ThemeMode::System`]. Call once per window.
+    fn follow_appearance(&mut self, window: &Window);
+}}
+
+impl ThemeSwitch for App {{
+    fn set_theme(&mut self, name: &str, mode: ThemeMode) -> bool {{
+        let resolved = mode.resolve(self.window_appearance());
+        apply_theme(self, name, mode, resolved)
+    }}
+
+    fn follow_appearance(&mut self, window: &Window) {{
+        window
+            .observe_window_appearance(|window, cx| {{
+                let Some((name, ThemeMode::System)) = cx
+                    .try_global::<ThemeRegistry>()
+                    .and_then(|registry| registry.active.clone())
+                else {{
+                    return;
+                }};
+                let resolved = ThemeMode::System.resolve(window.appearance());
+                apply_theme(cx, name.as_ref(), ThemeMode::System, resolved);
+            }})
+            .detach();
+    }}
+}}
+
+fn apply_theme(cx: &mut App, name: &str, mode: ThemeMode, resolved: ThemeMode) -> bool {{
+    let Some(mut theme) = cx.default_global::<ThemeRegistry>().theme(name, resolved) else {{
+        return false;
+    }};
+    if let Some(current) = cx.try_global::<Theme>() {{
+        theme.set_density(current.density);
+        theme.typography = current.typography.clone();
+    }}
+    cx.global_mut::<ThemeRegistry>().active = Some((name.to_string().into(), mode));
+    cx.set_global(theme);
+    cx.refresh_windows();
+    true
+}}
+{catalog}"#,
+        name = theme.fn_name,
+        overrides = overrides_expr(theme.overrides),
+        font_family = typography.font_family,
+        mono_font_family = typography.mono_font_family,
+        type_scale = struct_fields(
+            12,
+            &[
+                ("xs", typography.xs),
+                ("sm", typography.sm),
+                ("base", typography.base),
+                ("lg", typography.lg),
+                ("xl", typography.xl),
+                ("xxl", typography.xxl),
+            ]
+        ),
+        spacing = density_arms(|density| {
+            let s = Spacing::for_density(density);
+            vec![
+                ("xs", s.xs),
+                ("sm", s.sm),
+                ("md", s.md),
+                ("lg", s.lg),
+                ("xl", s.xl),
+            ]
+        }),
+        controls = density_arms(|density| {
+            let c = ControlHeights::for_density(density);
+            vec![("xs", c.xs), ("sm", c.sm), ("md", c.md), ("lg", c.lg)]
+        }),
+        toggles = density_arms(|density| {
+            let t = ToggleSizes::for_density(density);
+            vec![
+                ("sm", t.sm),
+                ("md", t.md),
+                ("lg", t.lg),
+                ("padding_sm", t.padding_sm),
+                ("padding_md", t.padding_md),
+                ("padding_lg", t.padding_lg),
+            ]
+        }),
+        shadows = [("sm", shadows.sm), ("md", shadows.md), ("lg", shadows.lg)]
+            .into_iter()
+            .map(|(name, shadow)| format!(
+                "            {name}: Shadow {{\n{}            }},\n",
+                shadow_fields(shadow)
+            ))
+            .collect::<String>(),
+        states = states_fields(&states),
+    )
+}
+
+/// The `ThemeDefinition` type and `themes()` list of const codegen.
+fn catalog(
+    themes: &[ThemeSource],
+    config_radius: Radius,
+    density: Density,
+    variant: Option<PaletteVariant>,
+) -> String {
+    let colors = |palette: &PaletteColors| -> String {
+        palette
+            .tokens()
+            .into_iter()
+            .map(|(token, value)| format!("            {token}: {},\n", fmt_hsl(value)))
+            .collect()
+    };
+    let slots = |table: &BTreeMap<String, Hsl>| {
+        let items = table
+            .iter()
+            .map(|(slot, color)| format!("({slot:?}, {})", fmt_hsl(*color)))
+            .collect();
+        format!("&{}", array_expr(items, 12))
+    };
+    let definitions: String = themes
+        .iter()
+        .map(|theme| {
+            let (light, dark) = palettes(theme, variant);
+            format!(
+                "    ThemeDefinition {{\n        name: {:?},\n        light: ThemeColors {{\n{}        }},\n        dark: ThemeColors {{\n{}        }},\n        radius: Radius::{:?},\n        overrides: {},\n    }},\n",
+                theme.fn_name,
+                colors(&light),
+                colors(&dark),
+                theme.radius.unwrap_or(config_radius),
+                array_expr(theme.overrides.map(slots).to_vec(), 8),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"
+/// A custom theme from `themes/`, with its palettes built at compile time.
+#[derive(Debug)]
+pub struct ThemeDefinition {{
+    pub name: &'static str,
+    pub light: ThemeColors,
+    pub dark: ThemeColors,
+    pub radius: Radius,
+    /// Component slot colors for light and dark mode.
+    pub overrides: [&'static [(&'static str, Hsla)]; 2],
+}}
+
+impl ThemeDefinition {{
+    /// Build one mode of this theme.
+    pub fn theme(&self, mode: ThemeMode) -> Theme {{
+        let (colors, slots) = match mode {{
+            ThemeMode::Light | ThemeMode::System => (&self.light, self.overrides[0]),
+            ThemeMode::Dark => (&self.dark, self.overrides[1]),
+        }};
+        Theme {{
+            mode,
+            colors: colors.clone(),
+            radius: self.radius,
+            density: Density::{density:?},
+            typography: Typography::default(),
+            spacing: Spacing::for_density(Density::{density:?}),
+            controls: ControlHeights::for_density(Density::{density:?}),
+            toggles: ToggleSizes::for_density(Density::{density:?}),
+            shadows: Shadows::default(),
+            states: InteractionStates::default(),
+            overrides: slots.iter().fold(
+                ComponentOverrides::default(),
+                |overrides, &(slot, color)| overrides.with(slot, color),
+            ),
+        }}
+    }}
+}}
+
+/// Every custom theme in `themes/`, e.g. for a theme picker. The default
+/// [`ThemeRegistry`] registers each of them by name.
+pub fn themes() -> &'static [ThemeDefinition] {{
+    &THEMES
+}}
+
+static THEMES: [ThemeDefinition; {count}] = [
+{definitions}];
+"#,
+        count = themes.len(),
+    )
+}
+
+/// Conversions from the `shadcn-ui-theme` crate, its fonts and icons, and
+/// `watch_theme_file`, emitted with `theme_crate = true`.
+fn bridge() -> String {
+    let colors: String = PaletteColors::TOKENS
+        .iter()
+        .map(|token| format!("                {token}: colors.{token},\n"))
+        .collect();
+    let copy = |indent: usize, from: &str, fields: &[&str]| -> String {
+        fields
+            .iter()
+            .map(|field| format!("{:indent$}{field}: {from}.{field},\n", ""))
+            .collect()
+    };
+    let variants = |indent: usize, ty: &str, names: &[&str]| -> String {
+        names
+            .iter()
+            .map(|name| {
+                format!(
+                    "{:indent$}shadcn_ui_theme::{ty}::{name} => {ty}::{name},\n",
+                    ""
+                )
+            })
+            .collect()
+    };
+
+    format!(
+        r#"
+// Conversions from the `shadcn-ui-theme` crate, for themes loaded with it at
+// runtime, and its embedded fonts and icons. Enabled by `theme_crate = true`
+// in shadcn-ui.toml.
+
+/// Serves the `icons/*.svg` the `icon` component loads; pass it to
+/// `Application::with_assets`.
+pub use shadcn_ui_theme::Assets;
+/// Adds the embedded fonts to gpui's text system. Call once at startup,
+/// before drawing with [`Typography::embedded`].
+pub use shadcn_ui_theme::register_fonts;
+
+impl From<shadcn_ui_theme::ThemeMode> for ThemeMode {{
+    fn from(mode: shadcn_ui_theme::ThemeMode) -> Self {{
+        match mode {{
+{to_mode}        }}
+    }}
+}}
+
+impl From<ThemeMode> for shadcn_ui_theme::ThemeMode {{
+    fn from(mode: ThemeMode) -> Self {{
+        match mode {{
+{from_mode}        }}
+    }}
+}}
+
+impl From<shadcn_ui_theme::Typography> for Typography {{
+    fn from(typography: shadcn_ui_theme::Typography) -> Self {{
+        Self {{
+            font_family: typography.font_family.into(),
+            mono_font_family: typography.mono_font_family.into(),
+{type_scale}        }}
+    }}
+}}
+
+impl Typography {{
+    /// The default type scale with the Inter and Fira Mono fonts embedded in
+    /// `shadcn-ui-theme`. Register them first with [`register_fonts`].
+    pub fn embedded() -> Self {{
+        shadcn_ui_theme::Typography::embedded().into()
+    }}
+}}
+
+impl From<shadcn_ui_theme::Theme> for Theme {{
+    fn from(theme: shadcn_ui_theme::Theme) -> Self {{
+        let colors = theme.colors;
+        let shadow = |shadow: shadcn_ui_theme::Shadow| Shadow {{
+{shadow}        }};
+        let opacity = |opacity: shadcn_ui_theme::StateOpacity| StateOpacity {{
+            hover: opacity.hover,
+            active: opacity.active,
+        }};
+        let states = theme.states;
+
+        Self {{
+            mode: theme.mode.into(),
+            colors: ThemeColors {{
+{colors}            }},
+            radius: match theme.radius {{
+{radius}            }},
+            density: match theme.density {{
+{density}            }},
+            typography: theme.typography.into(),
+            spacing: Spacing {{
+{spacing}            }},
+            controls: ControlHeights {{
+{controls}            }},
+            toggles: ToggleSizes {{
+{toggles}            }},
+            shadows: Shadows {{
+                sm: shadow(theme.shadows.sm),
+                md: shadow(theme.shadows.md),
+                lg: shadow(theme.shadows.lg),
+            }},
+            states: InteractionStates {{
+                primary: opacity(states.primary),
+                secondary: opacity(states.secondary),
+                destructive: opacity(states.destructive),
+                accent: opacity(states.accent),
+                focus_ring: FocusRing {{
+                    width: states.focus_ring.width,
+                    offset: states.focus_ring.offset,
+                }},
+                disabled_opacity: states.disabled_opacity,
+            }},
+            overrides: theme
+                .overrides
+                .iter()
+                .fold(ComponentOverrides::default(), |overrides, (slot, color)| {{
+                    overrides.with(slot, color)
+                }}),
+        }}
+    }}
+}}
+
+/// Apply the theme file at `path` now and again whenever it changes, for
+/// editing a theme while the app runs.
+///
+/// The theme is registered in the [`ThemeRegistry`] under its `[meta] name`
+/// and applied in the active mode; while another theme is active, changes
+/// only update the registry. Watching stops when the returned task is
+/// dropped; call `detach` to watch for the life of the app.
+pub fn watch_theme_file(path: impl Into<std::path::PathBuf>, cx: &mut App) -> gpui::Task<()> {{
+    shadcn_ui_theme::ThemeWatcher::new(path)
+        .on_reload(|file, cx| {{
+            let name = file.meta.name.clone();
+            let file = file.clone();
+            cx.default_global::<ThemeRegistry>()
+                .register(name.clone(), move |mode| file.theme(mode.into()).into());
+            let mode = match cx.global::<ThemeRegistry>().active() {{
+                Some((active, mode)) if active.as_ref() == name => mode,
+                Some(_) => return Ok(()),
+                None => cx
+                    .try_global::<Theme>()
+                    .map_or(ThemeMode::Light, |theme| theme.mode),
+            }};
+            cx.set_theme(&name, mode);
+            Ok(())
+        }})
+        .watch(cx)
+}}
+"#,
+        to_mode = variants(12, "ThemeMode", &["Light", "Dark", "System"]),
+        from_mode = ["Light", "Dark", "System"]
+            .iter()
+            .map(|name| format!(
+                "            ThemeMode::{name} => shadcn_ui_theme::ThemeMode::{name},\n"
+            ))
+            .collect::<String>(),
+        type_scale = copy(12, "typography", &["xs", "sm", "base", "lg", "xl", "xxl"]),
+        shadow = copy(12, "shadow", &["offset_y", "blur", "spread", "opacity"]),
+        radius = variants(16, "Radius", &["None", "Sm", "Md", "Lg", "Full"]),
+        density = variants(16, "Density", &["Compact", "Default", "Comfortable"]),
+        spacing = copy(16, "theme.spacing", &["xs", "sm", "md", "lg", "xl"]),
+        controls = copy(16, "theme.controls", &["xs", "sm", "md", "lg"]),
+        toggles = copy(
+            16,
+            "theme.toggles",
+            &["sm", "md", "lg", "padding_sm", "padding_md", "padding_lg"]
+        ),
+    )
+}
+
+/// An array literal laid out as rustfmt would: on one line if it is short,
+/// otherwise one item per line, indented by `indent` spaces.
+fn array_expr(items: Vec<String>, indent: usize) -> String {
+    let line = format!("[{}]", items.join(", "));
+    if line.len() <= 60 && !line.contains('\n') {
+        return line;
+    }
+    let items: String = items
+        .iter()
+        .map(|item| format!("{:width$}{item},\n", "", width = indent + 4))
+        .collect();
+    format!("[\n{items}{:indent$}]", "")
+}
+
+/// The `ComponentOverrides` for a theme constructor: the default when no
+/// slots are set, otherwise a match on the mode.
+fn overrides_expr([light, dark]: [&BTreeMap<String, Hsl>; 2]) -> String {
+    if light.is_empty() && dark.is_empty() {
+        return "ComponentOverrides::default()".to_string();
+    }
+    let chain = |slots: &BTreeMap<String, Hsl>| -> String {
+        slots
+            .iter()
+            .map(|(slot, color)| {
+                format!("\n                    .with({slot:?}, {})", fmt_hsl(*color))
+            })
+            .collect()
+    };
+    format!(
+        "match mode {{\n                ThemeMode::Light | ThemeMode::System => ComponentOverrides::default(){},\n                ThemeMode::Dark => ComponentOverrides::default(){},\n            }}",
+        chain(light),
+        chain(dark),
+    )
+}
+
+/// `name: value,` lines for a struct literal, indented by `indent` spaces.
+fn struct_fields(indent: usize, fields: &[(&str, f32)]) -> String {
+    fields
+        .iter()
+        .map(|(name, value)| format!("{:indent$}{name}: {},\n", "", float_lit(*value)))
+        .collect()
+}
+
+/// A theme's light and dark palettes with `variant` applied.
+fn palettes(
+    theme: &ThemeSource,
+    variant: Option<PaletteVariant>,
+) -> (PaletteColors, PaletteColors) {
+    match variant {
+        Some(variant) => (
+            theme.light.with_variant(variant, ThemeMode::Light),
+            theme.dark.with_variant(variant, ThemeMode::Dark),
+        ),
+        None => (*theme.light, *theme.dark),
+    }
+}
+
+/// One `Density::X => Self { .. },` match arm per density.
+fn density_arms(fields: impl Fn(Density) -> Vec<(&'static str, f32)>) -> String {
+    Density::ALL
+        .into_iter()
+        .map(|density| {
+            format!(
+                "            Density::{density:?} => Self {{\n{}            }},\n",
+                struct_fields(16, &fields(density))
+            )
+        })
+        .collect()
+}
+
+fn shadow_fields(shadow: Shadow) -> String {
+    struct_fields(
+        16,
+        &[
+            ("offset_y", shadow.offset_y),
+            ("blur", shadow.blur),
+            ("spread", shadow.spread),
+            ("opacity", shadow.opacity),
+        ],
+    )
+}
+
+/// The fields of the `InteractionStates` default.
+fn states_fields(states: &InteractionStates) -> String {
+    let opacity = |name: &str, o: StateOpacity| {
+        format!(
+            "            {name}: StateOpacity {{\n{}            }},\n",
+            struct_fields(16, &[("hover", o.hover), ("active", o.active)])
+        )
+    };
+    let ring = states.focus_ring;
+    format!(
+        "{}{}{}{}            focus_ring: FocusRing {{\n{}            }},\n{}",
+        opacity("primary", states.primary),
+        opacity("secondary", states.secondary),
+        opacity("destructive", states.destructive),
+        opacity("accent", states.accent),
+        struct_fields(16, &[("width", ring.width), ("offset", ring.offset)]),
+        struct_fields(12, &[("disabled_opacity", states.disabled_opacity)]),
+    )
+}
+
+/// Format a single color as a code string like `hsl(240.0, 5.9, 10.0)`, or
+/// `hsla(0.0, 0.0, 0.0, 0.8)` for translucent colors.
+fn fmt_hsl(c: Hsl) -> String {
+    let f = float_lit;
+    if c.a < 1.0 {
+        format!("hsla({}, {}, {}, {})", f(c.h), f(c.s), f(c.l), f(c.a))
+    } else {
+        format!("hsl({}, {}, {})", f(c.h), f(c.s), f(c.l))
+    }
+}
+
+/// Format an `f32` as a Rust float literal.
+///
+/// Ensures float literals always include a decimal point (e.g. `0.0` instead of
+/// `0`), which is required for valid Rust source code.
+fn float_lit(v: f32) -> String {
+    let s = format!("{v}");
+    if s.contains('.') { s } else { format!("{v}.0") }
+}
+
+#[cfg(test)]
+mod tests {
+    use shadcn_ui_theme::{ColorBlindness, ZINC};
+
+    use super::*;
+    use crate::config::Config;
+
+    fn zinc() -> ThemeSource<'static> {
+        static EMPTY: BTreeMap<String, Hsl> = BTreeMap::new();
+        ThemeSource {
+            fn_name: "zinc",
+            light: &ZINC.light,
+            dark: &ZINC.dark,
+            overrides: [&EMPTY, &EMPTY],
+            radius: None,
+        }
+    }
+
+    #[test]
+    fn test_variant_applies_to_palettes() {
+        let mut config = Config::default().theme;
+        let plain = generate_theme_rs(&zinc(), &[], &config, "init");
+        assert!(plain.contains(&format!(
+            "destructive: {},",
+            fmt_hsl(ZINC.light.destructive)
+        )));
+
+        config.variant = Some("protanopia".to_string());
+        let variant = generate_theme_rs(&zinc(), &[], &config, "init");
+        let light = ZINC
+            .light
+            .color_blind(ColorBlindness::Protanopia, ThemeMode::Light);
+        assert!(variant.contains(&format!("destructive: {},", fmt_hsl(light.destructive))));
+        assert!(!variant.contains(&format!(
+            "destructive: {},",
+            fmt_hsl(ZINC.light.destructive)
+        )));
+    }
+
+    #[test]
+    fn test_unknown_variant_is_ignored() {
+        let mut config = Config::default().theme;
+        let plain = generate_theme_rs(&zinc(), &[], &config, "init");
+        config.variant = Some("tritanopia".to_string());
+        assert_eq!(generate_theme_rs(&zinc(), &[], &config, "init"), plain);
+    }
+
+    #[test]
+    fn test_variant_applies_to_catalog() {
+        let mut config = Config::default().theme;
+        config.codegen = "const".to_string();
+        config.variant = Some("high_contrast".to_string());
+        let source = generate_theme_rs(&zinc(), &[zinc()], &config, "init");
+        let dark = ZINC.dark.high_contrast(ThemeMode::Dark);
+        // Once in ThemeColors::DARK and once in the themes() entry
+        let primary = format!("primary: {},", fmt_hsl(dark.primary));
+        assert_eq!(source.matches(&primary).count(), 2);
+    }
+
+    #[test]
+    fn test_theme_crate_adds_conversions() {
+        let mut config = Config::default().theme;
+        let plain = generate_theme_rs(&zinc(), &[], &config, "init");
+        assert!(!plain.contains("shadcn_ui_theme"));
+
+        config.theme_crate = true;
+        let source = generate_theme_rs(&zinc(), &[], &config, "init");
+        assert!(source.starts_with(&plain));
+        assert!(source.contains("impl From<shadcn_ui_theme::Theme> for Theme {"));
+        assert!(source.contains("pub fn watch_theme_file("));
+        assert!(source.contains("pub fn embedded() -> Self {"));
+        assert!(source.contains("pub use shadcn_ui_theme::register_fonts;"));
+        for token in PaletteColors::TOKENS {
+            assert!(source.contains(&format!("{token}: colors.{token},")));
+        }
+    }
+}
@@ -6,17 +6,20 @@
 use std::path::PathBuf;
 use std::time::Duration;
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use clap::Args;
 use indicatif::{ProgressBar, ProgressStyle};
-use shadcn_ui_registry::default_registry;
+use shadcn_ui_registry::{BUNDLE_PREFIX, default_registry};
 
 use crate::component_sources;
 use crate::config::Config;
+use crate::hooks::{self, Hook};
+use crate::registry_index::registry_with_bundles;
+use crate::transform::{self, UTILS_MODULE};
 
 #[derive(Args)]
 pub struct AddArgs {
-    /// Component names to add
+    /// Component names or bundles (e.g. @forms) to add
     pub components: Vec<String>,
 
     /// Install all components
@@ -30,13 +33,26 @@ pub struct AddArgs {
     /// Overwrite existing files
     #[arg(short, long)]
     pub overwrite: bool,
+
+    /// Do not run hooks configured in shadcn-ui.toml
+    #[arg(long)]
+    pub no_hooks: bool,
 }
 
 pub async fn run(args: AddArgs) -> Result<()> {
-    let config = Config::load(&PathBuf::from("."))
+    let mut config = Config::load(&PathBuf::from("."))
         .context("No shadcn-ui.toml found. Run `shadcn-ui init` first.")?;
 
-    let registry = default_registry();
+    // Bundles may come from a private registry index
+    let requests_bundle = args
+        .components
+        .iter()
+        .any(|name| name.starts_with(BUNDLE_PREFIX));
+    let registry = if requests_bundle {
+        registry_with_bundles(&config).await
+    } else {
+        default_registry()
+    };
 
     // Determine which components to add
     let requested: Vec<&str> = if args.all {
@@ -44,12 +60,26 @@ pub async fn run(args: AddArgs) -> Result<()> {
     } else {
         if args.components.is_empty() {
             bail!(
-                "Please specify component names or use --all.\n\nUsage: shadcn-ui add <component...>\n       shadcn-ui add --all"
+                "Please specify component names or use --all.\n\nUsage: shadcn-ui add <component...>\n       shadcn-ui add @<bundle>\n       shadcn-ui add --all"
             );
         }
 
+        // Expand `@bundle` names into their components
+        let names = registry.expand_bundles(&args.components).map_err(|name| {
+            anyhow!(
+                "Unknown bundle: '{}'\n\nAvailable bundles: {}",
+                name,
+                registry
+                    .bundle_names()
+                    .iter()
+                    .map(|b| format!("{}{}", BUNDLE_PREFIX, b))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+
         // Validate all requested names
-        for name in &args.components {
+        for name in &names {
             if registry.find(name).is_none() {
                 let available = registry.component_names().join(", ");
                 bail!(
@@ -60,7 +90,7 @@ pub async fn run(args: AddArgs) -> Result<()> {
             }
         }
 
-        args.components.iter().map(|s| s.as_str()).collect()
+        names
     };
 
     // Resolve dependencies
@@ -68,9 +98,9 @@ pub async fn run(args: AddArgs) -> Result<()> {
 
     let components_dir = args
         .path
-        .as_deref()
-        .unwrap_or(&config.project.components_dir);
-    let components_path = PathBuf::from(components_dir);
+        .clone()
+        .unwrap_or_else(|| config.project.components_dir.clone());
+    let components_path = PathBuf::from(&components_dir);
 
     // Create components directory if it doesn't exist
     std::fs::create_dir_all(&components_path).with_context(|| {
@@ -80,6 +110,21 @@ pub async fn run(args: AddArgs) -> Result<()> {
         )
     })?;
 
+    // Files that will be written, for the pre_add hook
+    let utils_file = config
+        .transform
+        .shared_utils
+        .then(|| format!("{}.rs", UTILS_MODULE));
+    let planned: Vec<PathBuf> = to_install
+        .iter()
+        .filter_map(|name| registry.find(name))
+        .flat_map(|meta| meta.files.iter().cloned())
+        .chain(utils_file)
+        .map(|file| components_path.join(file))
+        .filter(|dest| args.overwrite || !dest.exists())
+        .collect();
+    hooks::run(Hook::PreAdd, &config.hooks, &planned, !args.no_hooks)?;
+
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
         ProgressStyle::default_spinner()
@@ -90,6 +135,8 @@ pub async fn run(args: AddArgs) -> Result<()> {
 
     let mut added_count = 0;
     let mut skipped_count = 0;
+    let mut written: Vec<PathBuf> = Vec::new();
+    let mut installed: Vec<&str> = Vec::new();
 
     for name in &to_install {
         let meta = match registry.find(name) {
@@ -110,10 +157,11 @@ pub async fn run(args: AddArgs) -> Result<()> {
 
             // Strip the .rs extension to get the component name for source lookup
             let source_name = file.strip_suffix(".rs").unwrap_or(file);
-            let content = component_sources::get_component_source(source_name)
+            let content = transform::component_source(source_name, &config.transform)?
                 .with_context(|| format!("No embedded source for component '{}'", source_name))?;
             std::fs::write(&dest, content)
                 .with_context(|| format!("Failed to write component file: {}", dest.display()))?;
+            written.push(dest);
 
             let was_dep = !requested.contains(&name.as_str());
             let suffix = if was_dep {
@@ -127,16 +175,43 @@ pub async fn run(args: AddArgs) -> Result<()> {
 
             spinner.println(format!("  + Added {}{}", name, suffix));
             added_count += 1;
+            installed.push(name);
         }
     }
 
+    // Install the shared helper module the transformed components import
+    let mut modules = to_install.clone();
+    if config.transform.shared_utils {
+        let dest = components_path.join(format!("{}.rs", UTILS_MODULE));
+        if !dest.exists() || args.overwrite {
+            let content = transform::apply(component_sources::utils_source(), &config.transform)?;
+            std::fs::write(&dest, content)
+                .with_context(|| format!("Failed to write {}", dest.display()))?;
+            written.push(dest);
+            spinner.println(format!("  + Added {}", UTILS_MODULE));
+        }
+        modules.push(UTILS_MODULE.to_string());
+    }
+
     // Update mod.rs
     spinner.set_message("Updating mod.rs...");
-    update_mod_rs(&components_path, &to_install)?;
+    update_mod_rs(&components_path, &modules)?;
     spinner.println("  + Updated mod.rs");
 
+    // Record installed versions for `upgrade-check`. Installs into a
+    // custom --path are not tracked.
+    if !installed.is_empty() && args.path.is_none() {
+        config.record_versions(&registry, installed.iter().copied());
+        config.save(&PathBuf::from("."))?;
+    }
+
     spinner.finish_and_clear();
 
+    if !written.is_empty() {
+        written.push(components_path.join("mod.rs"));
+    }
+    hooks::run(Hook::PostAdd, &config.hooks, &written, !args.no_hooks)?;
+
     println!();
     if added_count > 0 {
         println!("Added {} component(s) to {}.", added_count, components_dir);
@@ -147,10 +222,36 @@ pub async fn run(args: AddArgs) -> Result<()> {
             skipped_count
         );
     }
+    if installed.contains(&"icon") {
+        print_icon_setup(&config);
+    }
 
     Ok(())
 }
 
+/// Explain how to serve the `icons/*.svg` files the icon component loads.
+fn print_icon_setup(config: &Config) {
+    let mut steps = Vec::new();
+    if !config.theme.theme_crate {
+        steps.push("Add shadcn-ui-theme to your Cargo.toml dependencies".to_string());
+        steps.push(format!(
+            "Set theme_crate = true under [theme] in shadcn-ui.toml and run `shadcn-ui theme apply {}`",
+            config.theme.base_color
+        ));
+    }
+    steps.push("Start the app with Application::new().with_assets(theme::Assets)".to_string());
+    steps.push(
+        "Optionally call theme::register_fonts(cx), set Typography::embedded() for Inter and Fira Mono and set .font_family(theme.typography.font_family.clone()) on the root element"
+            .to_string(),
+    );
+
+    println!();
+    println!("Icons load icons/<name>.svg from the app's asset source. To serve them:");
+    for (i, step) in steps.iter().enumerate() {
+        println!("  {}. {}", i + 1, step);
+    }
+}
+
 /// Find which requested component depends on the given dependency.
 fn find_dependent(
     install_order: &[String],
@@ -0,0 +1,172 @@
+//! Shell completions.
+//!
+//! Completions are dynamic: the script printed by `completions <shell>` calls
+//! back into `shadcn-ui` whenever the shell completes a word, so `add`,
+//! `remove`, `diff`, `update` and the `theme` subcommands always complete the
+//! registry's current component and bundle names and the themes in the
+//! project's `./themes` directory.
+//!
+//! Candidates only drive completion; arguments accept any value, so names
+//! the shell does not suggest still get the commands' own error messages.
+
+use std::ffi::OsStr;
+
+use anyhow::{Context, Result};
+use clap::{Args, Command, CommandFactory};
+use clap_complete::env::Shells;
+use clap_complete::{ArgValueCandidates, CompletionCandidate, Shell};
+use shadcn_ui_registry::{BUNDLE_PREFIX, default_registry};
+
+use crate::Cli;
+use crate::commands::theme;
+
+/// Name of the binary the registration script completes and calls.
+const BIN: &str = "shadcn-ui";
+
+/// Environment variable that switches the binary into completion mode.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+/// Subcommands whose positional `components` argument takes registry names.
+const COMPONENT_COMMANDS: &[&str] = &["add", "remove", "diff", "update"];
+
+/// `theme` subcommands whose positional `name` argument takes any theme name.
+const THEME_NAME_COMMANDS: &[&str] = &["preview", "apply", "check", "export"];
+
+#[derive(Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for
+    pub shell: Shell,
+}
+
+pub async fn run(args: CompletionsArgs) -> Result<()> {
+    let shell = args.shell.to_string();
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(&shell)
+        .with_context(|| format!("No completion support for {}", shell))?;
+    completer.write_registration(COMPLETE_VAR, BIN, BIN, BIN, &mut std::io::stdout())?;
+    Ok(())
+}
+
+/// Build the CLI command with completion candidates attached.
+///
+/// Candidates are computed when the shell asks for them, from the working
+/// directory the completion runs in.
+pub fn completion_command() -> Command {
+    let mut cmd = Cli::command();
+
+    for name in COMPONENT_COMMANDS {
+        // `add` also accepts `@bundle` names
+        let candidates = if *name == "add" {
+            ArgValueCandidates::new(installable_candidates)
+        } else {
+            ArgValueCandidates::new(component_candidates)
+        };
+        cmd = cmd.mut_subcommand(name, |sub| {
+            sub.mut_arg("components", |arg| arg.add(candidates))
+        });
+    }
+
+    cmd.mut_subcommand("theme", |mut sub| {
+        for name in THEME_NAME_COMMANDS {
+            sub = sub.mut_subcommand(name, |cmd| {
+                cmd.mut_arg("name", |arg| {
+                    arg.add(ArgValueCandidates::new(theme_candidates))
+                })
+            });
+        }
+        // Only custom themes in ./themes can be watched
+        sub = sub.mut_subcommand("watch", |watch| {
+            watch.mut_arg("name", |arg| {
+                arg.add(ArgValueCandidates::new(custom_theme_candidates))
+            })
+        });
+        sub = sub.mut_subcommand("diff", |diff| {
+            diff.mut_arg("a", |arg| {
+                arg.add(ArgValueCandidates::new(theme_candidates))
+            })
+            .mut_arg("b", |arg| {
+                arg.add(ArgValueCandidates::new(theme_candidates))
+            })
+        });
+        sub.mut_subcommand("create", |create| {
+            create
+                .mut_arg("base", |arg| {
+                    arg.add(ArgValueCandidates::new(theme_candidates))
+                })
+                .mut_arg("neutral", |arg| {
+                    arg.add(ArgValueCandidates::new(preset_candidates))
+                })
+        })
+    })
+}
+
+fn candidates(names: impl IntoIterator<Item = impl AsRef<OsStr>>) -> Vec<CompletionCandidate> {
+    names
+        .into_iter()
+        .map(|name| CompletionCandidate::new(name.as_ref()))
+        .collect()
+}
+
+fn component_candidates() -> Vec<CompletionCandidate> {
+    candidates(default_registry().component_names())
+}
+
+fn installable_candidates() -> Vec<CompletionCandidate> {
+    let registry = default_registry();
+    let bundles = registry
+        .bundle_names()
+        .into_iter()
+        .map(|name| format!("{}{}", BUNDLE_PREFIX, name));
+    let mut names: Vec<String> = registry
+        .component_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    names.extend(bundles);
+    candidates(names)
+}
+
+fn theme_candidates() -> Vec<CompletionCandidate> {
+    candidates(theme::theme_names())
+}
+
+fn custom_theme_candidates() -> Vec<CompletionCandidate> {
+    candidates(theme::custom_theme_names())
+}
+
+fn preset_candidates() -> Vec<CompletionCandidate> {
+    candidates(shadcn_ui_theme::preset_names())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete(args: &[&str]) -> Vec<String> {
+        let mut cmd = completion_command();
+        let args: Vec<_> = args.iter().map(std::ffi::OsString::from).collect();
+        clap_complete::engine::complete(&mut cmd, args.clone(), args.len() - 1, None)
+            .unwrap()
+            .into_iter()
+            .map(|candidate| candidate.get_value().to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_completes_components_and_bundles() {
+        assert!(complete(&[BIN, "add", "butt"]).contains(&"button".to_string()));
+        assert!(complete(&[BIN, "add", "@"]).contains(&"@forms".to_string()));
+        assert!(!complete(&[BIN, "remove", "@"]).contains(&"@forms".to_string()));
+        assert!(complete(&[BIN, "theme", "apply", "zi"]).contains(&"zinc".to_string()));
+    }
+
+    #[test]
+    fn test_candidates_do_not_restrict_values() {
+        let cmd = completion_command();
+        assert!(
+            cmd.try_get_matches_from([BIN, "theme", "apply", "created-later"])
+                .is_ok()
+        );
+    }
+}
@@ -9,8 +9,8 @@ use anyhow::{Context, Result, bail};
 use clap::Args;
 use shadcn_ui_registry::default_registry;
 
-use crate::component_sources;
 use crate::config::Config;
+use crate::transform;
 
 #[derive(Args)]
 pub struct DiffArgs {
@@ -71,7 +71,7 @@ pub async fn run(args: DiffArgs) -> Result<()> {
         let local_content = std::fs::read_to_string(&local_path)
             .with_context(|| format!("Failed to read {}", local_path.display()))?;
 
-        let registry_content = match component_sources::get_component_source(name) {
+        let registry_content = match transform::component_source(name, &config.transform)? {
             Some(src) => src,
             None => {
                 println!("  - {} (no registry source available)", name);
@@ -84,7 +84,7 @@ pub async fn run(args: DiffArgs) -> Result<()> {
             up_to_date_count += 1;
         } else {
             println!("  ~ {} (modified)", name);
-            let diff_output = unified_diff(registry_content, &local_content, &file_name);
+            let diff_output = unified_diff(&registry_content, &local_content, &file_name);
             println!("{}", diff_output);
             modified_count += 1;
         }
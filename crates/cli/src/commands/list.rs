@@ -5,19 +5,27 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use clap::Args;
-use shadcn_ui_registry::default_registry;
+use shadcn_ui_registry::{BUNDLE_PREFIX, default_registry};
 
 use crate::config::Config;
+use crate::registry_index::registry_with_bundles;
 
 #[derive(Args)]
 pub struct ListArgs {
     /// Show installed components only
     #[arg(short, long)]
     pub installed: bool,
+
+    /// Show component bundles
+    #[arg(short, long, conflicts_with = "installed")]
+    pub bundles: bool,
 }
 
 pub async fn run(args: ListArgs) -> Result<()> {
-    let registry = default_registry();
+    let registry = match Config::load(&PathBuf::from(".")) {
+        Ok(config) if args.bundles => registry_with_bundles(&config).await,
+        _ => default_registry(),
+    };
 
     // Try to load project config to detect installed components
     let installed_components = detect_installed_components();
@@ -46,6 +54,36 @@ pub async fn run(args: ListArgs) -> Result<()> {
         return Ok(());
     }
 
+    if args.bundles {
+        if registry.bundles.is_empty() {
+            println!("No bundles available.");
+            return Ok(());
+        }
+
+        println!("Available bundles:");
+        println!();
+        for bundle in &registry.bundles {
+            // Count the components of nested bundles, not the bundles
+            let name = [format!("{}{}", BUNDLE_PREFIX, bundle.name)];
+            let members = registry.expand_bundles(&name).unwrap_or_default();
+            let installed = members
+                .iter()
+                .filter(|c| installed_components.iter().any(|i| i == *c))
+                .count();
+            println!(
+                "  {:<16} {} ({}/{} installed)",
+                name[0],
+                bundle.description,
+                installed,
+                members.len()
+            );
+            println!("  {:<16} {}", "", bundle.components.join(", "));
+        }
+        println!();
+        println!("Add a bundle: shadcn-ui add {}<bundle>", BUNDLE_PREFIX);
+        return Ok(());
+    }
+
     // Group components by category
     let mut by_category: BTreeMap<&str, Vec<_>> = BTreeMap::new();
     for component in &registry.components {
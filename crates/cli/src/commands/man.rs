@@ -0,0 +1,39 @@
+//! Generate man pages for the CLI.
+//!
+//! Prints the top-level page to stdout, or writes one page per command
+//! (`shadcn-ui.1`, `shadcn-ui-add.1`, ...) into a directory.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::{Args, CommandFactory};
+
+use crate::Cli;
+
+#[derive(Args)]
+pub struct ManArgs {
+    /// Write a page for every command into this directory instead of stdout
+    #[arg(short, long)]
+    pub out_dir: Option<PathBuf>,
+}
+
+pub async fn run(args: ManArgs) -> Result<()> {
+    let cmd = Cli::command();
+
+    match args.out_dir {
+        Some(out_dir) => {
+            std::fs::create_dir_all(&out_dir)
+                .with_context(|| format!("Failed to create directory: {}", out_dir.display()))?;
+            clap_mangen::generate_to(cmd, &out_dir)
+                .with_context(|| format!("Failed to write man pages to {}", out_dir.display()))?;
+            println!("Generated man pages in {}", out_dir.display());
+        }
+        None => {
+            clap_mangen::Man::new(cmd)
+                .render(&mut std::io::stdout())
+                .context("Failed to render man page")?;
+        }
+    }
+
+    Ok(())
+}
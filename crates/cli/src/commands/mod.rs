@@ -1,9 +1,12 @@
 //! CLI command implementations
 
 pub mod add;
+pub mod completions;
 pub mod diff;
 pub mod init;
 pub mod list;
+pub mod man;
 pub mod remove;
 pub mod theme;
 pub mod update;
+pub mod upgrade_check;
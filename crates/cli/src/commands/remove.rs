@@ -24,7 +24,7 @@ pub async fn run(args: RemoveArgs) -> Result<()> {
         );
     }
 
-    let config = Config::load(&PathBuf::from("."))
+    let mut config = Config::load(&PathBuf::from("."))
         .context("No shadcn-ui.toml found. Run `shadcn-ui init` first.")?;
 
     let registry = default_registry();
@@ -86,6 +86,15 @@ pub async fn run(args: RemoveArgs) -> Result<()> {
     // Update mod.rs to remove the modules
     update_mod_rs_remove(&components_path, &args.components)?;
 
+    // Forget recorded versions of removed components
+    let recorded = config.components.len();
+    config
+        .components
+        .retain(|name, _| !args.components.contains(name));
+    if config.components.len() != recorded {
+        config.save(&PathBuf::from("."))?;
+    }
+
     println!();
     println!("Removed {} component(s).", removed_count);
 
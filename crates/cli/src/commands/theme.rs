@@ -1,16 +1,25 @@
 //! Theme management commands.
 //!
-//! List, preview, apply, and create themes for your GPUI project.
+//! List, preview, apply, check, diff, create, import, export, and watch
+//! themes for your GPUI project.
 
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
 use clap::{Args, Subcommand};
+use shadcn_ui_theme::{
+    ContrastLevel, ContrastReport, Hsl, PaletteColors, PaletteVariant, Radius, ThemeMode,
+    preset_names, preset_palette,
+};
+use toml::Spanned;
 
+use crate::codegen::{self, ThemeSource};
 use crate::config::Config;
-
-/// Available theme presets.
-const THEME_PRESETS: &[&str] = &["zinc", "slate", "stone", "gray", "neutral"];
+use crate::hooks::{self, Hook};
+use crate::theme_preview;
+use crate::web_theme;
 
 #[derive(Args)]
 pub struct ThemeArgs {
@@ -22,32 +31,123 @@ pub struct ThemeArgs {
 pub enum ThemeCommands {
     /// List available themes
     List,
-    /// Preview a theme's colors
+    /// Preview a theme's colors, light and dark side by side
     Preview {
         /// Theme name to preview
         name: String,
+        /// Write an HTML page with component mockups instead
+        #[arg(long, value_name = "FILE")]
+        html: Option<PathBuf>,
     },
     /// Apply a theme to your project
     Apply {
         /// Theme name to apply
         name: String,
+        /// Accessibility variant to apply on top, saved in shadcn-ui.toml:
+        /// `high_contrast`, `deuteranopia`, `protanopia`, or `none` to
+        /// remove it
+        #[arg(long, value_parser = variant_names())]
+        variant: Option<String>,
+        /// Do not run hooks configured in shadcn-ui.toml
+        #[arg(long)]
+        no_hooks: bool,
+    },
+    /// Re-apply a custom theme whenever its file, or a theme it extends,
+    /// changes
+    Watch {
+        /// Custom theme name to watch
+        name: String,
+        /// Do not run hooks configured in shadcn-ui.toml
+        #[arg(long)]
+        no_hooks: bool,
+    },
+    /// Check a theme's text contrast and exit non-zero on failures
+    Check {
+        /// Theme name to check
+        name: String,
+        /// Minimum contrast: WCAG 2.1 `aa` (4.5:1), `aaa` (7:1) or APCA `apca` (Lc 60)
+        #[arg(long, default_value = "aa", value_parser = ["aa", "aaa", "apca"])]
+        level: String,
+        /// Check the theme with an accessibility variant applied
+        #[arg(long, value_parser = variant_names())]
+        variant: Option<String>,
+    },
+    /// Compare two themes token by token, with contrast changes
+    Diff {
+        /// Theme to compare from
+        a: String,
+        /// Theme to compare to
+        b: String,
     },
     /// Create a custom theme from a base preset
     Create {
         /// Name for the new custom theme
         name: String,
-        /// Base theme to start from
+        /// Theme to extend: a preset or another custom theme
         #[arg(short, long, default_value = "zinc")]
         base: String,
+        /// Derive primary, accent, ring and chart colors from a brand color,
+        /// e.g. "#4f46e5"
+        #[arg(long, value_name = "COLOR")]
+        from_color: Option<String>,
+        /// Preset that provides the other colors with --from-color
+        /// [default: the base theme]
+        #[arg(long, requires = "from_color")]
+        neutral: Option<String>,
+    },
+    /// Import a shadcn/ui web theme from its CSS variables
+    Import {
+        /// CSS file with `:root` and `.dark` variable blocks
+        file: PathBuf,
+        /// Name for the new custom theme [default: the file name]
+        #[arg(long)]
+        name: Option<String>,
+        /// Preset that provides colors the CSS does not set
+        #[arg(short, long, default_value = "zinc")]
+        base: String,
+    },
+    /// Export a theme for use on the web
+    Export {
+        /// Theme name to export
+        name: String,
+        /// Output format: shadcn/ui CSS variables, a theme JSON file, or a
+        /// Tailwind v4 stylesheet
+        #[arg(long, default_value = "css", value_parser = ["css", "json", "tailwind"])]
+        format: String,
+        /// Write to a file instead of stdout
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 }
 
 pub async fn run(args: ThemeArgs) -> Result<()> {
     match args.command {
         ThemeCommands::List => run_list(),
-        ThemeCommands::Preview { name } => run_preview(&name),
-        ThemeCommands::Apply { name } => run_apply(&name),
-        ThemeCommands::Create { name, base } => run_create(&name, &base),
+        ThemeCommands::Preview { name, html } => run_preview(&name, html.as_deref()),
+        ThemeCommands::Apply {
+            name,
+            variant,
+            no_hooks,
+        } => run_apply(&name, variant.as_deref(), !no_hooks),
+        ThemeCommands::Watch { name, no_hooks } => run_watch(&name, !no_hooks).await,
+        ThemeCommands::Check {
+            name,
+            level,
+            variant,
+        } => run_check(&name, &level, variant.as_deref()),
+        ThemeCommands::Diff { a, b } => run_diff(&a, &b),
+        ThemeCommands::Create {
+            name,
+            base,
+            from_color,
+            neutral,
+        } => run_create(&name, &base, from_color.as_deref(), neutral.as_deref()),
+        ThemeCommands::Import { file, name, base } => run_import(&file, name.as_deref(), &base),
+        ThemeCommands::Export {
+            name,
+            format,
+            output,
+        } => run_export(&name, &format, output.as_deref()),
     }
 }
 
@@ -60,88 +160,80 @@ fn run_list() -> Result<()> {
     println!("Available themes:");
     println!();
 
-    for preset in THEME_PRESETS {
-        let marker = if current.as_deref() == Some(preset) {
+    for preset in shadcn_ui_theme::PRESETS {
+        let marker = if current.as_deref() == Some(preset.name) {
             " (current)"
         } else {
             ""
         };
-        let description = theme_description(preset);
-        println!("  {:<12} {}{}", preset, description, marker);
+        println!("  {:<12} {}{}", preset.name, preset.description, marker);
     }
 
     // Check for custom themes
-    let themes_dir = PathBuf::from("themes");
-    if themes_dir.exists()
-        && let Ok(entries) = std::fs::read_dir(&themes_dir)
-    {
-        let custom: Vec<String> = entries
-            .flatten()
-            .filter_map(|e| {
-                let path = e.path();
-                if path.extension().is_some_and(|ext| ext == "toml") {
-                    path.file_stem().map(|s| s.to_string_lossy().to_string())
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        if !custom.is_empty() {
-            println!();
-            println!("Custom themes:");
-            for name in &custom {
-                let marker = if current.as_deref() == Some(name.as_str()) {
-                    " (current)"
-                } else {
-                    ""
-                };
-                println!("  {:<12} Custom theme{}", name, marker);
-            }
+    let custom = custom_theme_names();
+    if !custom.is_empty() {
+        println!();
+        println!("Custom themes:");
+        for name in &custom {
+            let marker = if current.as_deref() == Some(name.as_str()) {
+                " (current)"
+            } else {
+                ""
+            };
+            println!("  {:<12} Custom theme{}", name, marker);
         }
     }
 
+    let current_variant = Config::load(&PathBuf::from("."))
+        .ok()
+        .and_then(|c| c.theme.variant);
     println!();
-    println!("Apply a theme: shadcn-ui theme apply <name>");
-    println!("Preview:       shadcn-ui theme preview <name>");
-
-    Ok(())
-}
-
-fn run_preview(name: &str) -> Result<()> {
-    if !THEME_PRESETS.contains(&name) {
-        bail!(
-            "Unknown theme: '{}'\n\nAvailable themes: {}",
-            name,
-            THEME_PRESETS.join(", ")
+    println!("Accessibility variants (apply on top of any theme):");
+    for variant in PaletteVariant::ALL {
+        let marker = if current_variant.as_deref() == Some(variant.name()) {
+            " (current)"
+        } else {
+            ""
+        };
+        println!(
+            "  {:<14} {}{}",
+            variant.name(),
+            variant.description(),
+            marker
         );
     }
 
-    println!("Theme: {}", name);
-    println!("{}", "=".repeat(40));
     println!();
+    println!("Apply a theme: shadcn-ui theme apply <name> [--variant <variant>]");
+    println!("Preview:       shadcn-ui theme preview <name>");
+    println!("Compare:       shadcn-ui theme diff <a> <b>");
 
-    let colors = theme_color_table(name);
+    Ok(())
+}
 
-    println!("Light mode:");
-    for (label, value) in &colors.light {
-        println!("  {:<24} {}", label, value);
-    }
+fn run_preview(name: &str, html: Option<&Path>) -> Result<()> {
+    let (light, dark, radius) = theme_palettes(name)?;
 
-    println!();
-    println!("Dark mode:");
-    for (label, value) in &colors.dark {
-        println!("  {:<24} {}", label, value);
+    if let Some(path) = html {
+        let radius = radius.unwrap_or_else(project_radius);
+        std::fs::write(path, theme_preview::html(name, &light, &dark, radius))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("Wrote preview of {} to {}", name, path.display());
+        return Ok(());
     }
 
+    print!(
+        "{}",
+        theme_preview::terminal(name, &light, &dark, theme_preview::supports_truecolor())
+    );
     println!();
     println!("Apply this theme: shadcn-ui theme apply {}", name);
 
     Ok(())
 }
 
-fn run_apply(name: &str) -> Result<()> {
-    let is_builtin = THEME_PRESETS.contains(&name);
+fn run_apply(name: &str, variant: Option<&str>, run_hooks: bool) -> Result<()> {
+    let is_builtin = preset_palette(name).is_some();
     let custom_path = PathBuf::from(format!("themes/{}.toml", name));
     let is_custom = custom_path.exists();
 
@@ -149,7 +241,7 @@ fn run_apply(name: &str) -> Result<()> {
         bail!(
             "Unknown theme: '{}'\n\nAvailable themes: {}\n\nYou can also create a custom theme: shadcn-ui theme create <name>",
             name,
-            THEME_PRESETS.join(", ")
+            preset_names().join(", ")
         );
     }
 
@@ -159,6 +251,9 @@ fn run_apply(name: &str) -> Result<()> {
 
     let old_color = config.theme.base_color.clone();
     config.theme.base_color = name.to_string();
+    if let Some(variant) = variant {
+        config.theme.variant = (variant != "none").then(|| variant.to_string());
+    }
 
     // Save updated config
     config.save(&project_dir)?;
@@ -168,36 +263,366 @@ fn run_apply(name: &str) -> Result<()> {
         std::fs::create_dir_all(parent)?;
     }
 
+    // Const codegen lists every custom theme in theme.rs
+    let catalog = if config.theme.codegen == "const" {
+        custom_theme_names()
+            .into_iter()
+            .map(|name| {
+                let resolved = resolve_custom_theme(&name)
+                    .with_context(|| format!("Failed to resolve custom theme '{}'", name))?;
+                Ok((name, resolved))
+            })
+            .collect::<Result<Vec<_>>>()?
+    } else {
+        Vec::new()
+    };
+    let themes: Vec<ThemeSource> = catalog
+        .iter()
+        .map(|(name, resolved)| theme_source(name, resolved))
+        .collect();
+
     if is_custom {
-        // Read the custom theme TOML and generate theme.rs from it
-        let custom_content = std::fs::read_to_string(&custom_path)
-            .with_context(|| format!("Failed to read custom theme: {}", custom_path.display()))?;
-        let theme_content = generate_theme_rs_from_custom_toml(name, &custom_content)?;
+        // Resolve the custom theme's `extends` chain and generate theme.rs from it
+        let resolved = resolve_custom_theme(name)?;
+        let theme_content = codegen::generate_theme_rs(
+            &theme_source(name, &resolved),
+            &themes,
+            &config.theme,
+            "theme apply",
+        );
         std::fs::write(&theme_file, theme_content)
             .with_context(|| format!("Failed to write theme file: {}", theme_file.display()))?;
+        print_token_sources(&resolved, &config.theme.radius);
     } else {
         // Built-in preset
-        let theme_content = crate::commands::init::generate_theme_rs_from_config(&config.theme);
+        let theme_content =
+            crate::commands::init::generate_theme_rs_from_config(&config.theme, &themes);
         std::fs::write(&theme_file, theme_content)
             .with_context(|| format!("Failed to write theme file: {}", theme_file.display()))?;
     }
 
-    println!("Theme updated: {} -> {}", old_color, name);
+    if old_color == name {
+        println!("Theme regenerated: {}", name);
+    } else {
+        println!("Theme updated: {} -> {}", old_color, name);
+    }
+    if let Some(variant) = &config.theme.variant {
+        println!("  With the {} variant", variant);
+    }
     println!("  Updated shadcn-ui.toml");
     println!("  Regenerated {}", config.project.theme_file);
+    if !themes.is_empty() {
+        println!("  Listed {} custom theme(s) in themes()", themes.len());
+    }
+
+    hooks::run(
+        Hook::PostThemeApply,
+        &config.hooks,
+        &[theme_file],
+        run_hooks,
+    )?;
 
     Ok(())
 }
 
-fn run_create(name: &str, base: &str) -> Result<()> {
-    if !THEME_PRESETS.contains(&base) {
+/// How often `theme watch` checks the theme files.
+const WATCH_INTERVAL: Duration = Duration::from_millis(500);
+
+async fn run_watch(name: &str, run_hooks: bool) -> Result<()> {
+    if !PathBuf::from(format!("themes/{}.toml", name)).exists() {
+        bail!(
+            "Unknown custom theme: '{}'\n\nOnly themes in themes/ can be watched. Custom themes: {}",
+            name,
+            custom_theme_names().join(", ")
+        );
+    }
+
+    // Watch every theme file, so edits to a theme in the `extends` chain
+    // are picked up too
+    let mut files = read_custom_themes();
+    if let Err(err) = run_apply(name, None, run_hooks) {
+        eprintln!("Error: {:#}", err);
+    }
+    println!();
+    println!("Watching themes/ for changes. Press Ctrl+C to stop.");
+
+    loop {
+        tokio::time::sleep(WATCH_INTERVAL).await;
+        let current = read_custom_themes();
+        if current == files {
+            continue;
+        }
+        files = current;
+        println!();
+        if let Err(err) = run_apply(name, None, run_hooks) {
+            eprintln!("Error: {:#}", err);
+        }
+    }
+}
+
+/// Values accepted by `--variant`.
+fn variant_names() -> Vec<&'static str> {
+    PaletteVariant::ALL
+        .map(PaletteVariant::name)
+        .into_iter()
+        .chain(["none"])
+        .collect()
+}
+
+/// The contents of every custom theme file, by name.
+fn read_custom_themes() -> BTreeMap<String, String> {
+    custom_theme_names()
+        .into_iter()
+        .filter_map(|name| {
+            let content = std::fs::read_to_string(format!("themes/{}.toml", name)).ok()?;
+            Some((name, content))
+        })
+        .collect()
+}
+
+fn run_check(name: &str, level: &str, variant: Option<&str>) -> Result<()> {
+    let level = ContrastLevel::from_name(level)
+        .with_context(|| format!("Unknown contrast level: '{}'", level))?;
+    let (mut light, mut dark, _) = theme_palettes(name)?;
+    let name = match variant.and_then(PaletteVariant::from_name) {
+        Some(variant) => {
+            light = light.with_variant(variant, ThemeMode::Light);
+            dark = dark.with_variant(variant, ThemeMode::Dark);
+            format!("{} ({})", name, variant.name())
+        }
+        None => name.to_string(),
+    };
+
+    println!(
+        "Contrast of {} against {} ({})",
+        name,
+        level.name().to_uppercase(),
+        level.threshold()
+    );
+    let mut failed = 0;
+    for (mode, palette) in [("light", &light), ("dark", &dark)] {
+        let report = ContrastReport::for_palette(palette);
+        let failures: Vec<_> = report.failures(level).collect();
+        println!();
+        if failures.is_empty() {
+            println!("{}: all {} pairs pass", mode, report.checks.len());
+            continue;
+        }
+        println!(
+            "{}: {} of {} pairs fail",
+            mode,
+            failures.len(),
+            report.checks.len()
+        );
+        for check in &failures {
+            println!(
+                "  {:<28} on {:<18} {:>5.2}:1  Lc {:>6.1}",
+                check.foreground, check.background, check.ratio, check.apca
+            );
+        }
+        failed += failures.len();
+    }
+    println!();
+
+    if failed > 0 {
+        bail!(
+            "Theme '{}' has {} pair(s) below {}",
+            name,
+            failed,
+            level.name().to_uppercase()
+        );
+    }
+    println!("Theme '{}' passes.", name);
+    Ok(())
+}
+
+fn run_diff(a: &str, b: &str) -> Result<()> {
+    let (a_light, a_dark, _) = theme_palettes(a)?;
+    let (b_light, b_dark, _) = theme_palettes(b)?;
+
+    print!(
+        "{}",
+        theme_preview::diff(
+            a,
+            b,
+            &[("light", &a_light, &b_light), ("dark", &a_dark, &b_dark)],
+            theme_preview::supports_truecolor()
+        )
+    );
+    Ok(())
+}
+
+/// The light and dark palettes of a custom theme or preset, with the radius
+/// a custom theme's `extends` chain sets.
+fn theme_palettes(name: &str) -> Result<(PaletteColors, PaletteColors, Option<Radius>)> {
+    if PathBuf::from(format!("themes/{}.toml", name)).exists() {
+        let resolved = resolve_custom_theme(name)?;
+        let radius = resolved
+            .radius
+            .as_ref()
+            .and_then(|(radius, _)| Radius::from_name(radius));
+        Ok((resolved.light, resolved.dark, radius))
+    } else if let Some(preset) = preset_palette(name) {
+        Ok((preset.light, preset.dark, None))
+    } else {
+        bail!(
+            "Unknown theme: '{}'\n\nAvailable themes: {}",
+            name,
+            theme_names().join(", ")
+        );
+    }
+}
+
+/// The radius in `shadcn-ui.toml`, for themes that do not set one.
+fn project_radius() -> Radius {
+    Config::load(&PathBuf::from("."))
+        .ok()
+        .and_then(|config| Radius::from_name(&config.theme.radius))
+        .unwrap_or_default()
+}
+
+fn run_create(
+    name: &str,
+    base: &str,
+    from_color: Option<&str>,
+    neutral: Option<&str>,
+) -> Result<()> {
+    if let Some(color) = from_color {
+        let brand: Hsl = color.parse().context("Invalid --from-color")?;
+        let neutral = neutral.unwrap_or(base);
+        let Some(preset) = preset_palette(neutral) else {
+            bail!(
+                "Unknown neutral preset: '{}'\n\nAvailable presets: {}",
+                neutral,
+                preset_names().join(", ")
+            );
+        };
+        let light = PaletteColors::from_brand(brand, preset, ThemeMode::Light);
+        let dark = PaletteColors::from_brand(brand, preset, ThemeMode::Dark);
+        let content = generate_custom_theme_toml(
+            name,
+            neutral,
+            [&preset.light, &preset.dark],
+            [&light, &dark],
+            None,
+        );
+        write_custom_theme(name, &content)?;
+        println!(
+            "Derived primary, accent, ring and chart colors from {}",
+            color
+        );
+        println!("  with neutrals from {}", neutral);
+        print_contrast_warnings(&[
+            ("light", &light, &preset.light),
+            ("dark", &dark, &preset.dark),
+        ]);
+        return Ok(());
+    }
+
+    let resolved;
+    let (light, dark) = if PathBuf::from(format!("themes/{}.toml", base)).exists() {
+        resolved = resolve_custom_theme(base)?;
+        (&resolved.light, &resolved.dark)
+    } else if let Some(preset) = preset_palette(base) {
+        (&preset.light, &preset.dark)
+    } else {
         bail!(
             "Unknown base theme: '{}'\n\nAvailable base themes: {}",
             base,
-            THEME_PRESETS.join(", ")
+            theme_names().join(", ")
         );
+    };
+
+    let content = generate_custom_theme_toml(name, base, [light, dark], [light, dark], None);
+    write_custom_theme(name, &content)?;
+    println!();
+    println!("Uncomment and edit the colors you want to change, then apply:");
+    println!("  shadcn-ui theme apply {}", name);
+
+    Ok(())
+}
+
+fn run_import(file: &Path, name: Option<&str>, base: &str) -> Result<()> {
+    let Some(preset) = preset_palette(base) else {
+        bail!(
+            "Unknown base preset: '{}'\n\nAvailable presets: {}",
+            base,
+            preset_names().join(", ")
+        );
+    };
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => file
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .with_context(|| format!("Cannot derive a theme name from {}", file.display()))?
+            .to_string(),
+    };
+    let source = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let web = web_theme::parse_css(&source)
+        .with_context(|| format!("Failed to import {}", file.display()))?;
+
+    // Colors the CSS does not set come from the base preset
+    let overlay = |colors: &BTreeMap<&str, Hsl>, base: &PaletteColors| {
+        PaletteColors::try_from_fn(|token| {
+            colors
+                .get(token)
+                .copied()
+                .or_else(|| base.get(token))
+                .context("Missing color token")
+        })
+    };
+    let light = overlay(&web.light, &preset.light)?;
+    let dark = overlay(&web.dark, &preset.dark)?;
+    let content = generate_custom_theme_toml(
+        &name,
+        base,
+        [&preset.light, &preset.dark],
+        [&light, &dark],
+        web.radius,
+    );
+    write_custom_theme(&name, &content)?;
+    println!(
+        "  Imported {} light and {} dark colors from {}",
+        web.light.len(),
+        web.dark.len(),
+        file.display()
+    );
+    if let Some(radius) = web.radius {
+        println!("  Mapped --radius to {}", radius.name());
+    }
+    if !web.ignored.is_empty() {
+        println!("  Ignored: --{}", web.ignored.join(", --"));
     }
+    println!();
+    println!("Apply it with: shadcn-ui theme apply {}", name);
+    Ok(())
+}
+
+fn run_export(name: &str, format: &str, output: Option<&Path>) -> Result<()> {
+    let (light, dark, radius) = theme_palettes(name)?;
+    let radius = radius.unwrap_or_else(project_radius);
+
+    let content = match format {
+        "css" => web_theme::to_css(&light, &dark, radius),
+        "tailwind" => web_theme::to_tailwind(&light, &dark, radius),
+        "json" => web_theme::to_json(name, &light, &dark, radius)?,
+        _ => bail!("Unknown export format: '{}'", format),
+    };
+    match output {
+        Some(path) => {
+            std::fs::write(path, content)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            println!("Exported {} to {}", name, path.display());
+        }
+        None => print!("{}", content),
+    }
+    Ok(())
+}
 
+/// Write `themes/{name}.toml`, refusing to overwrite an existing theme.
+fn write_custom_theme(name: &str, content: &str) -> Result<()> {
     let themes_dir = PathBuf::from("themes");
     std::fs::create_dir_all(&themes_dir)?;
 
@@ -210,429 +635,502 @@ fn run_create(name: &str, base: &str) -> Result<()> {
         );
     }
 
-    let content = generate_custom_theme_toml(name, base);
     std::fs::write(&theme_path, content)
         .with_context(|| format!("Failed to write {}", theme_path.display()))?;
-
     println!("Created custom theme: themes/{}.toml", name);
-    println!();
-    println!("Edit the file to customize colors, then apply:");
-    println!("  shadcn-ui theme apply {}", name);
-
     Ok(())
 }
 
-fn theme_description(name: &str) -> &'static str {
-    match name {
-        "zinc" => "Cool gray tones (default)",
-        "slate" => "Slate blue-gray tones",
-        "stone" => "Warm stone-brown tones",
-        "gray" => "Pure neutral gray tones",
-        "neutral" => "Balanced neutral tones",
-        _ => "Unknown theme",
+/// Print the pairs of derived palettes that miss WCAG AA, skipping pairs
+/// whose background is unchanged from the neutral palette.
+fn print_contrast_warnings(palettes: &[(&str, &PaletteColors, &PaletteColors)]) {
+    for (mode, palette, neutral) in palettes {
+        let report = ContrastReport::for_palette(palette);
+        for check in report
+            .failures(ContrastLevel::Aa)
+            .filter(|check| palette.get(check.background) != neutral.get(check.background))
+        {
+            println!(
+                "  Warning: {} on {} in {} mode is {:.2}:1, below AA",
+                check.foreground, check.background, mode, check.ratio
+            );
+        }
     }
 }
 
-struct ColorTable {
-    light: Vec<(&'static str, String)>,
-    dark: Vec<(&'static str, String)>,
-}
+/// Names of the custom themes found in the `themes/` directory.
+pub fn custom_theme_names() -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir("themes") else {
+        return Vec::new();
+    };
 
-fn theme_color_table(name: &str) -> ColorTable {
-    let colors = crate::commands::init::preset_colors(name);
-    let l = &colors.light;
-    let d = &colors.dark;
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter_map(|e| {
+            let path = e.path();
+            if path.extension().is_some_and(|ext| ext == "toml") {
+                path.file_stem().map(|s| s.to_string_lossy().to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
 
-    let fmt = |c: (f32, f32, f32)| format!("hsl({}, {}%, {}%)", c.0, c.1, c.2);
+/// All theme names that can be previewed or applied: presets first, then custom themes.
+pub fn theme_names() -> Vec<String> {
+    let mut names: Vec<String> = preset_names().iter().map(|s| s.to_string()).collect();
+    for name in custom_theme_names() {
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    names
+}
 
-    ColorTable {
-        light: vec![
-            ("background", fmt(l.background)),
-            ("foreground", fmt(l.foreground)),
-            ("primary", fmt(l.primary)),
-            ("secondary", fmt(l.secondary)),
-            ("muted", fmt(l.muted)),
-            ("accent", fmt(l.accent)),
-            ("destructive", fmt(l.destructive)),
-            ("border", fmt(l.border)),
-        ],
-        dark: vec![
-            ("background", fmt(d.background)),
-            ("foreground", fmt(d.foreground)),
-            ("primary", fmt(d.primary)),
-            ("secondary", fmt(d.secondary)),
-            ("muted", fmt(d.muted)),
-            ("accent", fmt(d.accent)),
-            ("destructive", fmt(d.destructive)),
-            ("border", fmt(d.border)),
-        ],
+/// Format a color as written in theme files, e.g. `hsl(240, 5.9%, 10%)`.
+///
+/// Translucent colors use `hsla(h, s%, l%, a)`.
+fn fmt_hsl(c: Hsl) -> String {
+    // Round away float noise such as 27.395996 from derived colors
+    let c = Hsl::new(round(c.h), round(c.s), round(c.l)).with_alpha(round(c.a));
+    if c.a < 1.0 {
+        format!("hsla({}, {}%, {}%, {})", c.h, c.s, c.l, c.a)
+    } else {
+        format!("hsl({}, {}%, {}%)", c.h, c.s, c.l)
     }
 }
 
-fn generate_custom_theme_toml(name: &str, base: &str) -> String {
-    let colors = crate::commands::init::preset_colors(base);
-    let l = &colors.light;
-    let d = &colors.dark;
+fn round(v: f32) -> f32 {
+    (v * 1000.0).round() / 1000.0
+}
 
-    let fmt = |c: (f32, f32, f32)| format!("hsl({}, {}%, {}%)", c.0, c.1, c.2);
+/// Write a custom theme that extends `base`.
+///
+/// Tokens that differ from the base palettes are written out; the rest are
+/// listed commented out with the base value, so overriding one only means
+/// uncommenting it.
+fn generate_custom_theme_toml(
+    name: &str,
+    base: &str,
+    [base_light, base_dark]: [&PaletteColors; 2],
+    [light, dark]: [&PaletteColors; 2],
+    radius: Option<Radius>,
+) -> String {
+    let table = |palette: &PaletteColors, base: &PaletteColors| -> String {
+        palette
+            .tokens()
+            .into_iter()
+            .map(|(token, value)| {
+                let comment = if base.get(token) == Some(value) {
+                    "# "
+                } else {
+                    ""
+                };
+                format!("{comment}{token} = \"{}\"\n", fmt_hsl(value))
+            })
+            .collect()
+    };
 
     format!(
         r#"# Custom theme: {name}
-# Based on: {base}
 #
-# Edit the HSL color values below to customize your theme.
-# Then apply with: shadcn-ui theme apply {name}
+# Tokens, radius and density that are not set here come from "{base}".
+# Uncomment the colors you want to change, then apply with:
+#   shadcn-ui theme apply {name}
 
 [meta]
 name = "{name}"
-base = "{base}"
+extends = "{base}"
+{radius}
 
 [light]
-background = "{light_background}"
-foreground = "{light_foreground}"
-card = "{light_card}"
-card_foreground = "{light_card_foreground}"
-popover = "{light_popover}"
-popover_foreground = "{light_popover_foreground}"
-primary = "{light_primary}"
-primary_foreground = "{light_primary_foreground}"
-secondary = "{light_secondary}"
-secondary_foreground = "{light_secondary_foreground}"
-muted = "{light_muted}"
-muted_foreground = "{light_muted_foreground}"
-accent = "{light_accent}"
-accent_foreground = "{light_accent_foreground}"
-destructive = "{light_destructive}"
-destructive_foreground = "{light_destructive_foreground}"
-border = "{light_border}"
-input = "{light_input}"
-ring = "{light_ring}"
-
+{light}
 [dark]
-background = "{dark_background}"
-foreground = "{dark_foreground}"
-card = "{dark_card}"
-card_foreground = "{dark_card_foreground}"
-popover = "{dark_popover}"
-popover_foreground = "{dark_popover_foreground}"
-primary = "{dark_primary}"
-primary_foreground = "{dark_primary_foreground}"
-secondary = "{dark_secondary}"
-secondary_foreground = "{dark_secondary_foreground}"
-muted = "{dark_muted}"
-muted_foreground = "{dark_muted_foreground}"
-accent = "{dark_accent}"
-accent_foreground = "{dark_accent_foreground}"
-destructive = "{dark_destructive}"
-destructive_foreground = "{dark_destructive_foreground}"
-border = "{dark_border}"
-input = "{dark_input}"
-ring = "{dark_ring}"
-"#,
-        light_background = fmt(l.background),
-        light_foreground = fmt(l.foreground),
-        light_card = fmt(l.card),
-        light_card_foreground = fmt(l.card_foreground),
-        light_popover = fmt(l.popover),
-        light_popover_foreground = fmt(l.popover_foreground),
-        light_primary = fmt(l.primary),
-        light_primary_foreground = fmt(l.primary_foreground),
-        light_secondary = fmt(l.secondary),
-        light_secondary_foreground = fmt(l.secondary_foreground),
-        light_muted = fmt(l.muted),
-        light_muted_foreground = fmt(l.muted_foreground),
-        light_accent = fmt(l.accent),
-        light_accent_foreground = fmt(l.accent_foreground),
-        light_destructive = fmt(l.destructive),
-        light_destructive_foreground = fmt(l.destructive_foreground),
-        light_border = fmt(l.border),
-        light_input = fmt(l.input),
-        light_ring = fmt(l.ring),
-        dark_background = fmt(d.background),
-        dark_foreground = fmt(d.foreground),
-        dark_card = fmt(d.card),
-        dark_card_foreground = fmt(d.card_foreground),
-        dark_popover = fmt(d.popover),
-        dark_popover_foreground = fmt(d.popover_foreground),
-        dark_primary = fmt(d.primary),
-        dark_primary_foreground = fmt(d.primary_foreground),
-        dark_secondary = fmt(d.secondary),
-        dark_secondary_foreground = fmt(d.secondary_foreground),
-        dark_muted = fmt(d.muted),
-        dark_muted_foreground = fmt(d.muted_foreground),
-        dark_accent = fmt(d.accent),
-        dark_accent_foreground = fmt(d.accent_foreground),
-        dark_destructive = fmt(d.destructive),
-        dark_destructive_foreground = fmt(d.destructive_foreground),
-        dark_border = fmt(d.border),
-        dark_input = fmt(d.input),
-        dark_ring = fmt(d.ring),
+{dark}"#,
+        radius = match radius {
+            Some(radius) => format!("radius = \"{}\"", radius.name()),
+            None => "# radius = \"md\"".to_string(),
+        },
+        light = table(light, base_light),
+        dark = table(dark, base_dark),
     )
 }
 
 /// TOML structure for custom theme files.
+///
+/// Colors keep their spans so that parse errors can point at them.
 #[derive(serde::Deserialize)]
 struct CustomThemeToml {
-    #[allow(dead_code)]
     meta: CustomThemeMeta,
-    light: CustomThemeModeColors,
-    dark: CustomThemeModeColors,
+    #[serde(default)]
+    light: BTreeMap<String, Spanned<String>>,
+    #[serde(default)]
+    dark: BTreeMap<String, Spanned<String>>,
+    #[serde(default)]
+    components: CustomComponentsToml,
+}
+
+/// `[components.light]` and `[components.dark]`: colors for component slots
+/// such as `button.destructive.bg`.
+#[derive(Default, serde::Deserialize)]
+struct CustomComponentsToml {
+    #[serde(default)]
+    light: BTreeMap<String, Spanned<String>>,
+    #[serde(default)]
+    dark: BTreeMap<String, Spanned<String>>,
+}
+
+/// A custom theme file with its colors parsed.
+struct CustomTheme {
+    meta: CustomThemeMeta,
+    light: BTreeMap<String, Hsl>,
+    dark: BTreeMap<String, Hsl>,
+    /// Component slot overrides for light and dark mode.
+    components: [BTreeMap<String, Hsl>; 2],
+}
+
+impl CustomTheme {
+    /// Read and parse `path`.
+    fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read custom theme: {}", path.display()))?;
+        let custom: CustomThemeToml = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        for (mode, table) in [("light", &custom.light), ("dark", &custom.dark)] {
+            if let Some(token) = table
+                .keys()
+                .find(|token| !PaletteColors::TOKENS.contains(&token.as_str()))
+            {
+                bail!(
+                    "Unknown color token '{}' in [{}] of {}",
+                    token,
+                    mode,
+                    path.display()
+                );
+            }
+        }
+        for (mode, table) in [
+            ("components.light", &custom.components.light),
+            ("components.dark", &custom.components.dark),
+        ] {
+            if let Some(slot) = table.keys().find(|slot| !is_slot_name(slot)) {
+                bail!(
+                    "Invalid component slot '{}' in [{}] of {}: expected a name such as \"button.destructive.bg\"",
+                    slot,
+                    mode,
+                    path.display()
+                );
+            }
+        }
+        let parse_table = |table: BTreeMap<String, Spanned<String>>, mode: &str| {
+            table
+                .into_iter()
+                .map(|(token, value)| {
+                    let start = value.span().start;
+                    let line = content[..start].matches('\n').count() + 1;
+                    let column = start - content[..start].rfind('\n').map_or(0, |i| i + 1) + 1;
+                    let color = value.get_ref().parse::<Hsl>().with_context(|| {
+                        format!(
+                            "Invalid color for '{}' in [{}] at {}:{}:{}",
+                            token,
+                            mode,
+                            path.display(),
+                            line,
+                            column
+                        )
+                    })?;
+                    Ok((token, color))
+                })
+                .collect::<Result<BTreeMap<_, _>>>()
+        };
+        Ok(Self {
+            light: parse_table(custom.light, "light")?,
+            dark: parse_table(custom.dark, "dark")?,
+            components: [
+                parse_table(custom.components.light, "components.light")?,
+                parse_table(custom.components.dark, "components.dark")?,
+            ],
+            meta: custom.meta,
+        })
+    }
+
+    /// The `[light]` or `[dark]` table.
+    fn table(&self, mode: &str) -> &BTreeMap<String, Hsl> {
+        if mode == "dark" {
+            &self.dark
+        } else {
+            &self.light
+        }
+    }
+}
+
+/// The first word of the slot names components look up with `Theme::slot`.
+const SLOT_COMPONENTS: &[&str] = &[
+    "alert", "badge", "button", "card", "checkbox", "input", "progress", "radio", "select",
+    "slider", "switch", "tabs", "toast", "toggle",
+];
+
+/// Whether `slot` looks like `component.part` or `component.variant.part`:
+/// two or more lowercase, dot-separated words.
+fn is_slot_name(slot: &str) -> bool {
+    slot.split('.').count() >= 2
+        && slot.split('.').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        })
 }
 
 #[derive(serde::Deserialize)]
 struct CustomThemeMeta {
     #[allow(dead_code)]
     name: String,
-    #[allow(dead_code)]
-    base: String,
+    /// Custom theme or preset that provides unset tokens. `base`, written by
+    /// older versions, is accepted as an alias.
+    #[serde(alias = "base")]
+    extends: Option<String>,
+    radius: Option<String>,
 }
 
-#[derive(serde::Deserialize)]
-struct CustomThemeModeColors {
-    background: String,
-    foreground: String,
-    card: String,
-    card_foreground: String,
-    popover: String,
-    popover_foreground: String,
-    primary: String,
-    primary_foreground: String,
-    secondary: String,
-    secondary_foreground: String,
-    muted: String,
-    muted_foreground: String,
-    accent: String,
-    accent_foreground: String,
-    destructive: String,
-    destructive_foreground: String,
-    border: String,
-    input: String,
-    ring: String,
-}
-
-/// Parse an HSL string like "hsl(240, 5.9%, 10%)" into (h, s, l) floats.
-fn parse_hsl(s: &str) -> Result<(f32, f32, f32)> {
-    let s = s.trim();
-    let inner = s
-        .strip_prefix("hsl(")
-        .and_then(|s| s.strip_suffix(')'))
-        .with_context(|| format!("Invalid HSL format: {s}"))?;
-    let parts: Vec<&str> = inner.split(',').collect();
-    if parts.len() != 3 {
-        bail!("Invalid HSL format (expected 3 values): {s}");
-    }
-    let h: f32 = parts[0]
-        .trim()
-        .parse()
-        .with_context(|| format!("Invalid hue in: {s}"))?;
-    let s_val: f32 = parts[1]
-        .trim()
-        .trim_end_matches('%')
-        .parse()
-        .with_context(|| format!("Invalid saturation in: {s}"))?;
-    let l: f32 = parts[2]
-        .trim()
-        .trim_end_matches('%')
-        .parse()
-        .with_context(|| format!("Invalid lightness in: {s}"))?;
-    Ok((h, s_val, l))
-}
-
-/// Generate theme.rs from a custom theme TOML string.
-fn generate_theme_rs_from_custom_toml(name: &str, toml_content: &str) -> Result<String> {
-    let custom: CustomThemeToml =
-        toml::from_str(toml_content).context("Failed to parse custom theme TOML")?;
-
-    let fmt = |hsl_str: &str| -> Result<String> {
-        let (h, s, l) = parse_hsl(hsl_str)?;
-        fn f(v: f32) -> String {
-            let s = format!("{v}");
-            if s.contains('.') { s } else { format!("{v}.0") }
+/// A custom theme with its `extends` chain resolved.
+struct ResolvedTheme {
+    light: PaletteColors,
+    dark: PaletteColors,
+    /// The themes in the chain, starting with the applied one.
+    sources: Vec<TokenSource>,
+    /// Radius from the first theme in the chain that sets one, with that
+    /// theme's name.
+    radius: Option<(String, String)>,
+    /// Component slot overrides for light and dark mode, each taken from the
+    /// first theme in the chain that sets it.
+    components: [BTreeMap<String, Hsl>; 2],
+}
+
+/// The tokens one theme in an `extends` chain provided.
+struct TokenSource {
+    theme: String,
+    light: Vec<&'static str>,
+    dark: Vec<&'static str>,
+}
+
+impl TokenSource {
+    fn tokens_mut(&mut self, mode: &str) -> &mut Vec<&'static str> {
+        if mode == "dark" {
+            &mut self.dark
+        } else {
+            &mut self.light
+        }
+    }
+}
+
+/// Load `themes/{name}.toml` and follow its `extends` chain through other
+/// custom themes down to a preset.
+///
+/// A custom theme named after a preset may extend that preset. Tokens are
+/// taken from the first theme in the chain that sets them. Without a preset
+/// at the end, every token must be set somewhere in the chain.
+fn resolve_custom_theme(name: &str) -> Result<ResolvedTheme> {
+    let mut files: Vec<(String, CustomTheme)> = Vec::new();
+    let mut current = name.to_string();
+    let preset = loop {
+        let visited = files.iter().any(|(theme, _)| *theme == current);
+        let path = PathBuf::from(format!("themes/{}.toml", current));
+        if !visited && path.exists() {
+            let custom = CustomTheme::load(&path)?;
+            let extends = custom.meta.extends.clone();
+            files.push((current.clone(), custom));
+            match extends {
+                Some(next) => current = next,
+                None => break None,
+            }
+        } else if let Some(preset) = preset_palette(&current) {
+            break Some(preset);
+        } else if visited {
+            bail!("Theme '{}' extends itself through '{}'", name, current);
+        } else {
+            bail!(
+                "Theme '{}' extends unknown theme '{}'\n\nAvailable themes: {}",
+                files.last().map_or(name, |(theme, _)| theme.as_str()),
+                current,
+                theme_names().join(", ")
+            );
         }
-        Ok(format!("hsl({}, {}, {})", f(h), f(s), f(l)))
     };
 
-    let l = &custom.light;
-    let d = &custom.dark;
+    if preset.is_none() {
+        for mode in ["light", "dark"] {
+            let missing: Vec<&str> = PaletteColors::TOKENS
+                .iter()
+                .copied()
+                .filter(|token| {
+                    !files
+                        .iter()
+                        .any(|(_, custom)| custom.table(mode).contains_key(*token))
+                })
+                .collect();
+            if !missing.is_empty() {
+                bail!(
+                    "Theme '{}' does not set {} in [{}] and extends no preset",
+                    name,
+                    missing.join(", "),
+                    mode
+                );
+            }
+        }
+    }
 
-    Ok(format!(
-        r#"//! Theme configuration for your GPUI project.
-//!
-//! Generated by `shadcn-ui theme apply`. Feel free to customize.
-
-use gpui::{{Global, Hsla}};
-
-/// Theme mode (light or dark).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ThemeMode {{
-    Light,
-    Dark,
-}}
-
-/// Border radius presets.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum Radius {{
-    None,
-    Sm,
-    Md,
-    Lg,
-    Full,
-}}
-
-impl Radius {{
-    pub fn to_px(&self) -> f32 {{
-        match self {{
-            Radius::None => 0.0,
-            Radius::Sm => 4.0,
-            Radius::Md => 6.0,
-            Radius::Lg => 8.0,
-            Radius::Full => 9999.0,
-        }}
-    }}
-}}
-
-/// Complete theme definition.
-pub struct Theme {{
-    pub mode: ThemeMode,
-    pub colors: ThemeColors,
-    pub radius: Radius,
-}}
-
-impl Global for Theme {{}}
-
-/// Theme color palette.
-#[derive(Debug, Clone)]
-pub struct ThemeColors {{
-    pub background: Hsla,
-    pub foreground: Hsla,
-    pub card: Hsla,
-    pub card_foreground: Hsla,
-    pub popover: Hsla,
-    pub popover_foreground: Hsla,
-    pub primary: Hsla,
-    pub primary_foreground: Hsla,
-    pub secondary: Hsla,
-    pub secondary_foreground: Hsla,
-    pub muted: Hsla,
-    pub muted_foreground: Hsla,
-    pub accent: Hsla,
-    pub accent_foreground: Hsla,
-    pub destructive: Hsla,
-    pub destructive_foreground: Hsla,
-    pub border: Hsla,
-    pub input: Hsla,
-    pub ring: Hsla,
-}}
-
-/// Helper to convert HSL values to GPUI's Hsla.
-fn hsl(h: f32, s: f32, l: f32) -> Hsla {{
-    Hsla {{
-        h: h / 360.0,
-        s: s / 100.0,
-        l: l / 100.0,
-        a: 1.0,
-    }}
-}}
-
-impl Theme {{
-    /// Create the default theme.
-    pub fn default_theme() -> Self {{
-        Self::{name}(ThemeMode::Dark)
-    }}
-
-    /// Create a theme with the {name} color preset.
-    pub fn {name}(mode: ThemeMode) -> Self {{
-        let colors = match mode {{
-            ThemeMode::Light => ThemeColors {{
-                background: {light_background},
-                foreground: {light_foreground},
-                card: {light_card},
-                card_foreground: {light_card_foreground},
-                popover: {light_popover},
-                popover_foreground: {light_popover_foreground},
-                primary: {light_primary},
-                primary_foreground: {light_primary_foreground},
-                secondary: {light_secondary},
-                secondary_foreground: {light_secondary_foreground},
-                muted: {light_muted},
-                muted_foreground: {light_muted_foreground},
-                accent: {light_accent},
-                accent_foreground: {light_accent_foreground},
-                destructive: {light_destructive},
-                destructive_foreground: {light_destructive_foreground},
-                border: {light_border},
-                input: {light_input},
-                ring: {light_ring},
-            }},
-            ThemeMode::Dark => ThemeColors {{
-                background: {dark_background},
-                foreground: {dark_foreground},
-                card: {dark_card},
-                card_foreground: {dark_card_foreground},
-                popover: {dark_popover},
-                popover_foreground: {dark_popover_foreground},
-                primary: {dark_primary},
-                primary_foreground: {dark_primary_foreground},
-                secondary: {dark_secondary},
-                secondary_foreground: {dark_secondary_foreground},
-                muted: {dark_muted},
-                muted_foreground: {dark_muted_foreground},
-                accent: {dark_accent},
-                accent_foreground: {dark_accent_foreground},
-                destructive: {dark_destructive},
-                destructive_foreground: {dark_destructive_foreground},
-                border: {dark_border},
-                input: {dark_input},
-                ring: {dark_ring},
-            }},
-        }};
-
-        Self {{
-            mode,
-            colors,
-            radius: Radius::Md,
-        }}
-    }}
-}}
-"#,
-        name = name,
-        light_background = fmt(&l.background)?,
-        light_foreground = fmt(&l.foreground)?,
-        light_card = fmt(&l.card)?,
-        light_card_foreground = fmt(&l.card_foreground)?,
-        light_popover = fmt(&l.popover)?,
-        light_popover_foreground = fmt(&l.popover_foreground)?,
-        light_primary = fmt(&l.primary)?,
-        light_primary_foreground = fmt(&l.primary_foreground)?,
-        light_secondary = fmt(&l.secondary)?,
-        light_secondary_foreground = fmt(&l.secondary_foreground)?,
-        light_muted = fmt(&l.muted)?,
-        light_muted_foreground = fmt(&l.muted_foreground)?,
-        light_accent = fmt(&l.accent)?,
-        light_accent_foreground = fmt(&l.accent_foreground)?,
-        light_destructive = fmt(&l.destructive)?,
-        light_destructive_foreground = fmt(&l.destructive_foreground)?,
-        light_border = fmt(&l.border)?,
-        light_input = fmt(&l.input)?,
-        light_ring = fmt(&l.ring)?,
-        dark_background = fmt(&d.background)?,
-        dark_foreground = fmt(&d.foreground)?,
-        dark_card = fmt(&d.card)?,
-        dark_card_foreground = fmt(&d.card_foreground)?,
-        dark_popover = fmt(&d.popover)?,
-        dark_popover_foreground = fmt(&d.popover_foreground)?,
-        dark_primary = fmt(&d.primary)?,
-        dark_primary_foreground = fmt(&d.primary_foreground)?,
-        dark_secondary = fmt(&d.secondary)?,
-        dark_secondary_foreground = fmt(&d.secondary_foreground)?,
-        dark_muted = fmt(&d.muted)?,
-        dark_muted_foreground = fmt(&d.muted_foreground)?,
-        dark_accent = fmt(&d.accent)?,
-        dark_accent_foreground = fmt(&d.accent_foreground)?,
-        dark_destructive = fmt(&d.destructive)?,
-        dark_destructive_foreground = fmt(&d.destructive_foreground)?,
-        dark_border = fmt(&d.border)?,
-        dark_input = fmt(&d.input)?,
-        dark_ring = fmt(&d.ring)?,
-    ))
+    let mut sources: Vec<TokenSource> = files
+        .iter()
+        .map(|(theme, _)| theme.clone())
+        .chain(preset.map(|p| p.name.to_string()))
+        .map(|theme| TokenSource {
+            theme,
+            light: Vec::new(),
+            dark: Vec::new(),
+        })
+        .collect();
+
+    let mut resolve_mode = |mode: &str| -> Result<PaletteColors> {
+        PaletteColors::try_from_fn(|token| {
+            for (i, (_, custom)) in files.iter().enumerate() {
+                if let Some(color) = custom.table(mode).get(token) {
+                    sources[i].tokens_mut(mode).push(token);
+                    return Ok(*color);
+                }
+            }
+            // Without a preset, tokens missing from the chain were rejected above
+            let preset = preset.expect("chain ends in a preset");
+            let palette = if mode == "dark" {
+                &preset.dark
+            } else {
+                &preset.light
+            };
+            let source = sources.last_mut().expect("chain ends in a preset");
+            source.tokens_mut(mode).push(token);
+            palette
+                .get(token)
+                .with_context(|| format!("Missing color token '{}' in [{}]", token, mode))
+        })
+    };
+    let light = resolve_mode("light")?;
+    let dark = resolve_mode("dark")?;
+
+    let radius = files
+        .iter()
+        .find_map(|(theme, custom)| Some((custom.meta.radius.clone()?, theme.clone())));
+    if let Some((value, theme)) = &radius
+        && Radius::from_name(value).is_none()
+    {
+        bail!(
+            "Invalid radius '{}' in {}: expected one of {}",
+            value,
+            theme,
+            Radius::ALL.map(Radius::name).join(", ")
+        );
+    }
+
+    let mut components: [BTreeMap<String, Hsl>; 2] = Default::default();
+    for (_, custom) in &files {
+        for (resolved, table) in components.iter_mut().zip(&custom.components) {
+            for (slot, color) in table {
+                resolved.entry(slot.clone()).or_insert(*color);
+            }
+        }
+    }
+
+    Ok(ResolvedTheme {
+        light,
+        dark,
+        sources,
+        radius,
+        components,
+    })
+}
+
+/// Print the `extends` chain of an applied custom theme and which theme each
+/// token and the radius came from.
+fn print_token_sources(resolved: &ResolvedTheme, config_radius: &str) {
+    let chain: Vec<&str> = resolved.sources.iter().map(|s| s.theme.as_str()).collect();
+    println!("Resolved {}", chain.join(" -> "));
+    let width = chain
+        .iter()
+        .map(|name| name.len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    let list = |tokens: &[&str]| match tokens.len() {
+        0 => "-".to_string(),
+        n if n > 6 => format!("{} tokens", n),
+        _ => tokens.join(", "),
+    };
+    for source in &resolved.sources {
+        println!("  {:<width$}  light: {}", source.theme, list(&source.light));
+        println!("  {:<width$}  dark:  {}", "", list(&source.dark));
+    }
+    match &resolved.radius {
+        Some((radius, theme)) => println!("  {:<width$}  {} (from {})", "radius", radius, theme),
+        None => println!(
+            "  {:<width$}  {} (from shadcn-ui.toml)",
+            "radius", config_radius
+        ),
+    }
+    let [light, dark] = &resolved.components;
+    if !light.is_empty() || !dark.is_empty() {
+        let slots = |table: &BTreeMap<String, Hsl>| match table.len() {
+            0 => "-".to_string(),
+            n if n > 6 => format!("{} slots", n),
+            _ => table.keys().cloned().collect::<Vec<_>>().join(", "),
+        };
+        println!("  {:<width$}  light: {}", "slots", slots(light));
+        println!("  {:<width$}  dark:  {}", "", slots(dark));
+        let mut unread: Vec<&str> = light
+            .keys()
+            .chain(dark.keys())
+            .filter_map(|slot| slot.split('.').next())
+            .filter(|component| !SLOT_COMPONENTS.contains(component))
+            .collect();
+        unread.sort_unstable();
+        unread.dedup();
+        if !unread.is_empty() {
+            println!(
+                "  Warning: no component reads slots of {}; see the slot table in docs/theming.md",
+                unread.join(", ")
+            );
+        }
+    }
+}
+
+/// The codegen input for a resolved custom theme.
+///
+/// The theme's radius, if its chain sets one, replaces the configured radius.
+fn theme_source<'a>(name: &'a str, resolved: &'a ResolvedTheme) -> ThemeSource<'a> {
+    ThemeSource {
+        fn_name: name,
+        light: &resolved.light,
+        dark: &resolved.dark,
+        overrides: [&resolved.components[0], &resolved.components[1]],
+        radius: resolved
+            .radius
+            .as_ref()
+            .and_then(|(radius, _)| Radius::from_name(radius)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_pass_check() {
+        for name in preset_names() {
+            run_check(name, "aa", None).unwrap();
+            for variant in PaletteVariant::ALL {
+                run_check(name, "aa", Some(variant.name())).unwrap();
+            }
+        }
+    }
 }
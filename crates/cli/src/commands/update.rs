@@ -11,8 +11,9 @@ use dialoguer::Confirm;
 use shadcn_ui_registry::default_registry;
 
 use crate::commands::diff::{installed_components, unified_diff};
-use crate::component_sources;
 use crate::config::Config;
+use crate::hooks::{self, Hook};
+use crate::transform;
 
 #[derive(Args)]
 pub struct UpdateArgs {
@@ -22,10 +23,14 @@ pub struct UpdateArgs {
     /// Overwrite without confirmation
     #[arg(short, long)]
     pub force: bool,
+
+    /// Do not run hooks configured in shadcn-ui.toml
+    #[arg(long)]
+    pub no_hooks: bool,
 }
 
 pub async fn run(args: UpdateArgs) -> Result<()> {
-    let config = Config::load(&PathBuf::from("."))
+    let mut config = Config::load(&PathBuf::from("."))
         .context("No shadcn-ui.toml found. Run `shadcn-ui init` first.")?;
 
     let registry = default_registry();
@@ -62,6 +67,8 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
     let mut updated_count = 0u32;
     let mut skipped_count = 0u32;
     let mut up_to_date_count = 0u32;
+    let mut updated_files: Vec<PathBuf> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
 
     for name in &to_update {
         let file_name = format!("{}.rs", name);
@@ -73,7 +80,7 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
             continue;
         }
 
-        let registry_content = match component_sources::get_component_source(name) {
+        let registry_content = match transform::component_source(name, &config.transform)? {
             Some(src) => src,
             None => {
                 println!("  - {} (no registry source available)", name);
@@ -88,12 +95,13 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
         if local_content == registry_content {
             println!("  = {} (already up to date)", name);
             up_to_date_count += 1;
+            current.push(name);
             continue;
         }
 
         // Show diff
         println!("  ~ {} (modified)", name);
-        let diff_output = unified_diff(registry_content, &local_content, &file_name);
+        let diff_output = unified_diff(&registry_content, &local_content, &file_name);
         println!("{}", diff_output);
 
         // Confirm unless --force
@@ -120,10 +128,12 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
         println!("  Backed up to {}.bak", file_name);
 
         // Write updated source
-        std::fs::write(&local_path, registry_content)
+        std::fs::write(&local_path, &registry_content)
             .with_context(|| format!("Failed to write {}", local_path.display()))?;
         println!("  Updated {}", name);
         updated_count += 1;
+        updated_files.push(local_path);
+        current.push(name);
     }
 
     println!();
@@ -139,5 +149,18 @@ pub async fn run(args: UpdateArgs) -> Result<()> {
     }
     println!("Summary: {}", parts.join(", "));
 
+    // Record installed versions for `upgrade-check`
+    if !current.is_empty() {
+        config.record_versions(&registry, current);
+        config.save(&PathBuf::from("."))?;
+    }
+
+    hooks::run(
+        Hook::PostUpdate,
+        &config.hooks,
+        &updated_files,
+        !args.no_hooks,
+    )?;
+
     Ok(())
 }
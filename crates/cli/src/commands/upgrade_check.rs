@@ -0,0 +1,164 @@
+//! Check for upstream changes to the registry and installed components.
+//!
+//! Compares the CLI version, the registry embedded in the CLI, the remote
+//! registry and the versions recorded in `shadcn-ui.toml`, then lists
+//! installed components with upstream changes and their changelog entries.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use clap::Args;
+use shadcn_ui_registry::{Registry, compare_versions, default_registry};
+
+use crate::commands::diff::installed_components;
+use crate::config::Config;
+use crate::registry_index::fetch_registry;
+use crate::transform;
+
+#[derive(Args)]
+pub struct UpgradeCheckArgs {
+    /// Only compare against the registry embedded in the CLI
+    #[arg(long)]
+    pub offline: bool,
+}
+
+pub async fn run(args: UpgradeCheckArgs) -> Result<()> {
+    let config = Config::load(&PathBuf::from("."))
+        .context("No shadcn-ui.toml found. Run `shadcn-ui init` first.")?;
+
+    let embedded = default_registry();
+    let remote = if args.offline {
+        None
+    } else {
+        match fetch_registry(&config.registry.url).await {
+            Ok(registry) => Some(registry),
+            Err(err) => {
+                println!("Warning: could not fetch the remote registry: {:#}", err);
+                println!();
+                None
+            }
+        }
+    };
+
+    println!("Versions:");
+    println!("  {:<20} {}", "CLI", env!("CARGO_PKG_VERSION"));
+    println!("  {:<20} {}", "Embedded registry", embedded.version);
+    let remote_version = match (&remote, args.offline) {
+        (Some(registry), _) => registry.version.as_str(),
+        (None, true) => "skipped (--offline)",
+        (None, false) => "unavailable",
+    };
+    println!("  {:<20} {}", "Remote registry", remote_version);
+    println!(
+        "  {:<20} {}",
+        "Project",
+        config.registry.version.as_deref().unwrap_or("not recorded")
+    );
+    println!();
+
+    // Compare against whichever registry is newer
+    let latest = match &remote {
+        Some(registry) if compare_versions(&registry.version, &embedded.version).is_gt() => {
+            println!(
+                "Registry {} is available, but this CLI embeds {}. Upgrade the CLI to install it:",
+                registry.version, embedded.version
+            );
+            println!("  cargo install shadcn-ui-cli");
+            println!();
+            registry
+        }
+        _ => &embedded,
+    };
+
+    let components_dir = PathBuf::from(&config.project.components_dir);
+    let installed = if components_dir.exists() {
+        installed_components(&components_dir, latest)?
+    } else {
+        Vec::new()
+    };
+
+    if installed.is_empty() {
+        println!("No installed components found.");
+        return Ok(());
+    }
+
+    let mut outdated: Vec<(&str, String, &str)> = Vec::new();
+    let mut up_to_date_count = 0u32;
+    let mut unknown: Vec<&str> = Vec::new();
+
+    for name in &installed {
+        let Some(meta) = latest.find(name) else {
+            continue;
+        };
+        match installed_version(name, &config, &components_dir, &embedded)? {
+            Some(version) if compare_versions(&meta.version, &version).is_gt() => {
+                outdated.push((name, version, &meta.version));
+            }
+            Some(_) => up_to_date_count += 1,
+            None => unknown.push(name),
+        }
+    }
+
+    if outdated.is_empty() {
+        println!("No components have upstream changes.");
+    } else {
+        println!("Components with upstream changes ({}):", outdated.len());
+        for (name, from, to) in &outdated {
+            println!("  ~ {} {} -> {}", name, from, to);
+            let entries = latest.changes_since(name, from);
+            if entries.is_empty() {
+                println!("      (no changelog entries)");
+            }
+            for entry in entries {
+                for change in &entry.changes {
+                    println!("      {}: {}", entry.version, change);
+                }
+            }
+        }
+    }
+
+    println!();
+    if up_to_date_count > 0 {
+        println!("{} component(s) up to date.", up_to_date_count);
+    }
+    if !unknown.is_empty() {
+        println!(
+            "Version not recorded for: {} (run `shadcn-ui update` to record it)",
+            unknown.join(", ")
+        );
+    }
+    if !outdated.is_empty() {
+        println!("Run `shadcn-ui update <component...>` to apply upstream changes, then");
+        println!(
+            "`shadcn-ui theme apply {}` to regenerate theme.rs with the fields they read.",
+            config.theme.base_color
+        );
+    }
+
+    Ok(())
+}
+
+/// The registry version of an installed component.
+///
+/// Uses the version recorded in `shadcn-ui.toml`. Projects set up before
+/// versions were recorded fall back to the embedded version when the file
+/// still matches the embedded source.
+fn installed_version(
+    name: &str,
+    config: &Config,
+    components_dir: &std::path::Path,
+    embedded: &Registry,
+) -> Result<Option<String>> {
+    if let Some(version) = config.components.get(name) {
+        return Ok(Some(version.clone()));
+    }
+
+    let Some(meta) = embedded.find(name) else {
+        return Ok(None);
+    };
+    let local_path = components_dir.join(format!("{}.rs", name));
+    let local_content = std::fs::read_to_string(&local_path)
+        .with_context(|| format!("Failed to read {}", local_path.display()))?;
+    let source = transform::component_source(name, &config.transform)?;
+    Ok((source.as_deref() == Some(local_content.as_str())).then(|| meta.version.clone()))
+}
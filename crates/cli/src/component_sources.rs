@@ -48,6 +48,7 @@ pub fn get_component_source(name: &str) -> Option<&'static str> {
         "collapsible" => Some(include_str!("../../../components/collapsible.rs")),
         "context_menu" => Some(include_str!("../../../components/context_menu.rs")),
         "field" => Some(include_str!("../../../components/field.rs")),
+        "icon" => Some(include_str!("../../../components/icon.rs")),
         "item" => Some(include_str!("../../../components/item.rs")),
         "menubar" => Some(include_str!("../../../components/menubar.rs")),
         "navigation_menu" => Some(include_str!("../../../components/navigation_menu.rs")),
@@ -57,3 +58,10 @@ pub fn get_component_source(name: &str) -> Option<&'static str> {
         _ => None,
     }
 }
+
+/// Return the embedded source of the shared `utils` helper module.
+///
+/// Installed alongside components when the `shared_utils` transform is enabled.
+pub fn utils_source() -> &'static str {
+    include_str!("../../../components/utils.rs")
+}
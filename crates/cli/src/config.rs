@@ -2,6 +2,7 @@
 //!
 //! Handles reading and writing `shadcn-ui.toml` configuration files.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
@@ -16,6 +17,13 @@ pub struct Config {
     pub project: ProjectConfig,
     pub theme: ThemeConfig,
     pub registry: RegistryConfig,
+    #[serde(default, skip_serializing_if = "HooksConfig::is_empty")]
+    pub hooks: HooksConfig,
+    #[serde(default, skip_serializing_if = "TransformConfig::is_empty")]
+    pub transform: TransformConfig,
+    /// Registry version of each installed component, recorded by `add` and `update`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, String>,
 }
 
 /// Project-level settings.
@@ -31,12 +39,130 @@ pub struct ThemeConfig {
     pub base_color: String,
     pub radius: String,
     pub dark_mode: bool,
+    /// Layout density: `compact`, `default` or `comfortable`.
+    #[serde(default = "default_density")]
+    pub density: String,
+    /// Accessibility variant applied to the theme: `high_contrast`,
+    /// `deuteranopia` or `protanopia`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    /// How the generated `theme.rs` builds palettes: `runtime` constructors,
+    /// or `const` palettes with a `themes()` list of the custom themes.
+    #[serde(default = "default_codegen")]
+    pub codegen: String,
+    /// Whether the project depends on the `shadcn-ui-theme` crate. The
+    /// generated `theme.rs` then converts the crate's themes and hot reloads
+    /// theme files with `watch_theme_file`.
+    #[serde(default)]
+    pub theme_crate: bool,
 }
 
+fn default_density() -> String {
+    "default".to_string()
+}
+
+fn default_codegen() -> String {
+    "runtime".to_string()
+}
+
+/// The official registry, whose index the CLI embeds.
+pub const DEFAULT_REGISTRY_URL: &str = "https://shadcn-ui-rs.dev/registry";
+
 /// Registry settings.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegistryConfig {
     pub url: String,
+    /// Registry version the project last installed components from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Commands run before or after CLI operations. See [`crate::hooks`].
+///
+/// Each hook accepts a single command string or a list of commands.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HooksConfig {
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub pre_add: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub post_add: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub post_update: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "one_or_many",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub post_theme_apply: Vec<String>,
+}
+
+impl HooksConfig {
+    /// Whether no hook commands are configured.
+    pub fn is_empty(&self) -> bool {
+        self.pre_add.is_empty()
+            && self.post_add.is_empty()
+            && self.post_update.is_empty()
+            && self.post_theme_apply.is_empty()
+    }
+}
+
+/// Source transforms applied to component files at install time.
+/// See [`crate::transform`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransformConfig {
+    /// Remove `#[cfg(test)]` items.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub strip_tests: bool,
+    /// Remove `///` and `//!` doc comments.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub strip_doc_comments: bool,
+    /// Prefix for public component types, e.g. `"Ui"` turns `Button` into `UiButton`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_prefix: Option<String>,
+    /// Replace duplicated helpers with imports from a shared `utils` module.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub shared_utils: bool,
+}
+
+impl TransformConfig {
+    /// Whether no transforms are enabled.
+    pub fn is_empty(&self) -> bool {
+        !self.strip_tests
+            && !self.strip_doc_comments
+            && self.type_prefix.as_deref().is_none_or(str::is_empty)
+            && !self.shared_utils
+    }
+}
+
+/// Deserialize either `"cmd"` or `["cmd1", "cmd2"]` into a list of commands.
+fn one_or_many<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(command) => vec![command],
+        OneOrMany::Many(commands) => commands,
+    })
 }
 
 impl Default for Config {
@@ -50,10 +176,18 @@ impl Default for Config {
                 base_color: "zinc".to_string(),
                 radius: "md".to_string(),
                 dark_mode: true,
+                density: default_density(),
+                variant: None,
+                codegen: default_codegen(),
+                theme_crate: false,
             },
             registry: RegistryConfig {
-                url: "https://shadcn-ui-rs.dev/registry".to_string(),
+                url: DEFAULT_REGISTRY_URL.to_string(),
+                version: None,
             },
+            hooks: HooksConfig::default(),
+            transform: TransformConfig::default(),
+            components: BTreeMap::new(),
         }
     }
 }
@@ -77,6 +211,21 @@ impl Config {
         Ok(())
     }
 
+    /// Record the registry version of installed components.
+    pub fn record_versions<'a>(
+        &mut self,
+        registry: &shadcn_ui_registry::Registry,
+        names: impl IntoIterator<Item = &'a str>,
+    ) {
+        self.registry.version = Some(registry.version.clone());
+        for name in names {
+            if let Some(meta) = registry.find(name) {
+                self.components
+                    .insert(name.to_string(), meta.version.clone());
+            }
+        }
+    }
+
     /// Check whether a `shadcn-ui.toml` exists in the given directory.
     pub fn exists(project_dir: &Path) -> bool {
         config_path(project_dir).exists()
@@ -87,3 +236,43 @@ impl Config {
 pub fn config_path(project_dir: &Path) -> PathBuf {
     project_dir.join(CONFIG_FILE_NAME)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hooks_accept_one_or_many_commands() {
+        let hooks: HooksConfig = toml::from_str(
+            r#"
+pre_add = "git diff --quiet"
+post_add = ["cargo fmt", "cargo check"]
+post_update = []
+"#,
+        )
+        .unwrap();
+        assert_eq!(hooks.pre_add, ["git diff --quiet"]);
+        assert_eq!(hooks.post_add, ["cargo fmt", "cargo check"]);
+        assert!(hooks.post_update.is_empty());
+        assert!(hooks.post_theme_apply.is_empty());
+        assert!(!hooks.is_empty());
+    }
+
+    #[test]
+    fn test_hooks_reject_other_types() {
+        assert!(toml::from_str::<HooksConfig>("post_add = 1").is_err());
+        assert!(toml::from_str::<HooksConfig>("post_add = [\"fmt\", 1]").is_err());
+    }
+
+    #[test]
+    fn test_empty_hooks_are_not_saved() {
+        let content = toml::to_string_pretty(&Config::default()).unwrap();
+        assert!(!content.contains("[hooks]"));
+
+        let mut config = Config::default();
+        config.hooks.post_theme_apply = vec!["cargo fmt".to_string()];
+        let content = toml::to_string_pretty(&config).unwrap();
+        let loaded: Config = toml::from_str(&content).unwrap();
+        assert_eq!(loaded.hooks.post_theme_apply, ["cargo fmt"]);
+    }
+}
@@ -0,0 +1,195 @@
+//! User-defined hook commands run around CLI operations.
+//!
+//! Hooks are configured in the `[hooks]` table of `shadcn-ui.toml`. Each hook
+//! is a list of shell commands. The affected file paths are passed both as
+//! arguments and, newline-separated, in the `SHADCN_UI_FILES` environment
+//! variable. `SHADCN_UI_HOOK` holds the hook name.
+//!
+//! On Unix commands run with `sh -c` and receive the paths as `$@`. On Windows
+//! they run with `cmd /C`, and the paths are appended to the command, each in
+//! double quotes, so `prettier --write` becomes
+//! `prettier --write "src\components\ui\button.rs"`.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+
+use crate::config::HooksConfig;
+
+/// The points at which hooks can run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreAdd,
+    PostAdd,
+    PostUpdate,
+    PostThemeApply,
+}
+
+impl Hook {
+    /// The hook's key in the `[hooks]` table.
+    pub fn name(self) -> &'static str {
+        match self {
+            Hook::PreAdd => "pre_add",
+            Hook::PostAdd => "post_add",
+            Hook::PostUpdate => "post_update",
+            Hook::PostThemeApply => "post_theme_apply",
+        }
+    }
+
+    fn commands(self, hooks: &HooksConfig) -> &[String] {
+        match self {
+            Hook::PreAdd => &hooks.pre_add,
+            Hook::PostAdd => &hooks.post_add,
+            Hook::PostUpdate => &hooks.post_update,
+            Hook::PostThemeApply => &hooks.post_theme_apply,
+        }
+    }
+}
+
+/// Run every command configured for `hook`, stopping at the first failure.
+///
+/// Does nothing when `enabled` is false (the `--no-hooks` flag) or when no
+/// files were affected.
+pub fn run(hook: Hook, hooks: &HooksConfig, files: &[PathBuf], enabled: bool) -> Result<()> {
+    let commands = hook.commands(hooks);
+    if !enabled || commands.is_empty() || files.is_empty() {
+        return Ok(());
+    }
+
+    let files_env = files_env(files);
+
+    for command in commands {
+        println!("  > {} hook: {}", hook.name(), command);
+
+        let status = shell_command(command, files)
+            .env("SHADCN_UI_HOOK", hook.name())
+            .env("SHADCN_UI_FILES", &files_env)
+            .status()
+            .with_context(|| format!("Failed to run {} hook: {}", hook.name(), command))?;
+
+        if !status.success() {
+            bail!(
+                "{} hook failed ({}): {}\n\nRe-run with --no-hooks to skip hooks.",
+                hook.name(),
+                status,
+                command
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Build a platform shell invocation for `command` with `files` as arguments.
+#[cfg(not(windows))]
+fn shell_command(command: &str, files: &[PathBuf]) -> Command {
+    let mut cmd = Command::new("sh");
+    // `sh -c <script> <$0> <$1>...`: the file paths become `$@`.
+    cmd.arg("-c").arg(command).arg("shadcn-ui").args(files);
+    cmd
+}
+
+/// Build a platform shell invocation for `command` with `files` as arguments.
+#[cfg(windows)]
+fn shell_command(command: &str, files: &[PathBuf]) -> Command {
+    use std::os::windows::process::CommandExt;
+
+    let mut cmd = Command::new("cmd");
+    // `/S` makes cmd strip exactly the outer quotes, keeping those in the line
+    cmd.raw_arg("/S /C")
+        .raw_arg(format!("\"{}\"", cmd_line(command, files)));
+    cmd
+}
+
+/// `command` followed by each file in double quotes, for `cmd /C`.
+///
+/// Quoting keeps paths with spaces in one argument and stops cmd from reading
+/// characters such as `&` or `^` in them.
+#[cfg(any(windows, test))]
+fn cmd_line(command: &str, files: &[PathBuf]) -> String {
+    let mut line = command.to_string();
+    for file in files {
+        line.push_str(&format!(" \"{}\"", file.display()));
+    }
+    line
+}
+
+/// The affected files, one per line, for `SHADCN_UI_FILES`.
+fn files_env(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|f| f.display().to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(files: &[&str]) -> Vec<PathBuf> {
+        files.iter().map(PathBuf::from).collect()
+    }
+
+    #[test]
+    fn test_files_env_is_one_path_per_line() {
+        let files = paths(&["src/ui/button.rs", "src/my ui/card & co.rs"]);
+        assert_eq!(
+            files_env(&files),
+            "src/ui/button.rs\nsrc/my ui/card & co.rs"
+        );
+        assert_eq!(files_env(&[]), "");
+    }
+
+    #[test]
+    fn test_cmd_line_quotes_each_path() {
+        let files = paths(&[r"src\ui\button.rs", r"C:\My Project\a&b.rs"]);
+        assert_eq!(
+            cmd_line("prettier --write", &files),
+            r#"prettier --write "src\ui\button.rs" "C:\My Project\a&b.rs""#
+        );
+        assert_eq!(cmd_line("cargo fmt", &[]), "cargo fmt");
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_shell_command_passes_paths_as_arguments() {
+        let files = paths(&["a b.rs", "c&d.rs", "$HOME.rs"]);
+        let output = shell_command(r#"printf '%s|' "$#" "$@""#, &files)
+            .output()
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(output.stdout).unwrap(),
+            "3|a b.rs|c&d.rs|$HOME.rs|"
+        );
+    }
+
+    #[test]
+    fn test_run_skips_without_files_or_when_disabled() {
+        let hooks = HooksConfig {
+            post_add: vec!["exit 1".to_string()],
+            ..Default::default()
+        };
+        let files = paths(&["button.rs"]);
+        assert!(run(Hook::PostAdd, &hooks, &[], true).is_ok());
+        assert!(run(Hook::PostAdd, &hooks, &files, false).is_ok());
+        assert!(run(Hook::PreAdd, &hooks, &files, true).is_ok());
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn test_run_stops_at_first_failure() {
+        let hooks = HooksConfig {
+            post_update: vec![
+                r#"test "$SHADCN_UI_HOOK" = post_update"#.to_string(),
+                "exit 3".to_string(),
+                "echo unreachable".to_string(),
+            ],
+            ..Default::default()
+        };
+        let err = run(Hook::PostUpdate, &hooks, &paths(&["x.rs"]), true).unwrap_err();
+        assert!(err.to_string().contains("post_update hook failed"));
+        assert!(err.to_string().contains("exit 3"));
+    }
+}
@@ -1,10 +1,17 @@
 //! shadcn-ui CLI - Add beautiful UI components to your GPUI project
 
 use clap::{Parser, Subcommand};
+use clap_complete::CompleteEnv;
 
+pub mod codegen;
 mod commands;
 pub mod component_sources;
 pub mod config;
+pub mod hooks;
+pub mod registry_index;
+pub mod theme_preview;
+pub mod transform;
+pub mod web_theme;
 
 #[derive(Parser)]
 #[command(name = "shadcn-ui")]
@@ -29,12 +36,26 @@ enum Commands {
     Diff(commands::diff::DiffArgs),
     /// Update components to latest version
     Update(commands::update::UpdateArgs),
+    /// Check for registry and component upgrades
+    UpgradeCheck(commands::upgrade_check::UpgradeCheckArgs),
     /// Manage themes
     Theme(commands::theme::ThemeArgs),
+    /// Generate shell completions
+    ///
+    /// The script calls back into shadcn-ui to complete component and theme
+    /// names, so themes created later complete without regenerating it.
+    Completions(commands::completions::CompletionsArgs),
+    /// Generate man pages
+    Man(commands::man::ManArgs),
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Answer completion requests from the script printed by `completions`
+    CompleteEnv::with_factory(commands::completions::completion_command)
+        .var(commands::completions::COMPLETE_VAR)
+        .complete();
+
     let cli = Cli::parse();
 
     match cli.command {
@@ -44,6 +65,9 @@ async fn main() -> anyhow::Result<()> {
         Commands::Remove(args) => commands::remove::run(args).await,
         Commands::Diff(args) => commands::diff::run(args).await,
         Commands::Update(args) => commands::update::run(args).await,
+        Commands::UpgradeCheck(args) => commands::upgrade_check::run(args).await,
         Commands::Theme(args) => commands::theme::run(args).await,
+        Commands::Completions(args) => commands::completions::run(args).await,
+        Commands::Man(args) => commands::man::run(args).await,
     }
 }
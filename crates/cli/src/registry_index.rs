@@ -0,0 +1,57 @@
+//! The registry index served under the `[registry] url`.
+//!
+//! Component sources always come from the registry embedded in the CLI. The
+//! index supplies what a newer or private registry adds on top: the version
+//! and changelog for `upgrade-check`, and bundles for `add` and `list`.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use shadcn_ui_registry::{Registry, default_registry};
+
+use crate::config::{Config, DEFAULT_REGISTRY_URL};
+
+/// File name of the registry index under the `[registry] url`.
+const REGISTRY_INDEX: &str = "registry.json";
+
+/// Fetch the registry index served under `base_url`.
+pub async fn fetch_registry(base_url: &str) -> Result<Registry> {
+    let url = format!("{}/{}", base_url.trim_end_matches('/'), REGISTRY_INDEX);
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .context("Failed to create HTTP client")?;
+    let body = client
+        .get(&url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to fetch {}", url))?
+        .text()
+        .await
+        .with_context(|| format!("Failed to read {}", url))?;
+    Registry::from_json(&body).with_context(|| format!("Invalid registry index at {}", url))
+}
+
+/// The embedded registry with the bundles of the configured registry.
+///
+/// The official registry's bundles are embedded, so the index is only
+/// fetched for another `[registry] url`. If that fails, a warning is printed
+/// and the embedded bundles are used.
+pub async fn registry_with_bundles(config: &Config) -> Registry {
+    let mut registry = default_registry();
+    if config.registry.url.trim_end_matches('/') == DEFAULT_REGISTRY_URL {
+        return registry;
+    }
+    match fetch_registry(&config.registry.url).await {
+        Ok(index) => registry.merge_bundles(index),
+        Err(err) => {
+            println!(
+                "Warning: could not fetch bundles from the configured registry: {:#}",
+                err
+            );
+            println!();
+        }
+    }
+    registry
+}
@@ -0,0 +1,364 @@
+//! Theme previews for `shadcn-ui theme preview`.
+//!
+//! [`terminal`] lists every token with light and dark side by side, drawing
+//! 24-bit color swatches and a row of button mockups when the terminal
+//! supports true color. [`html`] renders a standalone page with the same
+//! tokens and mockups of buttons, a card, an input and alerts, styled
+//! through the theme's CSS variables. [`diff`] lists the tokens that differ
+//! between two themes for `shadcn-ui theme diff`.
+
+use std::fmt::Write as _;
+
+use shadcn_ui_theme::{Hsl, PaletteColors, Radius};
+
+use crate::web_theme::{css_color, variable_for_token};
+
+/// Whether stdout is a terminal that accepts 24-bit colors, following the
+/// `COLORTERM` and `NO_COLOR` conventions.
+pub fn supports_truecolor() -> bool {
+    use std::io::IsTerminal;
+
+    std::io::stdout().is_terminal()
+        && std::env::var_os("NO_COLOR").is_none()
+        && std::env::var("COLORTERM").is_ok_and(|v| v == "truecolor" || v == "24bit")
+}
+
+/// Round away float noise such as 27.395996 from converted colors.
+fn round(v: f32) -> f32 {
+    (v * 1000.0).round() / 1000.0
+}
+
+/// A color as written in theme files, e.g. `hsl(240, 5.9%, 10%)`.
+fn theme_color(c: Hsl) -> String {
+    if c.a < 1.0 {
+        format!(
+            "hsla({}, {}%, {}%, {})",
+            round(c.h),
+            round(c.s),
+            round(c.l),
+            round(c.a)
+        )
+    } else {
+        format!("hsl({}, {}%, {}%)", round(c.h), round(c.s), round(c.l))
+    }
+}
+
+/// 8-bit RGB of `color` composited over `background`.
+fn rgb_over(color: Hsl, background: Hsl) -> [u8; 3] {
+    let composited = shadcn_ui_theme::mix(background, color.with_alpha(1.0), color.a);
+    composited.to_rgb().map(|v| (v * 255.0).round() as u8)
+}
+
+/// `text` drawn in `fg` on `bg` with ANSI 24-bit escapes.
+fn paint(text: &str, fg: [u8; 3], bg: [u8; 3]) -> String {
+    format!(
+        "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m{}\x1b[0m",
+        fg[0], fg[1], fg[2], bg[0], bg[1], bg[2], text
+    )
+}
+
+/// A four-cell swatch of a token, composited over the palette's background.
+fn swatch(palette: &PaletteColors, color: Hsl) -> String {
+    let rgb = rgb_over(color, palette.background);
+    paint("    ", rgb, rgb)
+}
+
+/// Button mockups for one mode, drawn on its background.
+fn button_row(palette: &PaletteColors) -> String {
+    let background = rgb_over(palette.background, palette.background);
+    let button = |label: &str, fg: Hsl, bg: Hsl| {
+        paint(
+            &format!(" {} ", label),
+            rgb_over(fg, bg),
+            rgb_over(bg, palette.background),
+        )
+    };
+    let gap = paint("  ", background, background);
+    [
+        button("Primary", palette.primary_foreground, palette.primary),
+        button("Secondary", palette.secondary_foreground, palette.secondary),
+        button(
+            "Destructive",
+            palette.destructive_foreground,
+            palette.destructive,
+        ),
+        button("Accent", palette.accent_foreground, palette.accent),
+        button("Muted", palette.muted_foreground, palette.muted),
+    ]
+    .join(&gap)
+}
+
+/// Every token of both modes, one per line, with swatches if `truecolor`.
+pub fn terminal(
+    name: &str,
+    light: &PaletteColors,
+    dark: &PaletteColors,
+    truecolor: bool,
+) -> String {
+    const VALUE_WIDTH: usize = 32;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Theme: {}", name);
+    let _ = writeln!(out, "{}", "=".repeat(40));
+    let _ = writeln!(out);
+
+    let swatch_width = if truecolor { 5 } else { 0 };
+    let _ = writeln!(
+        out,
+        "  {:<28} {:<width$} Dark",
+        "",
+        "Light",
+        width = swatch_width + VALUE_WIDTH
+    );
+    for (token, color) in light.tokens() {
+        let dark_color = dark.get(token).unwrap_or(color);
+        let cell = |palette: &PaletteColors, color: Hsl| {
+            let value = format!("{:<VALUE_WIDTH$}", theme_color(color));
+            if truecolor {
+                format!("{} {}", swatch(palette, color), value)
+            } else {
+                value
+            }
+        };
+        let _ = writeln!(
+            out,
+            "  {:<28} {} {}",
+            token,
+            cell(light, color),
+            cell(dark, dark_color).trim_end()
+        );
+    }
+
+    if truecolor {
+        let _ = writeln!(out);
+        let _ = writeln!(out, "  {:<6} {}", "Light", button_row(light));
+        let _ = writeln!(out, "  {:<6} {}", "Dark", button_row(dark));
+    }
+    out
+}
+
+/// The tokens that differ between two themes in each mode, as
+/// `(mode, before, after)`, and how the contrast of affected text pairs
+/// changes. Draws swatches if `truecolor`.
+pub fn diff(
+    before: &str,
+    after: &str,
+    modes: &[(&str, &PaletteColors, &PaletteColors)],
+    truecolor: bool,
+) -> String {
+    const VALUE_WIDTH: usize = 32;
+
+    let mut out = String::new();
+    let _ = writeln!(out, "Theme diff: {} -> {}", before, after);
+    let _ = writeln!(out, "{}", "=".repeat(40));
+
+    for (mode, a, b) in modes {
+        let diff = a.diff(b);
+        let _ = writeln!(out);
+        if diff.is_empty() {
+            let _ = writeln!(out, "{}: no differences", mode);
+            continue;
+        }
+        let _ = writeln!(
+            out,
+            "{}: {} of {} tokens differ",
+            mode,
+            diff.tokens.len(),
+            PaletteColors::TOKENS.len()
+        );
+        for token in &diff.tokens {
+            let cell = |palette: &PaletteColors, color: Hsl| {
+                let value = format!("{:<VALUE_WIDTH$}", theme_color(color));
+                if truecolor {
+                    format!("{} {}", swatch(palette, color), value)
+                } else {
+                    value
+                }
+            };
+            let _ = writeln!(
+                out,
+                "  {:<28} {} -> {}",
+                token.token,
+                cell(a, token.before),
+                cell(b, token.after).trim_end()
+            );
+        }
+
+        if diff.contrast.is_empty() {
+            continue;
+        }
+        let _ = writeln!(out);
+        let _ = writeln!(out, "  Contrast");
+        for delta in &diff.contrast {
+            let _ = writeln!(
+                out,
+                "  {:<48} {:>5.2}:1 -> {:>5.2}:1 ({:+.2})  Lc {:>6.1} -> {:>6.1} ({:+.1})",
+                format!("{} on {}", delta.after.foreground, delta.after.background),
+                delta.before.ratio,
+                delta.after.ratio,
+                delta.ratio(),
+                delta.before.apca,
+                delta.after.apca,
+                delta.apca()
+            );
+        }
+    }
+    out
+}
+
+/// Escape text for HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// CSS variable declarations for one palette.
+fn variables(palette: &PaletteColors) -> String {
+    palette
+        .tokens()
+        .into_iter()
+        .map(|(token, color)| {
+            format!(
+                "      --{}: {};\n",
+                variable_for_token(token),
+                css_color(color)
+            )
+        })
+        .collect()
+}
+
+/// The token grid and component mockups for one mode.
+fn panel(mode: &str, palette: &PaletteColors) -> String {
+    let tokens: String = palette
+        .tokens()
+        .into_iter()
+        .map(|(token, color)| {
+            format!(
+                r#"        <li><span class="swatch" style="background: var(--{var})"></span><code>{token}</code><small>{value}</small></li>
+"#,
+                var = variable_for_token(token),
+                value = theme_color(color),
+            )
+        })
+        .collect();
+
+    format!(
+        r#"    <section class="panel {mode}">
+      <h2>{title}</h2>
+      <div class="row">
+        <button class="btn primary">Primary</button>
+        <button class="btn secondary">Secondary</button>
+        <button class="btn destructive">Destructive</button>
+        <button class="btn outline">Outline</button>
+        <button class="btn ghost">Ghost</button>
+        <button class="btn link">Link</button>
+      </div>
+      <div class="card">
+        <h3>Create project</h3>
+        <p class="muted">Deploy your new project in one click.</p>
+        <label for="name-{mode}">Name</label>
+        <input id="name-{mode}" class="input" placeholder="Name of your project">
+        <div class="row end">
+          <button class="btn outline">Cancel</button>
+          <button class="btn primary">Deploy</button>
+        </div>
+      </div>
+      <div class="alert">
+        <strong>Heads up!</strong>
+        <p class="muted">You can add components to your app using the CLI.</p>
+      </div>
+      <div class="alert destructive">
+        <strong>Error</strong>
+        <p>Your session has expired. Please log in again.</p>
+      </div>
+      <div class="row">
+        <span class="badge success">Success</span>
+        <span class="badge warning">Warning</span>
+        <span class="badge info">Info</span>
+      </div>
+      <div class="row chart">
+        <span style="background: var(--chart-1)"></span>
+        <span style="background: var(--chart-2)"></span>
+        <span style="background: var(--chart-3)"></span>
+        <span style="background: var(--chart-4)"></span>
+        <span style="background: var(--chart-5)"></span>
+      </div>
+      <ul class="tokens">
+{tokens}      </ul>
+    </section>
+"#,
+        title = if mode == "dark" { "Dark" } else { "Light" },
+    )
+}
+
+/// A standalone HTML page previewing both modes side by side.
+pub fn html(name: &str, light: &PaletteColors, dark: &PaletteColors, radius: Radius) -> String {
+    let radius = match radius {
+        Radius::Full => "9999px".to_string(),
+        _ => format!("{}px", radius.to_px()),
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>{name} theme preview</title>
+  <style>
+    .light {{
+{light_vars}    }}
+    .dark {{
+{dark_vars}    }}
+    * {{ box-sizing: border-box; }}
+    body {{ margin: 0; font: 14px/1.5 system-ui, sans-serif; }}
+    h1 {{ margin: 0; padding: 16px 24px; font-size: 18px; }}
+    main {{ display: grid; grid-template-columns: 1fr 1fr; }}
+    .panel {{ padding: 24px; background: var(--background); color: var(--foreground); }}
+    .panel ::selection {{ background: var(--selection); }}
+    h2 {{ margin: 0 0 16px; font-size: 16px; }}
+    h3 {{ margin: 0; font-size: 16px; }}
+    p {{ margin: 4px 0 0; }}
+    .muted {{ color: var(--muted-foreground); }}
+    .row {{ display: flex; flex-wrap: wrap; gap: 8px; margin-bottom: 16px; }}
+    .end {{ justify-content: flex-end; margin: 16px 0 0; }}
+    .btn {{ font: inherit; font-weight: 500; height: 36px; padding: 0 16px; border: 1px solid transparent; border-radius: {radius}; cursor: pointer; }}
+    .btn.primary {{ background: var(--primary); color: var(--primary-foreground); }}
+    .btn.secondary {{ background: var(--secondary); color: var(--secondary-foreground); }}
+    .btn.destructive {{ background: var(--destructive); color: var(--destructive-foreground); }}
+    .btn.outline {{ background: var(--background); color: var(--foreground); border-color: var(--input); }}
+    .btn.outline:hover, .btn.ghost:hover {{ background: var(--accent); color: var(--accent-foreground); }}
+    .btn.ghost {{ background: transparent; color: var(--foreground); }}
+    .btn.link {{ background: transparent; color: var(--primary); text-decoration: underline; }}
+    .btn:focus-visible, .input:focus {{ outline: 2px solid var(--ring); outline-offset: 2px; }}
+    .card {{ background: var(--card); color: var(--card-foreground); border: 1px solid var(--border); border-radius: {radius}; padding: 24px; margin-bottom: 16px; }}
+    label {{ display: block; margin: 16px 0 6px; font-weight: 500; }}
+    .input {{ width: 100%; height: 36px; padding: 0 12px; font: inherit; background: transparent; color: var(--foreground); border: 1px solid var(--input); border-radius: {radius}; }}
+    .input::placeholder {{ color: var(--muted-foreground); }}
+    .alert {{ border: 1px solid var(--border); border-radius: {radius}; padding: 12px 16px; margin-bottom: 16px; }}
+    .alert.destructive {{ color: var(--destructive); border-color: var(--destructive); }}
+    .badge {{ padding: 2px 10px; border-radius: 9999px; font-size: 12px; font-weight: 600; }}
+    .badge.success {{ background: var(--success); color: var(--success-foreground); }}
+    .badge.warning {{ background: var(--warning); color: var(--warning-foreground); }}
+    .badge.info {{ background: var(--info); color: var(--info-foreground); }}
+    .chart span {{ flex: 1; height: 24px; border-radius: 4px; }}
+    .tokens {{ list-style: none; margin: 24px 0 0; padding: 0; display: grid; grid-template-columns: repeat(auto-fill, minmax(220px, 1fr)); gap: 8px; }}
+    .tokens li {{ display: grid; grid-template-columns: 32px 1fr; column-gap: 8px; align-items: center; }}
+    .tokens small {{ grid-column: 2; color: var(--muted-foreground); }}
+    .swatch {{ grid-row: span 2; width: 32px; height: 32px; border: 1px solid var(--border); border-radius: 6px; }}
+  </style>
+</head>
+<body>
+  <h1>{name}</h1>
+  <main>
+{light}{dark}  </main>
+</body>
+</html>
+"#,
+        name = escape(name),
+        light_vars = variables(light),
+        dark_vars = variables(dark),
+        light = panel("light", light),
+        dark = panel("dark", dark),
+    )
+}
@@ -0,0 +1,1204 @@
+//! Install-time source transforms for component files.
+//!
+//! Transforms are configured in the `[transform]` table of `shadcn-ui.toml`.
+//! Each component file is parsed with `syn` and the syntax tree decides what
+//! to change; the changes are then spliced into the original text by span, so
+//! formatting and line comments survive. The result is parsed again to make
+//! sure it is still valid Rust.
+
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+use proc_macro2::{Ident, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::spanned::Spanned;
+use syn::visit::{self, Visit};
+use syn::{
+    AssocType, Attribute, Constraint, File, ImplItemType, Item, ItemFn, ItemUse, Meta, Path,
+    TraitItemType, UseTree, Variant, Visibility,
+};
+
+use crate::component_sources;
+use crate::config::TransformConfig;
+
+/// Module name of the shared helper module installed by `shared_utils`.
+pub const UTILS_MODULE: &str = "utils";
+
+/// Maximum line width used when re-rendering `use` items (rustfmt's default).
+const MAX_WIDTH: usize = 100;
+
+/// Embedded source for the component `name` with the configured transforms applied.
+pub fn component_source(name: &str, config: &TransformConfig) -> Result<Option<String>> {
+    component_sources::get_component_source(name)
+        .map(|source| {
+            apply(source, config)
+                .with_context(|| format!("Failed to transform component '{}'", name))
+        })
+        .transpose()
+}
+
+/// Apply the configured transforms to a component source file.
+///
+/// Returns the source unchanged when no transform is enabled.
+pub fn apply(source: &str, config: &TransformConfig) -> Result<String> {
+    if config.is_empty() {
+        return Ok(source.to_string());
+    }
+
+    let file = syn::parse_file(source).context("Failed to parse component source")?;
+    let prefix = config.type_prefix.as_deref().filter(|p| !p.is_empty());
+    let rename = |name: &str| match prefix {
+        Some(prefix) if component_types().contains(name) => format!("{prefix}{name}"),
+        _ => name.to_string(),
+    };
+
+    let mut edits = Edits::new(source);
+
+    if config.strip_tests {
+        for item in &file.items {
+            if is_cfg_test(item_attrs(item)) {
+                edits.remove(item.span().byte_range());
+            }
+        }
+    }
+
+    if config.shared_utils {
+        share_helpers(&file, config, &rename, &mut edits);
+    }
+
+    if config.strip_doc_comments {
+        DocStripper { edits: &mut edits }.visit_file(&file);
+    }
+
+    if let Some(prefix) = prefix {
+        let mut renamer = Renamer {
+            names: component_types(),
+            prefix,
+            edits: &mut edits,
+        };
+        renamer.visit_file(&file);
+    }
+
+    let output = edits.apply();
+    syn::parse_file(&output).context("Transformed component source is not valid Rust")?;
+    Ok(output)
+}
+
+/// Collect the public type names of every embedded component, once.
+///
+/// Renaming uses the full set rather than the current file's, so that
+/// cross-component imports such as `DropdownMenuItem` in `context_menu.rs`
+/// stay consistent.
+fn component_types() -> &'static HashSet<String> {
+    static TYPES: OnceLock<HashSet<String>> = OnceLock::new();
+    TYPES.get_or_init(|| {
+        let mut names = HashSet::new();
+        let registry = shadcn_ui_registry::default_registry();
+        for name in registry.component_names() {
+            let Some(file) = component_sources::get_component_source(name)
+                .and_then(|source| syn::parse_file(source).ok())
+            else {
+                continue;
+            };
+            for item in &file.items {
+                let (vis, ident) = match item {
+                    Item::Struct(s) => (&s.vis, &s.ident),
+                    Item::Enum(e) => (&e.vis, &e.ident),
+                    Item::Type(t) => (&t.vis, &t.ident),
+                    Item::Trait(t) => (&t.vis, &t.ident),
+                    _ => continue,
+                };
+                if matches!(vis, Visibility::Public(_)) {
+                    names.insert(ident.to_string());
+                }
+            }
+        }
+        names
+    })
+}
+
+/// Replace private helper copies with imports from the shared `utils` module.
+///
+/// A helper is only replaced when its signature and body match the shared
+/// version exactly. Imports that were only needed by the removed helpers are
+/// pruned so the file stays warning-free.
+fn share_helpers(
+    file: &File,
+    config: &TransformConfig,
+    rename: &dyn Fn(&str) -> String,
+    edits: &mut Edits,
+) {
+    let shared = shared_helpers();
+    let mut removed: Vec<&ItemFn> = Vec::new();
+
+    for item in &file.items {
+        if let Item::Fn(f) = item
+            && matches!(f.vis, Visibility::Inherited)
+            && shared
+                .iter()
+                .any(|(name, tokens)| f.sig.ident == name && *tokens == fn_tokens(f))
+        {
+            edits.remove(item.span().byte_range());
+            removed.push(f);
+        }
+    }
+
+    if removed.is_empty() {
+        return;
+    }
+
+    // Identifiers that only the removed helpers referenced
+    let mut helper_idents = HashSet::new();
+    for f in &removed {
+        collect_idents(f.to_token_stream(), &mut helper_idents);
+    }
+    let mut used_idents = HashSet::new();
+    for item in &file.items {
+        let is_removed =
+            matches!(item, Item::Fn(f) if removed.iter().any(|r| r.sig.ident == f.sig.ident));
+        let is_stripped = config.strip_tests && is_cfg_test(item_attrs(item));
+        if !is_removed && !is_stripped && !matches!(item, Item::Use(_)) {
+            collect_idents(item.to_token_stream(), &mut used_idents);
+        }
+    }
+    let unused: HashSet<String> = helper_idents.difference(&used_idents).cloned().collect();
+
+    let uses: Vec<&ItemUse> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Use(u) => Some(u),
+            _ => None,
+        })
+        .collect();
+
+    let names: Vec<String> = removed.iter().map(|f| f.sig.ident.to_string()).collect();
+    let import = if names.len() == 1 {
+        format_use(&format!("super::{UTILS_MODULE}::{}", names[0]))
+    } else {
+        format_use(&format!("super::{UTILS_MODULE}::{{{}}}", names.join(", ")))
+    };
+
+    let Some((last, rest)) = uses.split_last() else {
+        edits.replace(0..0, format!("{import}\n\n"));
+        return;
+    };
+    let keep = |name: &str| !unused.contains(name);
+    let prune = |item: &ItemUse| {
+        if matches!(item.vis, Visibility::Inherited) && imports_any(&item.tree, &unused) {
+            Some(render_use_tree(&item.tree, &keep, rename).map(|tree| format_use(&tree)))
+        } else {
+            None
+        }
+    };
+
+    for item in rest {
+        match prune(item) {
+            Some(Some(text)) => edits.replace(item.span().byte_range(), text),
+            Some(None) => edits.remove(item.span().byte_range()),
+            None => {}
+        }
+    }
+
+    // The utils import goes directly after the last `use` item
+    let range = last.span().byte_range();
+    match prune(last) {
+        Some(Some(text)) => edits.replace(range, format!("{text}\n{import}")),
+        Some(None) => edits.replace(range, import),
+        None => edits.replace(range.end..range.end, format!("\n{import}")),
+    }
+}
+
+/// The helpers defined in the shared `utils` module, as `(name, tokens)`.
+fn shared_helpers() -> &'static [(String, String)] {
+    static HELPERS: OnceLock<Vec<(String, String)>> = OnceLock::new();
+    HELPERS.get_or_init(|| {
+        let file = syn::parse_file(component_sources::utils_source())
+            .expect("embedded utils.rs is valid Rust");
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Fn(f) => Some((f.sig.ident.to_string(), fn_tokens(f))),
+                _ => None,
+            })
+            .collect()
+    })
+}
+
+/// Token string of a function without its attributes and visibility.
+fn fn_tokens(f: &ItemFn) -> String {
+    let mut f = f.clone();
+    f.attrs.clear();
+    f.vis = Visibility::Inherited;
+    f.to_token_stream().to_string()
+}
+
+fn collect_idents(tokens: TokenStream, out: &mut HashSet<String>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Ident(ident) => {
+                out.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), out),
+            _ => {}
+        }
+    }
+}
+
+fn imports_any(tree: &UseTree, names: &HashSet<String>) -> bool {
+    match tree {
+        UseTree::Path(p) => imports_any(&p.tree, names),
+        UseTree::Name(n) => names.contains(&n.ident.to_string()),
+        UseTree::Rename(r) => names.contains(&r.rename.to_string()),
+        UseTree::Glob(_) => false,
+        UseTree::Group(g) => g.items.iter().any(|t| imports_any(t, names)),
+    }
+}
+
+/// Render a use tree, dropping names rejected by `keep`.
+///
+/// Returns `None` when nothing is left. Groups reduced to one entry are
+/// flattened, as rustfmt would write them.
+fn render_use_tree(
+    tree: &UseTree,
+    keep: &dyn Fn(&str) -> bool,
+    rename: &dyn Fn(&str) -> String,
+) -> Option<String> {
+    match tree {
+        UseTree::Path(p) => {
+            render_use_tree(&p.tree, keep, rename).map(|rest| format!("{}::{}", p.ident, rest))
+        }
+        UseTree::Name(n) => {
+            let name = n.ident.to_string();
+            keep(&name).then(|| rename(&name))
+        }
+        UseTree::Rename(r) => keep(&r.rename.to_string())
+            .then(|| format!("{} as {}", rename(&r.ident.to_string()), r.rename)),
+        UseTree::Glob(_) => Some("*".to_string()),
+        UseTree::Group(g) => {
+            let items: Vec<String> = g
+                .items
+                .iter()
+                .filter_map(|t| render_use_tree(t, keep, rename))
+                .collect();
+            match items.len() {
+                0 => None,
+                1 => items.into_iter().next(),
+                _ => Some(format!("{{{}}}", items.join(", "))),
+            }
+        }
+    }
+}
+
+/// Format `use <tree>;`, wrapping a long trailing group the way rustfmt does.
+fn format_use(tree: &str) -> String {
+    let line = format!("use {tree};");
+    if line.len() <= MAX_WIDTH {
+        return line;
+    }
+    let Some((head, inner)) = tree
+        .split_once('{')
+        .and_then(|(head, rest)| Some((head, rest.strip_suffix('}')?)))
+        .filter(|(_, inner)| !inner.contains('{'))
+    else {
+        return line;
+    };
+
+    let mut out = format!("use {head}{{\n");
+    let mut current = String::new();
+    for item in inner.split(", ") {
+        let entry = format!("{item},");
+        if !current.is_empty() && 4 + current.len() + 1 + entry.len() > MAX_WIDTH {
+            out.push_str(&format!("    {current}\n"));
+            current.clear();
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&entry);
+    }
+    out.push_str(&format!("    {current}\n}};"));
+    out
+}
+
+/// Removes `///` and `//!` doc comments.
+struct DocStripper<'e, 's> {
+    edits: &'e mut Edits<'s>,
+}
+
+impl<'ast> Visit<'ast> for DocStripper<'_, '_> {
+    fn visit_attribute(&mut self, attr: &'ast Attribute) {
+        if attr.path().is_ident("doc") && matches!(attr.meta, Meta::NameValue(_)) {
+            self.edits.remove(attr.span().byte_range());
+        }
+    }
+}
+
+/// Prefixes every reference to a public component type.
+///
+/// Only names that can refer to a component's own types are renamed: the
+/// first segment of a path, or a later one reached from `crate`, `super` or
+/// `self` through module names, as in `super::dropdown_menu::DropdownMenuItem`.
+/// Enum variants, associated types such as the `Item` in
+/// `IntoIterator<Item = AnyElement>` and items of other crates keep their
+/// names even when a component type shares them.
+struct Renamer<'e, 's> {
+    names: &'static HashSet<String>,
+    prefix: &'e str,
+    edits: &'e mut Edits<'s>,
+}
+
+/// Path roots whose later segments can name component types.
+const LOCAL_ROOTS: &[&str] = &["crate", "super", "self"];
+
+impl Renamer<'_, '_> {
+    fn rename(&mut self, ident: &Ident) {
+        let name = ident.to_string();
+        if self.names.contains(&name) {
+            self.edits.replace(
+                ident.span().byte_range(),
+                format!("{}{}", self.prefix, name),
+            );
+        }
+    }
+
+    /// Rename the last of `segments` if the path can name a component type.
+    fn rename_segment(&mut self, segments: &[&Ident]) {
+        let Some((last, modules)) = segments.split_last() else {
+            return;
+        };
+        let local = match modules.split_first() {
+            None => true,
+            Some((root, rest)) => {
+                LOCAL_ROOTS.iter().any(|r| *root == r) && rest.iter().all(|m| is_module(m))
+            }
+        };
+        if local {
+            self.rename(last);
+        }
+    }
+
+    /// Rename the names imported by a `use` tree below `segments`.
+    fn visit_use(&mut self, tree: &UseTree, segments: &mut Vec<Ident>) {
+        match tree {
+            UseTree::Path(path) => {
+                segments.push(path.ident.clone());
+                self.visit_use(&path.tree, segments);
+                segments.pop();
+            }
+            UseTree::Name(syn::UseName { ident })
+            | UseTree::Rename(syn::UseRename { ident, .. }) => {
+                let mut path: Vec<&Ident> = segments.iter().collect();
+                path.push(ident);
+                self.rename_segment(&path);
+            }
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.visit_use(tree, segments);
+                }
+            }
+            UseTree::Glob(_) => {}
+        }
+    }
+
+    /// Rename identifiers inside macro input, which `syn` does not parse.
+    fn visit_tokens(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let punct =
+            |i: usize, c: char| matches!(&tokens[i], TokenTree::Punct(p) if p.as_char() == c);
+        for (i, tt) in tokens.iter().enumerate() {
+            match tt {
+                TokenTree::Group(group) => self.visit_tokens(group.stream()),
+                TokenTree::Ident(ident) => {
+                    // `Item = ...` in generic arguments and `type Item = ...`
+                    let binding = i + 1 < tokens.len()
+                        && matches!(&tokens[i + 1], TokenTree::Punct(p) if p.as_char() == '=' && p.spacing() == Spacing::Alone)
+                        && i > 0
+                        && (punct(i - 1, '<')
+                            || punct(i - 1, ',')
+                            || matches!(&tokens[i - 1], TokenTree::Ident(prev) if prev == "type"));
+                    if binding {
+                        continue;
+                    }
+                    // Collect the path leading up to this identifier
+                    let mut segments = vec![ident];
+                    let mut j = i;
+                    while j >= 3 && punct(j - 1, ':') && punct(j - 2, ':') {
+                        let TokenTree::Ident(prev) = &tokens[j - 3] else {
+                            break;
+                        };
+                        segments.push(prev);
+                        j -= 3;
+                    }
+                    segments.reverse();
+                    self.rename_segment(&segments);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Whether a path segment names a module rather than a type.
+fn is_module(ident: &Ident) -> bool {
+    ident
+        .to_string()
+        .starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+impl<'ast> Visit<'ast> for Renamer<'_, '_> {
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        self.rename(ident);
+    }
+
+    fn visit_path(&mut self, path: &'ast Path) {
+        let idents: Vec<&Ident> = path.segments.iter().map(|s| &s.ident).collect();
+        for (i, segment) in path.segments.iter().enumerate() {
+            if path.leading_colon.is_none() {
+                self.rename_segment(&idents[..=i]);
+            }
+            self.visit_path_arguments(&segment.arguments);
+        }
+    }
+
+    fn visit_item_use(&mut self, item: &'ast ItemUse) {
+        self.visit_use(&item.tree, &mut Vec::new());
+    }
+
+    fn visit_assoc_type(&mut self, assoc: &'ast AssocType) {
+        if let Some(generics) = &assoc.generics {
+            self.visit_angle_bracketed_generic_arguments(generics);
+        }
+        self.visit_type(&assoc.ty);
+    }
+
+    fn visit_constraint(&mut self, constraint: &'ast Constraint) {
+        if let Some(generics) = &constraint.generics {
+            self.visit_angle_bracketed_generic_arguments(generics);
+        }
+        for bound in &constraint.bounds {
+            self.visit_type_param_bound(bound);
+        }
+    }
+
+    fn visit_impl_item_type(&mut self, item: &'ast ImplItemType) {
+        // The associated type's own name belongs to the trait
+        self.visit_generics(&item.generics);
+        self.visit_type(&item.ty);
+    }
+
+    fn visit_trait_item_type(&mut self, item: &'ast TraitItemType) {
+        self.visit_generics(&item.generics);
+        for bound in &item.bounds {
+            self.visit_type_param_bound(bound);
+        }
+        if let Some((_, ty)) = &item.default {
+            self.visit_type(ty);
+        }
+    }
+
+    fn visit_variant(&mut self, variant: &'ast Variant) {
+        // Variant names are left alone even when they match a type name,
+        // e.g. `DropdownMenuEntry::Separator`.
+        for attr in &variant.attrs {
+            self.visit_attribute(attr);
+        }
+        self.visit_fields(&variant.fields);
+        if let Some((_, discriminant)) = &variant.discriminant {
+            self.visit_expr(discriminant);
+        }
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        self.visit_path(&mac.path);
+        self.visit_tokens(mac.tokens.clone());
+    }
+
+    fn visit_attribute(&mut self, attr: &'ast Attribute) {
+        // Derives and other attribute arguments never name component types.
+        visit::visit_path(self, attr.path());
+    }
+}
+
+/// Sort ranges and merge any that overlap or are separated only by whitespace.
+fn merge_ranges(source: &str, mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.sort_by_key(|r| (r.start, r.end));
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last)
+                if range.start <= last.end || source[last.end..range.start].trim().is_empty() =>
+            {
+                last.end = last.end.max(range.end)
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn is_cfg_test(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg") && attr.parse_args::<Ident>().is_ok_and(|arg| arg == "test")
+    })
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Const(i) => &i.attrs,
+        Item::Enum(i) => &i.attrs,
+        Item::ExternCrate(i) => &i.attrs,
+        Item::Fn(i) => &i.attrs,
+        Item::ForeignMod(i) => &i.attrs,
+        Item::Impl(i) => &i.attrs,
+        Item::Macro(i) => &i.attrs,
+        Item::Mod(i) => &i.attrs,
+        Item::Static(i) => &i.attrs,
+        Item::Struct(i) => &i.attrs,
+        Item::Trait(i) => &i.attrs,
+        Item::TraitAlias(i) => &i.attrs,
+        Item::Type(i) => &i.attrs,
+        Item::Union(i) => &i.attrs,
+        Item::Use(i) => &i.attrs,
+        _ => &[],
+    }
+}
+
+/// Text edits against the original source, applied in one pass.
+struct Edits<'s> {
+    source: &'s str,
+    /// Byte ranges to delete, widened to whole lines where possible.
+    removals: Vec<Range<usize>>,
+    /// Byte ranges to replace with new text.
+    replacements: Vec<(Range<usize>, String)>,
+}
+
+impl<'s> Edits<'s> {
+    fn new(source: &'s str) -> Self {
+        Self {
+            source,
+            removals: Vec::new(),
+            replacements: Vec::new(),
+        }
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        let range = self.whole_lines(range);
+        self.removals.push(range);
+    }
+
+    fn replace(&mut self, range: Range<usize>, text: String) {
+        self.replacements.push((range, text));
+    }
+
+    /// Widen `range` to full lines when nothing else shares those lines.
+    fn whole_lines(&self, range: Range<usize>) -> Range<usize> {
+        let src = self.source;
+        let start = src[..range.start].rfind('\n').map_or(0, |i| i + 1);
+        let end = src[range.end..]
+            .find('\n')
+            .map_or(src.len(), |i| range.end + i + 1);
+        if src[start..range.start].trim().is_empty() && src[range.end..end].trim().is_empty() {
+            start..end
+        } else {
+            range
+        }
+    }
+
+    /// Whether the line ending just before `at` is blank (or `at` is the file start).
+    fn blank_line_before(&self, at: usize) -> bool {
+        at == 0
+            || self.source[..at - 1]
+                .rsplit('\n')
+                .next()
+                .is_some_and(|line| line.trim().is_empty())
+    }
+
+    /// Length of the blank line starting at `at`, if there is one.
+    fn blank_line_at(&self, at: usize) -> Option<usize> {
+        let rest = &self.source[at..];
+        let line = rest.find('\n').map_or(rest, |i| &rest[..=i]);
+        (!line.is_empty() && line.trim().is_empty()).then_some(line.len())
+    }
+
+    fn apply(self) -> String {
+        // Merge adjacent removals so that e.g. a run of doc comment lines is
+        // treated as one block when tidying up blank lines.
+        let mut merged = merge_ranges(self.source, self.removals.clone());
+
+        // Avoid leaving two blank lines (or a trailing/leading blank line)
+        // where a block was removed.
+        let src = self.source;
+        for range in &mut merged {
+            let whole_lines = (range.start == 0 || src.as_bytes()[range.start - 1] == b'\n')
+                && (range.end == src.len() || src.as_bytes()[range.end - 1] == b'\n');
+            if !whole_lines || !self.blank_line_before(range.start) {
+                continue;
+            }
+            if let Some(len) = self.blank_line_at(range.end) {
+                range.end += len;
+            } else if range.end == src.len() && range.start > 0 {
+                // Removing the tail of the file: drop the blank line before it.
+                range.start -= 1;
+            }
+        }
+
+        let mut edits: Vec<(Range<usize>, String)> = merge_ranges(self.source, merged)
+            .into_iter()
+            .map(|r| (r, String::new()))
+            .collect();
+        for (range, text) in self.replacements {
+            // Pure insertions may sit on the boundary of another edit
+            let conflicts = edits.iter().any(|(r, _)| {
+                if range.is_empty() {
+                    r.start < range.start && range.start < r.end
+                } else {
+                    r.start < range.end && range.start < r.end
+                }
+            });
+            if !conflicts {
+                edits.push((range, text));
+            }
+        }
+        edits.sort_by_key(|(r, _)| (r.start, r.end));
+
+        let mut output = src.to_string();
+        for (range, text) in edits.into_iter().rev() {
+            output.replace_range(range, &text);
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(edit: impl FnOnce(&mut TransformConfig)) -> TransformConfig {
+        let mut config = TransformConfig::default();
+        edit(&mut config);
+        config
+    }
+
+    fn prefixed(prefix: &str) -> TransformConfig {
+        config(|c| c.type_prefix = Some(prefix.to_string()))
+    }
+
+    #[test]
+    fn test_no_transforms_returns_source_unchanged() {
+        // Not even parsed, so invalid Rust passes through
+        let source = "fn broken( {\n";
+        assert_eq!(apply(source, &TransformConfig::default()).unwrap(), source);
+        assert_eq!(apply(source, &prefixed("")).unwrap(), source);
+    }
+
+    #[test]
+    fn test_strip_tests() {
+        let source = "\
+use gpui::px;
+
+pub fn size() -> f32 {
+    // Keep this comment
+    16.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size() {
+        assert_eq!(size(), 16.0);
+    }
+}
+";
+        let output = apply(source, &config(|c| c.strip_tests = true)).unwrap();
+        assert_eq!(
+            output,
+            "\
+use gpui::px;
+
+pub fn size() -> f32 {
+    // Keep this comment
+    16.0
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_strip_tests_keeps_other_cfgs() {
+        let source = "\
+#[cfg(test)]
+fn only_in_tests() {}
+
+#[cfg(feature = \"test\")]
+fn feature_gated() {}
+
+#[cfg(not(test))]
+fn not_in_tests() {}
+";
+        let output = apply(source, &config(|c| c.strip_tests = true)).unwrap();
+        assert_eq!(
+            output,
+            "\
+#[cfg(feature = \"test\")]
+fn feature_gated() {}
+
+#[cfg(not(test))]
+fn not_in_tests() {}
+"
+        );
+    }
+
+    #[test]
+    fn test_strip_doc_comments() {
+        let source = "\
+//! Button component
+//!
+//! With an example.
+
+use gpui::px;
+
+/// A button.
+///
+/// Second paragraph.
+pub struct Button {
+    /// The label.
+    label: String, // trailing comment
+    // Plain comments stay
+    size: f32,
+}
+
+#[doc(hidden)]
+pub fn helper() {}
+";
+        let output = apply(source, &config(|c| c.strip_doc_comments = true)).unwrap();
+        assert_eq!(
+            output,
+            "\
+use gpui::px;
+
+pub struct Button {
+    label: String, // trailing comment
+    // Plain comments stay
+    size: f32,
+}
+
+#[doc(hidden)]
+pub fn helper() {}
+"
+        );
+    }
+
+    #[test]
+    fn test_type_prefix_renames_types_but_not_variants() {
+        let source = "\
+pub enum ButtonVariant {
+    Default,
+    Outline,
+}
+
+pub struct Button {
+    variant: ButtonVariant,
+}
+
+impl Button {
+    pub fn new() -> Self {
+        Self { variant: ButtonVariant::Default }
+    }
+
+    fn is_outline(&self) -> bool {
+        matches!(self.variant, ButtonVariant::Outline)
+    }
+}
+";
+        let output = apply(source, &prefixed("Ui")).unwrap();
+        assert_eq!(
+            output,
+            "\
+pub enum UiButtonVariant {
+    Default,
+    Outline,
+}
+
+pub struct UiButton {
+    variant: UiButtonVariant,
+}
+
+impl UiButton {
+    pub fn new() -> Self {
+        Self { variant: UiButtonVariant::Default }
+    }
+
+    fn is_outline(&self) -> bool {
+        matches!(self.variant, UiButtonVariant::Outline)
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_type_prefix_skips_variants_named_like_types() {
+        // `Separator` is also the separator component's type
+        let source = "\
+use super::dropdown_menu::{DropdownMenuEntry, DropdownMenuItem};
+use super::separator::Separator;
+
+fn entries(item: DropdownMenuItem) -> Vec<DropdownMenuEntry> {
+    vec![
+        DropdownMenuEntry::Item(item),
+        DropdownMenuEntry::Separator,
+    ]
+}
+
+fn divider() -> Separator {
+    Separator::new()
+}
+";
+        let output = apply(source, &prefixed("Ui")).unwrap();
+        assert_eq!(
+            output,
+            "\
+use super::dropdown_menu::{UiDropdownMenuEntry, UiDropdownMenuItem};
+use super::separator::UiSeparator;
+
+fn entries(item: UiDropdownMenuItem) -> Vec<UiDropdownMenuEntry> {
+    vec![
+        UiDropdownMenuEntry::Item(item),
+        UiDropdownMenuEntry::Separator,
+    ]
+}
+
+fn divider() -> UiSeparator {
+    UiSeparator::new()
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_type_prefix_leaves_other_names_alone() {
+        let source = "\
+use gpui::{Div, SharedString};
+
+#[derive(Debug, Clone)]
+struct Local {
+    label: SharedString,
+    // Button in a comment is not a reference
+    text: &'static str,
+}
+
+fn make() -> Local {
+    Local { label: \"Button\".into(), text: \"Card\" }
+}
+";
+        assert_eq!(apply(source, &prefixed("Ui")).unwrap(), source);
+    }
+
+    #[test]
+    fn test_type_prefix_keeps_associated_types_and_foreign_paths() {
+        // `Item` is also the item component's type
+        let source = "\
+use gpui::{AnyElement, ParentElement};
+use super::item::Item;
+use super::button::ButtonVariant;
+
+pub struct List {
+    items: Vec<Item>,
+}
+
+impl ParentElement for List {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        let _: Option<<Vec<Item> as IntoIterator>::Item> = None;
+        let _ = crate::components::button::ButtonVariant::Default;
+        let _ = other::Item::new();
+    }
+}
+
+impl Iterator for List {
+    type Item = Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        vec![Item::new()].pop()
+    }
+}
+
+fn boxed() -> Box<dyn Iterator<Item = Item>> {
+    todo!()
+}
+";
+        let output = apply(source, &prefixed("Ui")).unwrap();
+        assert_eq!(
+            output,
+            "\
+use gpui::{AnyElement, ParentElement};
+use super::item::UiItem;
+use super::button::UiButtonVariant;
+
+pub struct List {
+    items: Vec<UiItem>,
+}
+
+impl ParentElement for List {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        let _: Option<<Vec<UiItem> as IntoIterator>::Item> = None;
+        let _ = crate::components::button::UiButtonVariant::Default;
+        let _ = other::Item::new();
+    }
+}
+
+impl Iterator for List {
+    type Item = UiItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        vec![UiItem::new()].pop()
+    }
+}
+
+fn boxed() -> Box<dyn Iterator<Item = UiItem>> {
+    todo!()
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_shared_utils_replaces_exact_copies() {
+        let source = "\
+use gpui::prelude::*;
+use gpui::{div, px, Div, Hsla, Stateful};
+
+use crate::theme::{Radius, Theme};
+
+pub fn render(theme: &Theme) -> Stateful<Div> {
+    apply_radius(div().id(\"x\").w(px(10.0)), theme.radius)
+}
+
+/// Apply the theme's border radius to a styled element.
+fn apply_radius(el: Stateful<Div>, radius: Radius) -> Stateful<Div> {
+    match radius {
+        Radius::None => el.rounded_none(),
+        Radius::Sm => el.rounded_sm(),
+        Radius::Md => el.rounded_md(),
+        Radius::Lg => el.rounded_lg(),
+        Radius::Full => el.rounded_full(),
+    }
+}
+
+/// Create a transparent Hsla color.
+fn transparent() -> Hsla {
+    Hsla {
+        h: 0.0,
+        s: 0.0,
+        l: 0.0,
+        a: 0.0,
+    }
+}
+";
+        let output = apply(source, &config(|c| c.shared_utils = true)).unwrap();
+        // `Hsla` and `Radius` were only used by the removed helpers
+        assert_eq!(
+            output,
+            "\
+use gpui::prelude::*;
+use gpui::{div, px, Div, Stateful};
+
+use crate::theme::Theme;
+use super::utils::{apply_radius, transparent};
+
+pub fn render(theme: &Theme) -> Stateful<Div> {
+    apply_radius(div().id(\"x\").w(px(10.0)), theme.radius)
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_shared_utils_keeps_modified_copies() {
+        let source = "\
+use gpui::Hsla;
+
+fn transparent() -> Hsla {
+    Hsla {
+        h: 0.0,
+        s: 0.0,
+        l: 0.0,
+        a: 0.1,
+    }
+}
+
+pub fn with_opacity(color: Hsla, a: f32) -> Hsla {
+    Hsla { a, ..color }
+}
+";
+        // A changed body and a public function with the same name both stay
+        assert_eq!(
+            apply(source, &config(|c| c.shared_utils = true)).unwrap(),
+            source
+        );
+    }
+
+    #[test]
+    fn test_shared_utils_import_is_prefixed_with_types() {
+        let source = "\
+use gpui::Hsla;
+
+pub struct Button {
+    bg: Hsla,
+}
+
+impl Button {
+    pub fn new() -> Self {
+        Self { bg: with_opacity(transparent(), 1.0) }
+    }
+}
+
+fn transparent() -> Hsla {
+    Hsla {
+        h: 0.0,
+        s: 0.0,
+        l: 0.0,
+        a: 0.0,
+    }
+}
+
+fn with_opacity(color: Hsla, a: f32) -> Hsla {
+    Hsla { a, ..color }
+}
+";
+        let output = apply(
+            source,
+            &config(|c| {
+                c.shared_utils = true;
+                c.type_prefix = Some("Ui".to_string());
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            "\
+use gpui::Hsla;
+use super::utils::{transparent, with_opacity};
+
+pub struct UiButton {
+    bg: Hsla,
+}
+
+impl UiButton {
+    pub fn new() -> Self {
+        Self { bg: with_opacity(transparent(), 1.0) }
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_embedded_components_transform_cleanly() {
+        let all = config(|c| {
+            c.strip_tests = true;
+            c.strip_doc_comments = true;
+            c.type_prefix = Some("Ui".to_string());
+            c.shared_utils = true;
+        });
+        for name in shadcn_ui_registry::default_registry().component_names() {
+            let output = component_source(name, &all).unwrap().unwrap();
+            assert!(!output.contains("#[cfg(test)]"), "{} kept tests", name);
+            assert!(!output.contains("\n///"), "{} kept doc comments", name);
+            assert!(
+                !output.contains("\n\n\n"),
+                "{} has double blank lines",
+                name
+            );
+            assert!(
+                !output.contains("UiItem = "),
+                "{} renamed an associated type",
+                name
+            );
+        }
+    }
+
+    /// Install every component with a type prefix into a scratch project and
+    /// type-check it against gpui. Slow and needs gpui's build dependencies,
+    /// so CI runs it with `--ignored`.
+    #[test]
+    #[ignore = "builds gpui"]
+    fn test_prefixed_components_type_check() {
+        let project =
+            std::env::temp_dir().join(format!("shadcn-ui-prefixed-{}", std::process::id()));
+        let ui = project.join("src/components/ui");
+        std::fs::create_dir_all(&ui).unwrap();
+        let workspace = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        std::fs::write(
+            project.join("Cargo.toml"),
+            "[package]\nname = \"prefixed\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+             [workspace]\n\n[dependencies]\ngpui = \"0.2\"\n",
+        )
+        .unwrap();
+        // Build against the same gpui dependency versions as the workspace
+        std::fs::copy(workspace.join("Cargo.lock"), project.join("Cargo.lock")).unwrap();
+        std::fs::write(
+            project.join("src/lib.rs"),
+            "pub mod components;\npub mod theme;\n",
+        )
+        .unwrap();
+        std::fs::write(project.join("src/components/mod.rs"), "pub mod ui;\n").unwrap();
+        let theme = crate::config::Config::default().theme;
+        std::fs::write(
+            project.join("src/theme.rs"),
+            crate::commands::init::generate_theme_rs_from_config(&theme, &[]),
+        )
+        .unwrap();
+
+        let prefixed = config(|c| {
+            c.type_prefix = Some("Ui".to_string());
+            c.shared_utils = true;
+        });
+        let mut modules = vec![UTILS_MODULE.to_string()];
+        std::fs::write(
+            ui.join(format!("{UTILS_MODULE}.rs")),
+            apply(component_sources::utils_source(), &prefixed).unwrap(),
+        )
+        .unwrap();
+        for name in shadcn_ui_registry::default_registry().component_names() {
+            let source = component_source(name, &prefixed).unwrap().unwrap();
+            std::fs::write(ui.join(format!("{name}.rs")), source).unwrap();
+            modules.push(name.to_string());
+        }
+        let mod_rs: String = modules.iter().map(|m| format!("pub mod {m};\n")).collect();
+        std::fs::write(ui.join("mod.rs"), mod_rs).unwrap();
+
+        let output = std::process::Command::new(env!("CARGO"))
+            .args(["check", "--quiet", "--message-format", "short"])
+            .current_dir(&project)
+            .env("CARGO_TARGET_DIR", workspace.join("target/prefixed-check"))
+            .output()
+            .unwrap();
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            output.status.success(),
+            "prefixed components fail to build:\n{stderr}"
+        );
+        std::fs::remove_dir_all(&project).unwrap();
+    }
+
+    #[test]
+    fn test_format_use_wraps_long_groups() {
+        assert_eq!(format_use("gpui::{div, px}"), "use gpui::{div, px};");
+        // Same layout as rustfmt
+        let long = "gpui::{AnyElement, App, ClickEvent, ElementId, FontWeight, IntoElement, \
+                    KeyDownEvent, ParentElement, RenderOnce, SharedString}";
+        assert_eq!(
+            format_use(long),
+            "\
+use gpui::{
+    AnyElement, App, ClickEvent, ElementId, FontWeight, IntoElement, KeyDownEvent, ParentElement,
+    RenderOnce, SharedString,
+};"
+        );
+    }
+}
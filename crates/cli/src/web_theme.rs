@@ -0,0 +1,302 @@
+//! Conversion between custom themes and shadcn/ui web theme formats.
+//!
+//! [`parse_css`] reads the CSS variables written by the shadcn/ui theme
+//! editor: a `:root` block for light mode and a `.dark` block, optionally
+//! inside `@layer base`. Values may be bare HSL channels (`240 5.9% 10%`), as
+//! in shadcn/ui for Tailwind v3, or any color function, as in Tailwind v4.
+//! The export functions write the same variables back out, as CSS, as a
+//! Tailwind v4 stylesheet, or as a JSON theme file.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result, bail};
+use serde::{Serialize, Serializer};
+use shadcn_ui_theme::{ColorParseError, Hsl, PaletteColors, Radius};
+
+/// Colors and radius read from a web theme stylesheet.
+pub struct WebTheme {
+    pub light: BTreeMap<&'static str, Hsl>,
+    pub dark: BTreeMap<&'static str, Hsl>,
+    pub radius: Option<Radius>,
+    /// Variables that do not map to a color token, e.g. `--font-sans`.
+    pub ignored: Vec<String>,
+}
+
+/// Parse `:root` and `.dark` variable blocks.
+pub fn parse_css(source: &str) -> Result<WebTheme> {
+    let mut theme = WebTheme {
+        light: BTreeMap::new(),
+        dark: BTreeMap::new(),
+        radius: None,
+        ignored: Vec::new(),
+    };
+    for (selector, body) in blocks(&strip_comments(source))? {
+        let selector = selector.trim();
+        let (colors, mode) = if selector == ":root" {
+            (&mut theme.light, "light")
+        } else if selector == ".dark" || selector == ":root.dark" {
+            (&mut theme.dark, "dark")
+        } else {
+            continue;
+        };
+        for declaration in body.split(';') {
+            let Some((name, value)) = declaration.split_once(':') else {
+                continue;
+            };
+            let Some(name) = name.trim().strip_prefix("--") else {
+                continue;
+            };
+            let value = value.trim();
+            if name == "radius" {
+                theme.radius = Some(
+                    parse_radius(value)
+                        .with_context(|| format!("Invalid --radius in {}: {}", selector, value))?,
+                );
+                continue;
+            }
+            let Some(token) = token_for_variable(name) else {
+                if !theme.ignored.iter().any(|ignored| ignored == name) {
+                    theme.ignored.push(name.to_string());
+                }
+                continue;
+            };
+            colors.insert(
+                token,
+                parse_value(value).with_context(|| {
+                    format!("Invalid color for --{} in {} ({})", name, selector, mode)
+                })?,
+            );
+        }
+    }
+    if theme.light.is_empty() && theme.dark.is_empty() {
+        bail!("No color variables found in a :root or .dark block");
+    }
+    Ok(theme)
+}
+
+/// Remove `/* ... */` comments.
+fn strip_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = rest[start + 2..]
+            .split_once("*/")
+            .map_or("", |(_, after)| after);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Split a stylesheet into `(selector, body)` rule blocks, descending into
+/// at-rules such as `@layer base { ... }`.
+fn blocks(source: &str) -> Result<Vec<(&str, &str)>> {
+    let mut found = Vec::new();
+    let mut rest = source;
+    while let Some(open) = rest.find('{') {
+        let selector = rest[..open].rsplit(['}', ';']).next().unwrap_or("").trim();
+        let mut depth = 0;
+        let close = rest[open..]
+            .char_indices()
+            .find_map(|(i, c)| {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => {}
+                }
+                (depth == 0).then_some(open + i)
+            })
+            .with_context(|| format!("Unclosed block after '{}'", selector))?;
+        let body = &rest[open + 1..close];
+        if selector.starts_with('@') {
+            found.extend(blocks(body)?);
+        } else {
+            found.push((selector, body));
+        }
+        rest = &rest[close + 1..];
+    }
+    Ok(found)
+}
+
+/// The color token for a CSS variable name, e.g. `card-foreground`.
+fn token_for_variable(name: &str) -> Option<&'static str> {
+    // shadcn/ui for Tailwind v3 calls the sidebar background `--sidebar-background`
+    let name = if name == "sidebar-background" {
+        "sidebar".to_string()
+    } else {
+        name.replace('-', "_")
+    };
+    PaletteColors::TOKENS
+        .iter()
+        .copied()
+        .find(|token| *token == name)
+}
+
+/// The CSS variable name for a color token, as written by [`to_css`].
+pub fn variable_for_token(token: &str) -> String {
+    match token {
+        "sidebar" => "sidebar-background".to_string(),
+        _ => token.replace('_', "-"),
+    }
+}
+
+/// Parse a color value; bare channels such as `240 5.9% 10%` are HSL.
+fn parse_value(value: &str) -> Result<Hsl> {
+    let value = value.trim();
+    if value.starts_with('#') || value.contains('(') {
+        return Ok(value.parse()?);
+    }
+    format!("hsl({})", value)
+        .parse()
+        .map_err(|_| ColorParseError(value.to_string()).into())
+}
+
+/// Map a CSS length to the nearest radius preset.
+fn parse_radius(value: &str) -> Result<Radius> {
+    let px = if let Some(rem) = value.strip_suffix("rem") {
+        rem.trim().parse::<f32>()? * 16.0
+    } else if let Some(px) = value.strip_suffix("px") {
+        px.trim().parse::<f32>()?
+    } else if value == "0" {
+        0.0
+    } else {
+        bail!("expected a length in rem or px");
+    };
+    if px >= 100.0 {
+        return Ok(Radius::Full);
+    }
+    let nearest = [Radius::None, Radius::Sm, Radius::Md, Radius::Lg]
+        .into_iter()
+        .min_by(|a, b| (a.to_px() - px).abs().total_cmp(&(b.to_px() - px).abs()))
+        .unwrap_or_default();
+    Ok(nearest)
+}
+
+/// The radius as a CSS length.
+fn radius_css(radius: Radius) -> String {
+    match radius {
+        Radius::None => "0rem".to_string(),
+        Radius::Full => "9999px".to_string(),
+        _ => format!("{}rem", radius.to_px() / 16.0),
+    }
+}
+
+/// Round away float noise such as 27.395996 from converted colors.
+fn round(v: f32) -> f32 {
+    (v * 1000.0).round() / 1000.0
+}
+
+/// Bare HSL channels, e.g. `240 5.9% 10%` or `0 0% 0% / 0.8`.
+fn channels(c: Hsl) -> String {
+    let hsl = format!("{} {}% {}%", round(c.h), round(c.s), round(c.l));
+    if c.a < 1.0 {
+        format!("{} / {}", hsl, round(c.a))
+    } else {
+        hsl
+    }
+}
+
+/// A CSS Color 4 `hsl()` color, e.g. `hsl(240 5.9% 10%)`.
+pub fn css_color(c: Hsl) -> String {
+    format!("hsl({})", channels(c))
+}
+
+fn variables(palette: &PaletteColors, value: impl Fn(Hsl) -> String) -> String {
+    palette
+        .tokens()
+        .into_iter()
+        .map(|(token, color)| format!("    --{}: {};\n", variable_for_token(token), value(color)))
+        .collect()
+}
+
+/// CSS variables in the shadcn/ui (Tailwind v3) format that [`parse_css`]
+/// reads.
+pub fn to_css(light: &PaletteColors, dark: &PaletteColors, radius: Radius) -> String {
+    format!(
+        "@layer base {{\n  :root {{\n{}    --radius: {};\n  }}\n\n  .dark {{\n{}  }}\n}}\n",
+        variables(light, channels),
+        radius_css(radius),
+        variables(dark, channels),
+    )
+}
+
+/// A Tailwind v4 stylesheet: the variables as `hsl()` colors, plus an
+/// `@theme inline` block exposing them as `bg-primary`, `rounded-lg`, etc.
+pub fn to_tailwind(light: &PaletteColors, dark: &PaletteColors, radius: Radius) -> String {
+    let theme: String = PaletteColors::TOKENS
+        .iter()
+        .map(|token| {
+            let name = token.replace('_', "-");
+            format!(
+                "  --color-{}: var(--{});\n",
+                name,
+                variable_for_token(token)
+            )
+        })
+        .collect();
+    format!(
+        r#"@import "tailwindcss";
+
+@custom-variant dark (&:is(.dark *));
+
+:root {{
+{light}    --radius: {radius};
+}}
+
+.dark {{
+{dark}}}
+
+@theme inline {{
+{theme}  --radius-sm: calc(var(--radius) - 4px);
+  --radius-md: calc(var(--radius) - 2px);
+  --radius-lg: var(--radius);
+  --radius-xl: calc(var(--radius) + 4px);
+}}
+"#,
+        light = variables(light, css_color).replace("    --", "  --"),
+        dark = variables(dark, css_color).replace("    --", "  --"),
+        radius = radius_css(radius),
+    )
+}
+
+/// A JSON theme file, as read by `ThemeFile::from_json` in the theme crate.
+pub fn to_json(
+    name: &str,
+    light: &PaletteColors,
+    dark: &PaletteColors,
+    radius: Radius,
+) -> Result<String> {
+    #[derive(Serialize)]
+    struct JsonTheme<'a> {
+        meta: JsonMeta<'a>,
+        light: JsonPalette<'a>,
+        dark: JsonPalette<'a>,
+    }
+
+    #[derive(Serialize)]
+    struct JsonMeta<'a> {
+        name: &'a str,
+        radius: Radius,
+    }
+
+    /// Serializes tokens in declaration order rather than sorted.
+    struct JsonPalette<'a>(&'a PaletteColors);
+
+    impl Serialize for JsonPalette<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(
+                self.0
+                    .tokens()
+                    .into_iter()
+                    .map(|(token, color)| (token, css_color(color))),
+            )
+        }
+    }
+
+    let file = JsonTheme {
+        meta: JsonMeta { name, radius },
+        light: JsonPalette(light),
+        dark: JsonPalette(dark),
+    };
+    Ok(serde_json::to_string_pretty(&file)? + "\n")
+}
@@ -43,11 +43,34 @@ impl ComponentCategory {
     }
 }
 
+/// Prefix that marks a bundle name on the command line, as in `add @forms`.
+pub const BUNDLE_PREFIX: char = '@';
+
+/// A named group of components that are commonly installed together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleMeta {
+    pub name: String,
+    pub description: String,
+    pub components: Vec<String>,
+}
+
+/// Release notes for one version of a component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    pub component: String,
+    pub version: String,
+    pub changes: Vec<String>,
+}
+
 /// Registry containing all available components.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Registry {
     pub version: String,
     pub components: Vec<ComponentMeta>,
+    #[serde(default)]
+    pub bundles: Vec<BundleMeta>,
+    #[serde(default)]
+    pub changelog: Vec<ChangelogEntry>,
 }
 
 impl Registry {
@@ -61,6 +84,65 @@ impl Registry {
         self.components.iter().find(|c| c.name == name)
     }
 
+    /// Get all bundle names (without the `@` prefix).
+    pub fn bundle_names(&self) -> Vec<&str> {
+        self.bundles.iter().map(|b| b.name.as_str()).collect()
+    }
+
+    /// Find a bundle by name. Accepts the name with or without the `@` prefix.
+    pub fn find_bundle(&self, name: &str) -> Option<&BundleMeta> {
+        let name = name.strip_prefix(BUNDLE_PREFIX).unwrap_or(name);
+        self.bundles.iter().find(|b| b.name == name)
+    }
+
+    /// Expand `@bundle` entries in a list of names into their components.
+    ///
+    /// Plain component names are passed through unchanged and duplicates are
+    /// dropped, keeping the first occurrence. A bundle may list other bundles
+    /// as `@name`; a bundle that includes itself is expanded once. Returns the
+    /// offending name if a bundle is unknown.
+    pub fn expand_bundles<'a>(&'a self, names: &'a [String]) -> Result<Vec<&'a str>, &'a str> {
+        let mut expanded: Vec<&str> = Vec::new();
+        let mut seen: Vec<&str> = Vec::new();
+        for name in names {
+            self.expand_name(name, &mut expanded, &mut seen)?;
+        }
+        Ok(expanded)
+    }
+
+    /// Add the bundles of `other`, e.g. a private registry index, replacing
+    /// bundles with the same name.
+    pub fn merge_bundles(&mut self, other: Registry) {
+        for bundle in other.bundles {
+            self.bundles.retain(|existing| existing.name != bundle.name);
+            self.bundles.push(bundle);
+        }
+    }
+
+    fn expand_name<'a>(
+        &'a self,
+        name: &'a str,
+        expanded: &mut Vec<&'a str>,
+        seen: &mut Vec<&'a str>,
+    ) -> Result<(), &'a str> {
+        if !name.starts_with(BUNDLE_PREFIX) {
+            if !expanded.contains(&name) {
+                expanded.push(name);
+            }
+            return Ok(());
+        }
+
+        let bundle = self.find_bundle(name).ok_or(name)?;
+        if seen.contains(&bundle.name.as_str()) {
+            return Ok(());
+        }
+        seen.push(&bundle.name);
+        for member in &bundle.components {
+            self.expand_name(member, expanded, seen)?;
+        }
+        Ok(())
+    }
+
     /// Get components by category.
     pub fn by_category(&self, category: ComponentCategory) -> Vec<&ComponentMeta> {
         self.components
@@ -69,6 +151,26 @@ impl Registry {
             .collect()
     }
 
+    /// Changelog entries for a component newer than `since`, oldest first.
+    pub fn changes_since(&self, component: &str, since: &str) -> Vec<&ChangelogEntry> {
+        let mut entries: Vec<&ChangelogEntry> = self
+            .changelog
+            .iter()
+            .filter(|e| e.component == component && compare_versions(&e.version, since).is_gt())
+            .collect();
+        entries.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        entries
+    }
+
+    /// Parse a registry from its JSON representation.
+    ///
+    /// This is the format of the `registry.json` index that `upgrade-check`,
+    /// and `add` and `list` for bundles, fetch from the configured registry
+    /// URL; `bundles` and `changelog` may be omitted.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
     /// Resolve all transitive dependencies for a set of component names.
     ///
     /// Returns the original names plus any dependencies, in installation order
@@ -108,11 +210,11 @@ impl Registry {
 /// Default registry with all components.
 pub fn default_registry() -> Registry {
     Registry {
-        version: "0.4.0".to_string(),
+        version: "0.9.0".to_string(),
         components: vec![
             ComponentMeta {
                 name: "button".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A button component with multiple variants and sizes".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["button.rs".to_string()],
@@ -121,7 +223,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "input".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A text input component".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["input.rs".to_string()],
@@ -130,7 +232,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "label".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A label component for form fields".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["label.rs".to_string()],
@@ -139,17 +241,17 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "checkbox".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "A checkbox input with checked/unchecked/indeterminate states"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["checkbox.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["icon".to_string()],
                 category: ComponentCategory::Input,
             },
             ComponentMeta {
                 name: "radio".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A radio group component for single selection".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["radio.rs".to_string()],
@@ -158,7 +260,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "switch".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A toggle switch component".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["switch.rs".to_string()],
@@ -167,7 +269,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "slider".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A slider input for selecting a value from a range".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["slider.rs".to_string()],
@@ -176,16 +278,16 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "select".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "A select dropdown for choosing from a list of options".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["select.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["icon".to_string()],
                 category: ComponentCategory::Input,
             },
             ComponentMeta {
                 name: "toggle".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A toggle button that can be on or off".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["toggle.rs".to_string()],
@@ -194,7 +296,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "toggle_group".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A group of toggle buttons with single or multiple selection"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -204,7 +306,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "card".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A card container with header, content, and footer sections"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -214,26 +316,26 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "dialog".to_string(),
-                version: "0.1.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "A modal dialog overlay with backdrop".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["dialog.rs".to_string()],
-                dependencies: vec!["button".to_string()],
+                dependencies: vec!["button".to_string(), "icon".to_string()],
                 category: ComponentCategory::Feedback,
             },
             // Phase 2
             ComponentMeta {
                 name: "alert".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "A static alert box with icon, title, and description".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["alert.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["icon".to_string()],
                 category: ComponentCategory::Feedback,
             },
             ComponentMeta {
                 name: "alert_dialog".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A modal confirmation dialog with action and cancel buttons"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -243,7 +345,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "tooltip".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A hover-triggered overlay with text content".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["tooltip.rs".to_string()],
@@ -252,7 +354,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "popover".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A click-triggered overlay with arbitrary content".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["popover.rs".to_string()],
@@ -261,7 +363,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "hover_card".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A hover-triggered card overlay with rich content".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["hover_card.rs".to_string()],
@@ -270,7 +372,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "dropdown_menu".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A click-triggered menu with items, separators, and labels"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -280,7 +382,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "sheet".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A slide-in overlay panel from screen edge".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["sheet.rs".to_string()],
@@ -289,7 +391,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "drawer".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A bottom sheet variant with drag handle".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["drawer.rs".to_string()],
@@ -298,7 +400,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "toast".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A temporary notification with auto-dismiss support".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["toast.rs".to_string()],
@@ -307,7 +409,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "sonner".to_string(),
-                version: "0.2.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A stacked toast notification system".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["sonner.rs".to_string()],
@@ -317,7 +419,7 @@ pub fn default_registry() -> Registry {
             // Phase 3
             ComponentMeta {
                 name: "badge".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "An inline status label with multiple variants".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["badge.rs".to_string()],
@@ -326,7 +428,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "avatar".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A circular avatar with fallback initials".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["avatar.rs".to_string()],
@@ -335,7 +437,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "separator".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A horizontal or vertical dividing line".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["separator.rs".to_string()],
@@ -344,7 +446,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "skeleton".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A static loading placeholder block".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["skeleton.rs".to_string()],
@@ -353,7 +455,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "progress".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "A horizontal progress bar with track and fill".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["progress.rs".to_string()],
@@ -362,7 +464,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "kbd".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "A keyboard shortcut display label".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["kbd.rs".to_string()],
@@ -371,7 +473,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "typography".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "Text styling presets: headings, paragraphs, blockquotes, code"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -381,7 +483,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "table".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A data table with header, body, rows, and cells".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["table.rs".to_string()],
@@ -399,7 +501,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "textarea".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A multi-line text display component".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["textarea.rs".to_string()],
@@ -408,7 +510,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "empty".to_string(),
-                version: "0.3.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "An empty state placeholder with title, description, and action"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -419,17 +521,17 @@ pub fn default_registry() -> Registry {
             // Phase 4
             ComponentMeta {
                 name: "accordion".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "Expandable/collapsible content sections with single or multiple mode"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["accordion.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["icon".to_string()],
                 category: ComponentCategory::Layout,
             },
             ComponentMeta {
                 name: "breadcrumb".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "Navigation path indicator with clickable items and separators"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -457,7 +559,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "context_menu".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A right-click triggered context menu".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["context_menu.rs".to_string()],
@@ -466,7 +568,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "field".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "Form field wrapper with label, input, description, and error"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -474,9 +576,18 @@ pub fn default_registry() -> Registry {
                 dependencies: vec![],
                 category: ComponentCategory::Input,
             },
+            ComponentMeta {
+                name: "icon".to_string(),
+                version: "0.9.0".to_string(),
+                description: "An SVG icon from the Lucide set".to_string(),
+                gpui_version: ">=0.2.0".to_string(),
+                files: vec!["icon.rs".to_string()],
+                dependencies: vec![],
+                category: ComponentCategory::Display,
+            },
             ComponentMeta {
                 name: "item".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "A generic list item with icon, label, and action".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["item.rs".to_string()],
@@ -485,7 +596,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "menubar".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "Application menu bar with dropdown menus".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["menubar.rs".to_string()],
@@ -494,7 +605,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "navigation_menu".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "Multi-level navigation with wide dropdown panels".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["navigation_menu.rs".to_string()],
@@ -503,17 +614,17 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "pagination".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.9.0".to_string(),
                 description: "Page navigation controls with previous/next and page numbers"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["pagination.rs".to_string()],
-                dependencies: vec![],
+                dependencies: vec!["icon".to_string()],
                 category: ComponentCategory::Navigation,
             },
             ComponentMeta {
                 name: "sidebar".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.7.0".to_string(),
                 description: "Collapsible side navigation panel".to_string(),
                 gpui_version: ">=0.2.0".to_string(),
                 files: vec!["sidebar.rs".to_string()],
@@ -522,7 +633,7 @@ pub fn default_registry() -> Registry {
             },
             ComponentMeta {
                 name: "tabs".to_string(),
-                version: "0.4.0".to_string(),
+                version: "0.8.0".to_string(),
                 description: "Tabbed content panels with trigger list and content areas"
                     .to_string(),
                 gpui_version: ">=0.2.0".to_string(),
@@ -531,5 +642,868 @@ pub fn default_registry() -> Registry {
                 category: ComponentCategory::Navigation,
             },
         ],
+        bundles: default_bundles(),
+        changelog: default_changelog(),
+    }
+}
+
+/// Compare two dotted version strings numerically, e.g. `0.10.0 > 0.9.1`.
+///
+/// Missing parts count as zero and non-numeric parts compare as text. A
+/// pre-release such as `1.0.0-beta.2` sorts before its release, and build
+/// metadata after `+` is ignored.
+pub fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let split = |version: &str| {
+        let version = version.trim_start_matches('v');
+        let version = version
+            .split_once('+')
+            .map_or(version, |(version, _)| version);
+        match version.split_once('-') {
+            Some((release, pre)) => (release.to_string(), Some(pre.to_string())),
+            None => (version.to_string(), None),
+        }
+    };
+    let ((a_release, a_pre), (b_release, b_pre)) = (split(a), split(b));
+    compare_dotted(&a_release, &b_release).then_with(|| match (a_pre, b_pre) {
+        (None, None) => std::cmp::Ordering::Equal,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (Some(a), Some(b)) => compare_dotted(&a, &b),
+    })
+}
+
+fn compare_dotted(a: &str, b: &str) -> std::cmp::Ordering {
+    let mut a_parts = a.split('.');
+    let mut b_parts = b.split('.');
+    loop {
+        let ordering = match (a_parts.next(), b_parts.next()) {
+            (None, None) => return std::cmp::Ordering::Equal,
+            (a, b) => {
+                let (a, b) = (a.unwrap_or("0"), b.unwrap_or("0"));
+                match (a.parse::<u64>(), b.parse::<u64>()) {
+                    (Ok(a), Ok(b)) => a.cmp(&b),
+                    _ => a.cmp(b),
+                }
+            }
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+/// Bundles shipped with the default registry.
+fn default_bundles() -> Vec<BundleMeta> {
+    fn bundle(name: &str, description: &str, components: &[&str]) -> BundleMeta {
+        BundleMeta {
+            name: name.to_string(),
+            description: description.to_string(),
+            components: components.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    vec![
+        bundle(
+            "forms",
+            "Form controls with labels and field wrappers",
+            &[
+                "field", "input", "label", "checkbox", "radio", "select", "switch", "textarea",
+            ],
+        ),
+        bundle(
+            "overlays",
+            "Dialogs, sheets, popovers, tooltips and menus",
+            &[
+                "dialog",
+                "alert_dialog",
+                "sheet",
+                "drawer",
+                "popover",
+                "hover_card",
+                "tooltip",
+                "dropdown_menu",
+                "context_menu",
+            ],
+        ),
+        bundle(
+            "feedback",
+            "Alerts, notifications and loading states",
+            &["alert", "toast", "sonner", "progress", "skeleton", "empty"],
+        ),
+        bundle(
+            "navigation",
+            "Tabs, breadcrumbs, pagination, menus and sidebar",
+            &[
+                "tabs",
+                "breadcrumb",
+                "pagination",
+                "menubar",
+                "navigation_menu",
+                "sidebar",
+            ],
+        ),
+    ]
+}
+
+/// Changelog entries shipped with the default registry.
+///
+/// Entries from 0.5.0 on describe changes that read new theme fields, so
+/// they also need a `theme.rs` generated by the same CLI version.
+fn default_changelog() -> Vec<ChangelogEntry> {
+    fn entry(component: &str, version: &str, changes: &[&str]) -> ChangelogEntry {
+        ChangelogEntry {
+            component: component.to_string(),
+            version: version.to_string(),
+            changes: changes.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    vec![
+        entry(
+            "dropdown_menu",
+            "0.4.0",
+            &[
+                "Public accessor methods on DropdownMenuItem (value, label, is_disabled, is_destructive) for reuse by ContextMenu and Menubar.",
+            ],
+        ),
+        // 0.5.0: density tokens
+        entry(
+            "button",
+            "0.5.0",
+            &[
+                "Height comes from `theme.controls` (`xs` to `lg` by size), horizontal padding from `theme.spacing` and text size from `theme.typography`, so buttons follow the theme's density.",
+            ],
+        ),
+        entry(
+            "input",
+            "0.5.0",
+            &[
+                "Height is `theme.controls.md`, horizontal padding `theme.spacing.md` and text size `theme.typography.sm` instead of fixed pixels.",
+            ],
+        ),
+        entry(
+            "textarea",
+            "0.5.0",
+            &["Horizontal padding is `theme.spacing.md` and text size `theme.typography.sm`."],
+        ),
+        entry(
+            "select",
+            "0.5.0",
+            &[
+                "The trigger is `theme.controls.md` tall with `theme.spacing.md` padding and `theme.typography.sm` text.",
+            ],
+        ),
+        entry(
+            "toggle",
+            "0.5.0",
+            &[
+                "Height and padding for each size come from `theme.controls` and `theme.spacing`, and text size from `theme.typography.sm`.",
+            ],
+        ),
+        entry(
+            "toggle_group",
+            "0.5.0",
+            &[
+                "Items take their height and padding for the group's size from `theme.controls` and `theme.spacing`.",
+            ],
+        ),
+        entry(
+            "pagination",
+            "0.5.0",
+            &[
+                "Page buttons are `theme.controls.md` square with `theme.typography.sm` text, spaced by `theme.spacing.xs`.",
+            ],
+        ),
+        // 0.5.0: elevation shadows
+        entry(
+            "alert_dialog",
+            "0.5.0",
+            &["The content panel uses `theme.shadows.lg` instead of a fixed gpui shadow."],
+        ),
+        entry(
+            "card",
+            "0.5.0",
+            &["Uses `theme.shadows.sm` instead of a fixed gpui shadow."],
+        ),
+        entry(
+            "context_menu",
+            "0.5.0",
+            &["The menu panel uses `theme.shadows.lg`."],
+        ),
+        entry(
+            "dialog",
+            "0.5.0",
+            &["DialogContent uses `theme.shadows.lg`."],
+        ),
+        entry(
+            "drawer",
+            "0.5.0",
+            &["The drawer panel uses `theme.shadows.lg`."],
+        ),
+        entry(
+            "hover_card",
+            "0.5.0",
+            &["The card uses `theme.shadows.md`."],
+        ),
+        entry("menubar", "0.5.0", &["Open menus use `theme.shadows.lg`."]),
+        entry(
+            "navigation_menu",
+            "0.5.0",
+            &["Dropdown panels use `theme.shadows.lg`."],
+        ),
+        entry(
+            "popover",
+            "0.5.0",
+            &["The popover panel uses `theme.shadows.lg`."],
+        ),
+        entry(
+            "sheet",
+            "0.5.0",
+            &["The sheet panel uses `theme.shadows.lg`."],
+        ),
+        entry(
+            "sonner",
+            "0.5.0",
+            &["Stacked toasts use `theme.shadows.md`."],
+        ),
+        entry(
+            "tabs",
+            "0.5.0",
+            &["The active trigger uses `theme.shadows.sm`."],
+        ),
+        entry("toast", "0.5.0", &["Uses `theme.shadows.lg`."]),
+        entry("tooltip", "0.5.0", &["Uses `theme.shadows.md`."]),
+        // 0.6.0: component slots
+        entry(
+            "alert",
+            "0.6.0",
+            &[
+                "Colors can be overridden per variant with the `alert.<variant>.bg`, `.fg` and `.border` slots, e.g. `alert.destructive.border`.",
+            ],
+        ),
+        entry(
+            "badge",
+            "0.6.0",
+            &[
+                "Colors can be overridden per variant with the `badge.<variant>.bg`, `.fg` and `.border` slots, e.g. `badge.outline.border`.",
+            ],
+        ),
+        entry(
+            "button",
+            "0.6.0",
+            &[
+                "Colors can be overridden per variant with the `button.<variant>.bg`, `.fg`, `.border`, `.hover_bg`, `.hover_fg` and `.active_bg` slots, e.g. `button.destructive.bg`.",
+            ],
+        ),
+        entry(
+            "card",
+            "0.6.0",
+            &["Colors can be overridden with the `card.bg`, `card.fg` and `card.border` slots."],
+        ),
+        entry(
+            "input",
+            "0.6.0",
+            &[
+                "Colors can be overridden with the `input.bg`, `input.fg`, `input.border`, `input.hover_border` and `input.placeholder` slots.",
+            ],
+        ),
+        // 0.7.0: ThemeScope
+        entry(
+            "accordion",
+            "0.7.0",
+            &["AccordionItem and AccordionTrigger follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "alert",
+            "0.7.0",
+            &["Alert follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "alert_dialog",
+            "0.7.0",
+            &["AlertDialogContent and AlertDialogDescription follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "avatar",
+            "0.7.0",
+            &["Avatar follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "badge",
+            "0.7.0",
+            &["Badge follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "breadcrumb",
+            "0.7.0",
+            &["BreadcrumbItem and BreadcrumbSeparator follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "button",
+            "0.7.0",
+            &["Button follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "card",
+            "0.7.0",
+            &["Card and CardDescription follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "checkbox",
+            "0.7.0",
+            &["Checkbox follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "context_menu",
+            "0.7.0",
+            &["ContextMenu follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "dialog",
+            "0.7.0",
+            &["DialogContent and DialogDescription follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "drawer",
+            "0.7.0",
+            &["Drawer, DrawerContent and DrawerDescription follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "dropdown_menu",
+            "0.7.0",
+            &["DropdownMenu follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "empty",
+            "0.7.0",
+            &["Empty follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "field",
+            "0.7.0",
+            &["Field follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "hover_card",
+            "0.7.0",
+            &["HoverCard follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "input",
+            "0.7.0",
+            &["Input follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "item",
+            "0.7.0",
+            &["Item follows the enclosing `ThemeScope`."],
+        ),
+        entry("kbd", "0.7.0", &["Kbd follows the enclosing `ThemeScope`."]),
+        entry(
+            "label",
+            "0.7.0",
+            &["Label follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "menubar",
+            "0.7.0",
+            &["Menubar, MenubarMenu and MenubarSeparator follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "navigation_menu",
+            "0.7.0",
+            &["NavigationMenuItem and NavigationMenuLink follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "pagination",
+            "0.7.0",
+            &["Pagination follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "popover",
+            "0.7.0",
+            &["Popover follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "progress",
+            "0.7.0",
+            &["Progress follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "radio",
+            "0.7.0",
+            &["RadioGroup follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "select",
+            "0.7.0",
+            &["Select follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "separator",
+            "0.7.0",
+            &["Separator follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "sheet",
+            "0.7.0",
+            &["Sheet, SheetContent and SheetDescription follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "sidebar",
+            "0.7.0",
+            &["Sidebar, SidebarHeader and SidebarFooter follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "skeleton",
+            "0.7.0",
+            &["Skeleton follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "slider",
+            "0.7.0",
+            &["Slider follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "sonner",
+            "0.7.0",
+            &["Sonner follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "switch",
+            "0.7.0",
+            &["Switch follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "table",
+            "0.7.0",
+            &["TableRow, TableHead and TableCaption follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "tabs",
+            "0.7.0",
+            &["TabsList and TabsTrigger follow the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "textarea",
+            "0.7.0",
+            &["Textarea follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "toast",
+            "0.7.0",
+            &["Toast follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "toggle",
+            "0.7.0",
+            &["Toggle follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "toggle_group",
+            "0.7.0",
+            &["ToggleGroupItem follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "tooltip",
+            "0.7.0",
+            &["Tooltip follows the enclosing `ThemeScope`."],
+        ),
+        entry(
+            "typography",
+            "0.7.0",
+            &[
+                "Headings, Paragraph, Blockquote, InlineCode and Lead follow the enclosing `ThemeScope`.",
+            ],
+        ),
+        // 0.8.0: interaction states
+        entry(
+            "button",
+            "0.8.0",
+            &[
+                "Hover and pressed backgrounds use the `primary`, `secondary`, `destructive` and `accent` state opacities. Disabled buttons use `states.disabled_opacity`, and outline, ghost and link labels `disabled_foreground`.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "checkbox",
+            "0.8.0",
+            &[
+                "Pressed checkboxes use the `primary` (checked) or `accent` (unchecked) pressed opacity, and disabled ones `states.disabled_opacity`.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "context_menu",
+            "0.8.0",
+            &[
+                "Items highlight with the `accent` hover and pressed opacities; disabled items use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "dropdown_menu",
+            "0.8.0",
+            &[
+                "Items highlight with the `accent` hover and pressed opacities; disabled items use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "input",
+            "0.8.0",
+            &[
+                "Disabled inputs use `states.disabled_opacity` and `disabled_foreground` text.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "item",
+            "0.8.0",
+            &[
+                "Hover and pressed backgrounds use the `accent` state opacities; disabled items use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "menubar",
+            "0.8.0",
+            &[
+                "Menu triggers and items highlight with the `accent` state opacities; disabled items use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "navigation_menu",
+            "0.8.0",
+            &["Items highlight with the `accent` hover and pressed opacities."],
+        ),
+        entry(
+            "pagination",
+            "0.8.0",
+            &[
+                "Page buttons highlight with the `accent` state opacities; disabled previous and next buttons use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "radio",
+            "0.8.0",
+            &["Disabled options use `states.disabled_opacity` and `disabled_foreground` labels."],
+        ),
+        entry(
+            "select",
+            "0.8.0",
+            &[
+                "Options highlight with the `accent` state opacities and the open trigger draws the focus ring; disabled selects use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "slider",
+            "0.8.0",
+            &["Disabled sliders use `states.disabled_opacity`."],
+        ),
+        entry(
+            "switch",
+            "0.8.0",
+            &[
+                "The track uses the `primary` (on) or `accent` (off) hover and pressed opacities; disabled switches use `states.disabled_opacity`.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "table",
+            "0.8.0",
+            &["Rows highlight with the `accent` hover opacity."],
+        ),
+        entry(
+            "tabs",
+            "0.8.0",
+            &[
+                "Triggers highlight with the `accent` state opacities; disabled triggers use `disabled_foreground`.",
+            ],
+        ),
+        entry(
+            "textarea",
+            "0.8.0",
+            &[
+                "Disabled textareas use `states.disabled_opacity` and `disabled_foreground` text.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "toggle",
+            "0.8.0",
+            &[
+                "Hover and pressed backgrounds use the `accent` state opacities; disabled toggles use `disabled_foreground`.",
+                "New `focused(bool)` builder draws the focus ring.",
+            ],
+        ),
+        entry(
+            "toggle_group",
+            "0.8.0",
+            &[
+                "Items use the `accent` hover and pressed opacities; disabled items use `disabled_foreground`.",
+            ],
+        ),
+        // 0.9.0: icons and fonts
+        entry(
+            "icon",
+            "0.9.0",
+            &[
+                "New component drawing Lucide icons from `icons/<name>.svg` in the app's asset source, which `shadcn_ui_theme::Assets` serves.",
+            ],
+        ),
+        entry(
+            "accordion",
+            "0.9.0",
+            &[
+                "The trigger chevron is a `ChevronDown`/`ChevronUp` icon instead of a text triangle.",
+            ],
+        ),
+        entry(
+            "alert",
+            "0.9.0",
+            &["New `icon(IconName)` builder shows an icon beside the content."],
+        ),
+        entry(
+            "checkbox",
+            "0.9.0",
+            &["The check mark is a `Check` icon instead of a `✓` glyph."],
+        ),
+        entry(
+            "dialog",
+            "0.9.0",
+            &["DialogClose without children shows an `X` icon."],
+        ),
+        entry(
+            "pagination",
+            "0.9.0",
+            &[
+                "Previous, next and ellipsis use `ChevronLeft`, `ChevronRight` and `Ellipsis` icons instead of arrow and `…` glyphs.",
+            ],
+        ),
+        entry(
+            "select",
+            "0.9.0",
+            &["The trigger chevron is an icon, and the selected option shows a `Check` icon."],
+        ),
+        entry(
+            "kbd",
+            "0.9.0",
+            &["Uses `theme.typography.mono_font_family`."],
+        ),
+        entry(
+            "typography",
+            "0.9.0",
+            &["InlineCode uses `theme.typography.mono_font_family`."],
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn registry_with_bundles(bundles: &[(&str, &[&str])]) -> Registry {
+        Registry {
+            bundles: bundles
+                .iter()
+                .map(|(name, components)| BundleMeta {
+                    name: name.to_string(),
+                    description: String::new(),
+                    components: names(components),
+                })
+                .collect(),
+            ..default_registry()
+        }
+    }
+
+    fn changelog(entries: &[(&str, &str)]) -> Registry {
+        Registry {
+            changelog: entries
+                .iter()
+                .map(|(component, version)| ChangelogEntry {
+                    component: component.to_string(),
+                    version: version.to_string(),
+                    changes: vec![format!("{} {}", component, version)],
+                })
+                .collect(),
+            ..default_registry()
+        }
+    }
+
+    #[test]
+    fn test_compare_versions_numerically() {
+        use std::cmp::Ordering::*;
+
+        assert_eq!(compare_versions("0.10.0", "0.9.1"), Greater);
+        assert_eq!(compare_versions("0.9.0", "0.9.0"), Equal);
+        assert_eq!(compare_versions("v1.2.3", "1.2.3"), Equal);
+        assert_eq!(compare_versions("1.2.3", "1.10.0"), Less);
+    }
+
+    #[test]
+    fn test_compare_versions_of_unequal_length() {
+        use std::cmp::Ordering::*;
+
+        assert_eq!(compare_versions("1.0", "1.0.0"), Equal);
+        assert_eq!(compare_versions("1", "0.9.9"), Greater);
+        assert_eq!(compare_versions("1.0.0.1", "1.0.0"), Greater);
+    }
+
+    #[test]
+    fn test_compare_versions_with_pre_releases() {
+        use std::cmp::Ordering::*;
+
+        assert_eq!(compare_versions("1.0.0-beta", "1.0.0"), Less);
+        assert_eq!(compare_versions("1.0.0", "1.0.0-rc.1"), Greater);
+        assert_eq!(compare_versions("1.0.0-alpha", "1.0.0-beta"), Less);
+        assert_eq!(compare_versions("1.0.0-beta.2", "1.0.0-beta.10"), Less);
+        assert_eq!(compare_versions("1.0.0-alpha", "1.0.0-alpha.1"), Less);
+        // A pre-release of a later version is still newer
+        assert_eq!(compare_versions("1.1.0-beta", "1.0.0"), Greater);
+        assert_eq!(compare_versions("1.0.0+build.5", "1.0.0"), Equal);
+    }
+
+    #[test]
+    fn test_compare_versions_with_non_numeric_parts() {
+        use std::cmp::Ordering::*;
+
+        assert_eq!(compare_versions("1.x", "1.x"), Equal);
+        assert_eq!(compare_versions("1.b", "1.a"), Greater);
+        // Text sorts after numbers
+        assert_eq!(compare_versions("1.a", "1.9"), Greater);
+    }
+
+    #[test]
+    fn test_changes_since_is_newer_and_oldest_first() {
+        let registry = changelog(&[
+            ("button", "0.10.0"),
+            ("button", "0.5.0"),
+            ("card", "0.8.0"),
+            ("button", "0.8.0"),
+            ("button", "0.4.0"),
+        ]);
+        let versions: Vec<&str> = registry
+            .changes_since("button", "0.5.0")
+            .iter()
+            .map(|e| e.version.as_str())
+            .collect();
+        assert_eq!(versions, ["0.8.0", "0.10.0"]);
+
+        assert!(registry.changes_since("button", "0.10.0").is_empty());
+        assert!(registry.changes_since("badge", "0.1.0").is_empty());
+        assert_eq!(registry.changes_since("card", "0.8.0-rc.1").len(), 1);
+    }
+
+    #[test]
+    fn test_default_changelog_is_specific() {
+        let registry = default_registry();
+        let mut seen = std::collections::HashSet::new();
+        for entry in &registry.changelog {
+            assert!(
+                registry.find(&entry.component).is_some(),
+                "changelog names unknown component {}",
+                entry.component
+            );
+            let meta = registry.find(&entry.component).unwrap();
+            assert!(
+                compare_versions(&entry.version, &meta.version).is_le(),
+                "{} {} is newer than the component",
+                entry.component,
+                entry.version
+            );
+            assert!(
+                seen.insert((&entry.component, &entry.version)),
+                "{} {} has two entries",
+                entry.component,
+                entry.version
+            );
+        }
+    }
+
+    #[test]
+    fn test_find_bundle_with_or_without_prefix() {
+        let registry = default_registry();
+        assert_eq!(registry.find_bundle("@forms").unwrap().name, "forms");
+        assert_eq!(registry.find_bundle("forms").unwrap().name, "forms");
+        assert!(registry.find_bundle("@nope").is_none());
+        assert!(registry.find_bundle("@").is_none());
+        // Only a single leading prefix is stripped
+        assert!(registry.find_bundle("@@forms").is_none());
+    }
+
+    #[test]
+    fn test_default_bundles_list_known_components() {
+        let registry = default_registry();
+        for bundle in &registry.bundles {
+            for component in &bundle.components {
+                assert!(
+                    registry.find(component).is_some(),
+                    "@{} lists unknown component {}",
+                    bundle.name,
+                    component
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_expand_bundles_passes_components_through() {
+        let registry = default_registry();
+        let input = names(&["button", "card"]);
+        assert_eq!(registry.expand_bundles(&input).unwrap(), ["button", "card"]);
+    }
+
+    #[test]
+    fn test_expand_bundles_drops_duplicates_in_order() {
+        let registry = registry_with_bundles(&[
+            ("forms", &["input", "label", "checkbox"]),
+            ("toggles", &["checkbox", "switch"]),
+        ]);
+        let input = names(&["label", "@forms", "@toggles", "input", "@forms"]);
+        assert_eq!(
+            registry.expand_bundles(&input).unwrap(),
+            ["label", "input", "checkbox", "switch"]
+        );
+    }
+
+    #[test]
+    fn test_expand_bundles_reports_unknown_bundle() {
+        let registry = default_registry();
+        let input = names(&["button", "@nope", "@forms"]);
+        assert_eq!(registry.expand_bundles(&input), Err("@nope"));
+    }
+
+    #[test]
+    fn test_expand_nested_bundles() {
+        let registry = registry_with_bundles(&[
+            ("base", &["button", "label"]),
+            ("forms", &["@base", "input"]),
+            ("all", &["@forms", "@base", "@all", "card"]),
+            ("broken", &["@missing"]),
+        ]);
+        assert_eq!(
+            registry.expand_bundles(&names(&["@all"])).unwrap(),
+            ["button", "label", "input", "card"]
+        );
+        assert_eq!(
+            registry.expand_bundles(&names(&["@broken"])),
+            Err("@missing")
+        );
+    }
+
+    #[test]
+    fn test_merge_bundles_adds_and_replaces() {
+        let mut registry = registry_with_bundles(&[("forms", &["input"]), ("base", &["button"])]);
+        registry.merge_bundles(registry_with_bundles(&[
+            ("forms", &["input", "label"]),
+            ("team", &["@forms", "card"]),
+        ]));
+        assert_eq!(registry.bundle_names(), ["base", "forms", "team"]);
+        assert_eq!(
+            registry.expand_bundles(&names(&["@team"])).unwrap(),
+            ["input", "label", "card"]
+        );
     }
 }
@@ -0,0 +1,527 @@
+//! Accessibility variants of any palette
+//!
+//! [`PaletteColors::high_contrast`] pushes every text pair to WCAG AAA, and
+//! [`PaletteColors::color_blind`] remaps the status and chart colors so they
+//! stay apart for a red-green [`ColorBlindness`]. Both start from an
+//! existing palette, so they apply on top of any preset or custom theme.
+
+use crate::colors::{linear_to_srgb, srgb_to_linear};
+use crate::{
+    Hsl, PaletteColors, PresetPalette, ThemeMode, apca_contrast, contrast_ratio, relative_luminance,
+};
+
+/// A red-green color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorBlindness {
+    /// Missing green (M) cones, the most common form.
+    Deuteranopia,
+    /// Missing red (L) cones. Reds also look much darker.
+    Protanopia,
+}
+
+impl ColorBlindness {
+    /// Both deficiencies.
+    pub const ALL: [ColorBlindness; 2] = [ColorBlindness::Deuteranopia, ColorBlindness::Protanopia];
+
+    /// Lowercase name, e.g. `"deuteranopia"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ColorBlindness::Deuteranopia => "deuteranopia",
+            ColorBlindness::Protanopia => "protanopia",
+        }
+    }
+
+    /// How `color` looks with this deficiency at full strength, using the
+    /// Machado, Oliveira and Fernandes (2009) simulation. Keeps alpha.
+    pub fn simulate(self, color: Hsl) -> Hsl {
+        let matrix = match self {
+            ColorBlindness::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorBlindness::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+        };
+        let rgb = color.to_rgb().map(srgb_to_linear);
+        let [r, g, b] = matrix.map(|row| {
+            let v = row[0] * rgb[0] + row[1] * rgb[1] + row[2] * rgb[2];
+            linear_to_srgb(v)
+        });
+        crate::parse::rgb_to_hsl(r, g, b, color.a)
+    }
+}
+
+/// An accessibility variant, applied on top of a preset or custom theme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteVariant {
+    /// Every text pair at WCAG AAA and APCA Lc 60.
+    HighContrast,
+    /// Status and chart colors that stay apart for a color vision
+    /// deficiency.
+    ColorBlind(ColorBlindness),
+}
+
+impl PaletteVariant {
+    /// All variants, as accepted by `variant` in `shadcn-ui.toml`.
+    pub const ALL: [PaletteVariant; 3] = [
+        PaletteVariant::HighContrast,
+        PaletteVariant::ColorBlind(ColorBlindness::Deuteranopia),
+        PaletteVariant::ColorBlind(ColorBlindness::Protanopia),
+    ];
+
+    /// Look up a variant by its name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|variant| variant.name() == name)
+    }
+
+    /// Lowercase name, e.g. `"high_contrast"` or `"protanopia"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            PaletteVariant::HighContrast => "high_contrast",
+            PaletteVariant::ColorBlind(vision) => vision.name(),
+        }
+    }
+
+    /// One-line description for theme lists.
+    pub fn description(self) -> &'static str {
+        match self {
+            PaletteVariant::HighContrast => {
+                "AAA text contrast with black or white text and borders"
+            }
+            PaletteVariant::ColorBlind(ColorBlindness::Deuteranopia) => {
+                "Status and chart colors for green-blind (deuteranopia) users"
+            }
+            PaletteVariant::ColorBlind(ColorBlindness::Protanopia) => {
+                "Status and chart colors for red-blind (protanopia) users"
+            }
+        }
+    }
+}
+
+impl PaletteColors {
+    /// This palette with `variant` applied. `mode` is the mode the palette
+    /// is for.
+    pub fn with_variant(&self, variant: PaletteVariant, mode: ThemeMode) -> Self {
+        match variant {
+            PaletteVariant::HighContrast => self.high_contrast(mode),
+            PaletteVariant::ColorBlind(vision) => self.color_blind(vision, mode),
+        }
+    }
+
+    /// A high-contrast version of this palette for `mode`.
+    ///
+    /// Surfaces become white (black in dark mode), with black (white) text
+    /// and borders. Tinted surfaces and filled colors keep their hue; fills
+    /// and muted text move away from their text color until every pair
+    /// reaches WCAG AAA and APCA Lc 60, and focus rings and chart colors
+    /// reach 3:1 against the background.
+    pub fn high_contrast(&self, mode: ThemeMode) -> Self {
+        let dark = mode == ThemeMode::Dark;
+        let (paper, ink) = if dark { (BLACK, WHITE) } else { (WHITE, BLACK) };
+        let aaa = |text: Hsl, surface: Hsl| {
+            contrast_ratio(text, surface) >= 7.0 && apca_contrast(text, surface).abs() >= 60.0
+        };
+        let tint = |color: Hsl, light: f32, dark_l: f32| {
+            Hsl::new(color.h, color.s, if dark { dark_l } else { light })
+        };
+        // Ink or paper text, whichever reads better, on a fill moved away
+        // from it until the pair reaches AAA
+        let fill = |color: Hsl| {
+            let color = color.with_alpha(1.0);
+            let text = if contrast_ratio(ink, color) >= contrast_ratio(paper, color) {
+                ink
+            } else {
+                paper
+            };
+            (separate(color, text, |color| aaa(text, color)), text)
+        };
+        let graphic =
+            |color: Hsl| separate(color, paper, |color| contrast_ratio(color, paper) >= 3.0);
+
+        let mut colors = *self;
+        for surface in [
+            &mut colors.background,
+            &mut colors.card,
+            &mut colors.popover,
+            &mut colors.sidebar,
+        ] {
+            *surface = paper;
+        }
+        colors.secondary = tint(self.secondary, 92.0, 15.0);
+        colors.muted = tint(self.muted, 92.0, 15.0);
+        colors.accent = tint(self.accent, 88.0, 20.0);
+        colors.sidebar_accent = tint(self.sidebar_accent, 88.0, 20.0);
+        for text in [
+            &mut colors.foreground,
+            &mut colors.card_foreground,
+            &mut colors.popover_foreground,
+            &mut colors.sidebar_foreground,
+            &mut colors.secondary_foreground,
+            &mut colors.accent_foreground,
+            &mut colors.sidebar_accent_foreground,
+            &mut colors.border,
+            &mut colors.input,
+            &mut colors.sidebar_border,
+        ] {
+            *text = ink;
+        }
+        // Muted is the darker (lighter in dark mode) of the two surfaces
+        // muted text sits on, so reaching AAA there covers the background
+        let muted = colors.muted;
+        colors.muted_foreground = separate(self.muted_foreground, muted, |text| aaa(text, muted));
+        colors.disabled_foreground = colors.muted_foreground;
+
+        (colors.primary, colors.primary_foreground) = fill(self.primary);
+        (colors.sidebar_primary, colors.sidebar_primary_foreground) = fill(self.sidebar_primary);
+        (colors.destructive, colors.destructive_foreground) = fill(self.destructive);
+        (colors.success, colors.success_foreground) = fill(self.success);
+        (colors.warning, colors.warning_foreground) = fill(self.warning);
+        (colors.info, colors.info_foreground) = fill(self.info);
+
+        colors.ring = graphic(self.ring);
+        colors.sidebar_ring = graphic(self.sidebar_ring);
+        colors.chart_1 = graphic(self.chart_1);
+        colors.chart_2 = graphic(self.chart_2);
+        colors.chart_3 = graphic(self.chart_3);
+        colors.chart_4 = graphic(self.chart_4);
+        colors.chart_5 = graphic(self.chart_5);
+        colors.overlay = self.overlay.with_alpha(self.overlay.a.max(0.85));
+        colors
+    }
+
+    /// A version of this palette for `mode` whose destructive, success,
+    /// warning, info and chart colors stay distinct with `vision`.
+    ///
+    /// Success is blue and destructive orange, so the two never rely on
+    /// telling red from green, and info is a reddish purple. Charts use the
+    /// five Okabe-Ito colors that simulate furthest apart for `vision`.
+    /// Foregrounds come from the palette's `background` and `foreground`,
+    /// whichever contrasts more, and every status pair reaches WCAG AA.
+    pub fn color_blind(&self, vision: ColorBlindness, mode: ThemeMode) -> Self {
+        let safe = SafeColors::new(vision, mode == ThemeMode::Dark);
+        let (background, foreground) = (self.background, self.foreground);
+        let fill = |color: Hsl| {
+            let text = if contrast_ratio(background, color) >= contrast_ratio(foreground, color) {
+                background
+            } else {
+                foreground
+            };
+            (
+                separate(color, text, |color| contrast_ratio(text, color) >= 4.5),
+                text,
+            )
+        };
+
+        let mut colors = *self;
+        (colors.destructive, colors.destructive_foreground) = fill(safe.destructive);
+        (colors.success, colors.success_foreground) = fill(safe.success);
+        (colors.warning, colors.warning_foreground) = fill(safe.warning);
+        (colors.info, colors.info_foreground) = fill(safe.info);
+        [
+            colors.chart_1,
+            colors.chart_2,
+            colors.chart_3,
+            colors.chart_4,
+            colors.chart_5,
+        ] = safe.charts;
+        colors
+    }
+}
+
+impl PresetPalette {
+    /// This preset with `variant` applied to both modes. The name,
+    /// description and radius are unchanged.
+    pub fn with_variant(&self, variant: PaletteVariant) -> Self {
+        Self {
+            light: self.light.with_variant(variant, ThemeMode::Light),
+            dark: self.dark.with_variant(variant, ThemeMode::Dark),
+            ..*self
+        }
+    }
+
+    /// The high-contrast variant of this preset; see
+    /// [`PaletteColors::high_contrast`].
+    pub fn high_contrast(&self) -> Self {
+        self.with_variant(PaletteVariant::HighContrast)
+    }
+
+    /// The color-blind safe variant of this preset for `vision`; see
+    /// [`PaletteColors::color_blind`].
+    pub fn color_blind(&self, vision: ColorBlindness) -> Self {
+        self.with_variant(PaletteVariant::ColorBlind(vision))
+    }
+}
+
+const BLACK: Hsl = Hsl::new(0.0, 0.0, 0.0);
+const WHITE: Hsl = Hsl::new(0.0, 0.0, 100.0);
+
+/// Move `color`'s lightness away from `from` one step at a time until `ok`
+/// holds, stopping at black or white.
+fn separate(color: Hsl, from: Hsl, ok: impl Fn(Hsl) -> bool) -> Hsl {
+    let step = if relative_luminance(from) > relative_luminance(color) {
+        -1.0
+    } else {
+        1.0
+    };
+    let mut color = color;
+    while !ok(color) && (0.0..=100.0).contains(&(color.l + step)) {
+        color.l += step;
+    }
+    color
+}
+
+/// Status and chart colors for one deficiency and mode.
+struct SafeColors {
+    destructive: Hsl,
+    success: Hsl,
+    warning: Hsl,
+    info: Hsl,
+    charts: [Hsl; 5],
+}
+
+impl SafeColors {
+    fn new(vision: ColorBlindness, dark: bool) -> Self {
+        // Okabe-Ito: blue, orange, sky blue, bluish green, vermilion, yellow
+        const BLUE: Hsl = Hsl::new(202.1, 100.0, 34.9);
+        const ORANGE: Hsl = Hsl::new(41.3, 100.0, 45.1);
+        const SKY: Hsl = Hsl::new(201.8, 77.3, 62.9);
+        const GREEN: Hsl = Hsl::new(163.7, 100.0, 31.0);
+        const VERMILION: Hsl = Hsl::new(26.5, 100.0, 41.8);
+        const YELLOW: Hsl = Hsl::new(55.7, 84.8, 60.0);
+
+        // Protanopes see reds much darker, so destructive leans orange and
+        // the charts swap bluish green, which they confuse with sky blue
+        // and orange, for vermilion
+        let (destructive, charts) = match (vision, dark) {
+            (ColorBlindness::Deuteranopia, false) => (
+                Hsl::new(24.3, 100.0, 36.9),
+                [BLUE, ORANGE, SKY, GREEN, YELLOW],
+            ),
+            (ColorBlindness::Deuteranopia, true) => (
+                Hsl::new(24.3, 100.0, 50.0),
+                [SKY, ORANGE, BLUE, GREEN, YELLOW],
+            ),
+            (ColorBlindness::Protanopia, false) => (
+                Hsl::new(30.0, 100.0, 37.0),
+                [BLUE, ORANGE, SKY, VERMILION, YELLOW],
+            ),
+            (ColorBlindness::Protanopia, true) => (
+                Hsl::new(30.0, 100.0, 55.0),
+                [SKY, ORANGE, BLUE, VERMILION, YELLOW],
+            ),
+        };
+        let warning = match (vision, dark) {
+            (ColorBlindness::Deuteranopia, false) => ORANGE,
+            (ColorBlindness::Protanopia, true) => Hsl::new(55.0, 90.0, 60.0),
+            _ => Hsl::new(48.0, 96.0, 53.1),
+        };
+        if dark {
+            Self {
+                destructive,
+                success: Hsl::new(212.4, 92.1, 62.0),
+                warning,
+                info: Hsl::new(326.8, 45.3, 63.7),
+                charts,
+            }
+        } else {
+            Self {
+                destructive,
+                success: Hsl::new(212.4, 92.1, 44.5),
+                warning,
+                info: Hsl::new(326.8, 60.0, 40.0),
+                charts,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContrastLevel, ContrastReport, PRESETS, ZINC, to_oklch};
+
+    const MODES: [ThemeMode; 2] = [ThemeMode::Light, ThemeMode::Dark];
+
+    /// OKLab distance, where about 0.02 is just noticeable.
+    fn distance(a: Hsl, b: Hsl) -> f32 {
+        let lab = |color: Hsl| {
+            let [l, c, h] = to_oklch(color);
+            let h = h.to_radians();
+            [l, c * h.cos(), c * h.sin()]
+        };
+        let (a, b) = (lab(a), lab(b));
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+    }
+
+    /// The smallest simulated distance between any two of `colors`.
+    fn closest(colors: &[Hsl], vision: ColorBlindness) -> f32 {
+        let simulated: Vec<_> = colors.iter().map(|&c| vision.simulate(c)).collect();
+        let mut closest = f32::MAX;
+        for (i, &a) in simulated.iter().enumerate() {
+            for &b in &simulated[i + 1..] {
+                closest = closest.min(distance(a, b));
+            }
+        }
+        closest
+    }
+
+    fn status(colors: &PaletteColors) -> [Hsl; 4] {
+        [
+            colors.destructive,
+            colors.success,
+            colors.warning,
+            colors.info,
+        ]
+    }
+
+    fn charts(colors: &PaletteColors) -> [Hsl; 5] {
+        [
+            colors.chart_1,
+            colors.chart_2,
+            colors.chart_3,
+            colors.chart_4,
+            colors.chart_5,
+        ]
+    }
+
+    #[test]
+    fn test_variant_names_round_trip() {
+        for variant in PaletteVariant::ALL {
+            assert_eq!(PaletteVariant::from_name(variant.name()), Some(variant));
+        }
+        assert_eq!(PaletteVariant::from_name("colorblind"), None);
+    }
+
+    #[test]
+    fn test_simulation_keeps_grays() {
+        let gray = Hsl::new(0.0, 0.0, 50.0);
+        for vision in ColorBlindness::ALL {
+            assert!(distance(vision.simulate(gray), gray) < 0.01);
+        }
+        // Protanopes see red darker than deuteranopes do
+        let red = Hsl::new(0.0, 100.0, 50.0);
+        assert!(
+            relative_luminance(ColorBlindness::Protanopia.simulate(red))
+                < relative_luminance(ColorBlindness::Deuteranopia.simulate(red))
+        );
+    }
+
+    #[test]
+    fn test_high_contrast_meets_aaa_and_apca() {
+        let brand = PaletteColors::from_brand("#4f46e5".parse().unwrap(), &ZINC, ThemeMode::Light);
+        for preset in PRESETS {
+            for mode in MODES {
+                let colors = preset.colors(mode).high_contrast(mode);
+                let report = ContrastReport::for_palette(&colors);
+                for level in [ContrastLevel::Aaa, ContrastLevel::Apca] {
+                    let failures: Vec<_> = report.failures(level).collect();
+                    assert!(
+                        failures.is_empty(),
+                        "{} {mode:?} {level:?}: {failures:?}",
+                        preset.name
+                    );
+                }
+                for graphic in [colors.ring, colors.chart_1, colors.chart_5] {
+                    assert!(contrast_ratio(graphic, colors.background) >= 3.0);
+                }
+            }
+        }
+        let report = ContrastReport::for_palette(&brand.high_contrast(ThemeMode::Light));
+        assert!(report.passes(ContrastLevel::Aaa));
+    }
+
+    #[test]
+    fn test_high_contrast_keeps_hues() {
+        let colors = ZINC.high_contrast();
+        for (before, after) in [
+            (ZINC.light.destructive, colors.light.destructive),
+            (ZINC.dark.success, colors.dark.success),
+            (ZINC.dark.chart_1, colors.dark.chart_1),
+        ] {
+            assert_eq!(after.h, before.h);
+        }
+        assert_eq!(colors.light.background, WHITE);
+        assert_eq!(colors.dark.border, WHITE);
+    }
+
+    #[test]
+    fn test_color_blind_status_pairs_meet_aa() {
+        for vision in ColorBlindness::ALL {
+            for preset in PRESETS {
+                for mode in MODES {
+                    let colors = preset.colors(mode).color_blind(vision, mode);
+                    let report = ContrastReport::for_palette(&colors);
+                    for check in &report.checks {
+                        if ["destructive", "success", "warning", "info"].contains(&check.background)
+                        {
+                            assert!(
+                                check.passes(ContrastLevel::Aa),
+                                "{} {vision:?} {mode:?}: {check:?}",
+                                preset.name
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_blind_hues_avoid_red_green() {
+        for vision in ColorBlindness::ALL {
+            for mode in MODES {
+                let colors = ZINC.colors(mode).color_blind(vision, mode);
+                let [destructive, success] =
+                    [colors.destructive, colors.success].map(|color| to_oklch(color)[2]);
+                // Orange against blue, on opposite sides of the hue circle
+                assert!(
+                    (40.0..80.0).contains(&destructive),
+                    "{vision:?} {destructive}"
+                );
+                assert!((220.0..270.0).contains(&success), "{vision:?} {success}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_blind_colors_stay_apart_when_simulated() {
+        for vision in ColorBlindness::ALL {
+            for mode in MODES {
+                let before = ZINC.colors(mode);
+                let after = before.color_blind(vision, mode);
+                assert!(
+                    closest(&status(&after), vision) > 0.1,
+                    "{vision:?} {mode:?} status"
+                );
+                assert!(
+                    closest(&charts(&after), vision) > 0.1,
+                    "{vision:?} {mode:?} charts"
+                );
+                // Zinc's red and green collapse onto the same simulated hue;
+                // the remapped pair lands on opposite sides
+                let hues = |colors: &PaletteColors| {
+                    let [a, b] = [colors.destructive, colors.success]
+                        .map(|color| to_oklch(vision.simulate(color))[2]);
+                    let gap = (a - b).abs();
+                    gap.min(360.0 - gap)
+                };
+                assert!(hues(before) < 10.0, "{vision:?} {mode:?}");
+                assert!(hues(&after) > 90.0, "{vision:?} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_color_blind_variants_differ() {
+        let [deuteranopia, protanopia] = ColorBlindness::ALL.map(|vision| ZINC.color_blind(vision));
+        assert_ne!(deuteranopia.light.destructive, protanopia.light.destructive);
+        assert_ne!(deuteranopia.dark.chart_4, protanopia.dark.chart_4);
+        // Neutrals are untouched
+        assert_eq!(deuteranopia.light.background, ZINC.light.background);
+        assert_eq!(protanopia.dark.primary, ZINC.dark.primary);
+    }
+}
@@ -0,0 +1,137 @@
+//! Embedded fonts and icons
+//!
+//! With the `assets` feature, the crate embeds Inter for UI text and Fira
+//! Mono for code and key hints, both under the SIL Open Font License, and a
+//! set of Lucide icons under the ISC license. [`register_fonts`] adds the
+//! fonts to gpui's text system and [`Typography::embedded`] refers to them.
+//! Only Inter's Regular face is embedded, so medium, semibold and bold text
+//! draws in Regular unless the app adds those faces itself.
+//! [`Assets`] serves the icons as `icons/<name>.svg`, the paths the `icon`
+//! component draws.
+
+use std::borrow::Cow;
+
+use gpui::{App, AssetSource, SharedString};
+
+use crate::Typography;
+
+/// Family name of the embedded UI font.
+pub const UI_FONT_FAMILY: &str = "Inter";
+
+/// Family name of the embedded monospace font.
+pub const MONO_FONT_FAMILY: &str = "Fira Mono";
+
+const FONTS: &[&[u8]] = &[
+    include_bytes!("../assets/fonts/Inter-Regular.ttf"),
+    include_bytes!("../assets/fonts/FiraMono-Medium.ttf"),
+];
+
+/// Includes `assets/icons/<name>.svg` for each name.
+macro_rules! icons {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_str!(concat!("../assets/icons/", $name, ".svg")))),*]
+    };
+}
+
+/// Embedded Lucide icons as `(name, svg)`, e.g. `("chevron-down", "<svg ...")`.
+pub const ICONS: &[(&str, &str)] = icons![
+    "check",
+    "chevron-down",
+    "chevron-left",
+    "chevron-right",
+    "chevron-up",
+    "circle-alert",
+    "circle-check",
+    "ellipsis",
+    "info",
+    "minus",
+    "triangle-alert",
+    "x",
+];
+
+/// Add the embedded fonts to gpui's text system. Call once at startup,
+/// before drawing with [`Typography::embedded`].
+pub fn register_fonts(cx: &App) -> anyhow::Result<()> {
+    cx.text_system()
+        .add_fonts(FONTS.iter().map(|font| Cow::Borrowed(*font)).collect())
+}
+
+/// The SVG source of an embedded icon, looked up by asset path such as
+/// `icons/check.svg`.
+pub fn icon_svg(path: &str) -> Option<&'static str> {
+    let name = path.strip_prefix("icons/")?.strip_suffix(".svg")?;
+    ICONS
+        .iter()
+        .find(|(icon, _)| *icon == name)
+        .map(|(_, svg)| *svg)
+}
+
+/// Serves the embedded icons to gpui, e.g. with
+/// `Application::new().with_assets(Assets)`.
+///
+/// Apps with their own asset source can call [`icon_svg`] for paths under
+/// `icons/` and serve everything else themselves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Assets;
+
+impl AssetSource for Assets {
+    fn load(&self, path: &str) -> anyhow::Result<Option<Cow<'static, [u8]>>> {
+        Ok(icon_svg(path).map(|svg| Cow::Borrowed(svg.as_bytes())))
+    }
+
+    fn list(&self, path: &str) -> anyhow::Result<Vec<SharedString>> {
+        Ok(ICONS
+            .iter()
+            .map(|(name, _)| format!("icons/{}.svg", name))
+            .filter(|icon| icon.starts_with(path))
+            .map(SharedString::from)
+            .collect())
+    }
+}
+
+impl Typography {
+    /// The default type scale with the embedded [`UI_FONT_FAMILY`] and
+    /// [`MONO_FONT_FAMILY`]. Register them first with [`register_fonts`].
+    pub fn embedded() -> Self {
+        Self {
+            font_family: UI_FONT_FAMILY.to_string(),
+            mono_font_family: MONO_FONT_FAMILY.to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icons_resolve_by_path() {
+        assert!(
+            icon_svg("icons/check.svg")
+                .unwrap()
+                .contains("M20 6 9 17l-5-5")
+        );
+        assert!(icon_svg("icons/missing.svg").is_none());
+        assert!(icon_svg("check").is_none());
+
+        for (name, svg) in ICONS {
+            assert!(svg.starts_with("<svg"), "{} is not an svg", name);
+            assert!(
+                svg.contains("stroke=\"currentColor\""),
+                "{} is not tintable",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_embedded_fonts_are_truetype() {
+        for font in FONTS {
+            assert_eq!(&font[..4], &[0, 1, 0, 0]);
+        }
+        let typography = Typography::embedded();
+        assert_eq!(typography.mono_font_family, MONO_FONT_FAMILY);
+        assert_eq!(typography.base, Typography::default().base);
+    }
+}
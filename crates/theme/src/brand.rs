@@ -0,0 +1,154 @@
+//! Palettes derived from a single brand color
+//!
+//! The brand color becomes `primary`, `ring` and the first chart color, a
+//! light tint of it becomes `accent`, and everything else comes from a
+//! neutral preset. Foregrounds are picked from the neutral's `background` and
+//! `foreground`, whichever contrasts more, and mid-tone brands are shaded
+//! until their foreground reaches WCAG AA.
+
+use crate::{Hsl, PaletteColors, PresetPalette, ThemeMode, contrast_ratio};
+
+/// WCAG AA contrast for body text.
+const AA: f32 = 4.5;
+
+impl PaletteColors {
+    /// Derive a palette for `mode` from a brand color, with neutrals taken
+    /// from `neutral`.
+    ///
+    /// In dark mode the brand color is lightened to at least 60% lightness
+    /// so it stands out on dark backgrounds. If neither neutral foreground
+    /// reaches 4.5:1 on it, `primary` is darkened or lightened away from the
+    /// foreground until one does, so the palette passes `theme check`.
+    pub fn from_brand(brand: Hsl, neutral: &PresetPalette, mode: ThemeMode) -> Self {
+        let mut colors = *neutral.colors(mode);
+        let dark = mode == ThemeMode::Dark;
+        let brand = brand.with_alpha(1.0);
+        let primary = if dark {
+            Hsl::new(brand.h, brand.s, brand.l.max(60.0))
+        } else {
+            brand
+        };
+        let accent = if dark {
+            Hsl::new(brand.h, (brand.s * 0.4).min(40.0), 16.0)
+        } else {
+            Hsl::new(brand.h, (brand.s * 0.8).min(80.0), 96.0)
+        };
+        let on = |surface: Hsl| {
+            let (background, foreground) = (colors.background, colors.foreground);
+            if contrast_ratio(background, surface) >= contrast_ratio(foreground, surface) {
+                background
+            } else {
+                foreground
+            }
+        };
+        let mut primary = primary;
+        while contrast_ratio(on(primary), primary) < AA && (1.0..99.0).contains(&primary.l) {
+            primary.l += if on(primary).l > primary.l { -1.0 } else { 1.0 };
+        }
+        let (primary_foreground, accent_foreground) = (on(primary), on(accent));
+
+        colors.primary = primary;
+        colors.primary_foreground = primary_foreground;
+        colors.ring = primary;
+        colors.accent = accent;
+        colors.accent_foreground = accent_foreground;
+        colors.sidebar_primary = primary;
+        colors.sidebar_primary_foreground = primary_foreground;
+        colors.sidebar_accent = accent;
+        colors.sidebar_accent_foreground = accent_foreground;
+        colors.sidebar_ring = primary;
+        colors.selection = primary.with_alpha(if dark { 0.4 } else { 0.3 });
+        // Spread the chart colors around the hue circle, starting at the brand
+        let chart = |step: f32| {
+            Hsl::new(
+                (primary.h + 72.0 * step).rem_euclid(360.0),
+                primary.s,
+                primary.l,
+            )
+        };
+        colors.chart_1 = primary;
+        colors.chart_2 = chart(1.0);
+        colors.chart_3 = chart(2.0);
+        colors.chart_4 = chart(3.0);
+        colors.chart_5 = chart(4.0);
+        colors
+    }
+}
+
+#[cfg(feature = "gpui")]
+impl crate::ThemeColors {
+    /// Derive a palette for `mode` from a brand color, with neutrals taken
+    /// from `neutral`. See [`PaletteColors::from_brand`].
+    pub fn from_brand(brand: gpui::Hsla, neutral: &PresetPalette, mode: ThemeMode) -> Self {
+        (&PaletteColors::from_brand(brand.into(), neutral, mode)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ContrastLevel, ContrastReport, PRESETS, SLATE, ZINC};
+
+    #[test]
+    fn test_brand_becomes_primary() {
+        let indigo: Hsl = "#4f46e5".parse().unwrap();
+        let light = PaletteColors::from_brand(indigo, &SLATE, ThemeMode::Light);
+        assert_eq!(light.primary, indigo);
+        assert_eq!(light.ring, indigo);
+        assert_eq!(light.background, SLATE.light.background);
+        assert_eq!(light.primary_foreground, SLATE.light.background);
+
+        let dark = PaletteColors::from_brand(indigo, &SLATE, ThemeMode::Dark);
+        assert_eq!(dark.primary.h, indigo.h);
+        assert!(dark.primary.l >= 60.0);
+        assert_eq!(dark.border, SLATE.dark.border);
+    }
+
+    #[test]
+    fn test_foregrounds_follow_brand_lightness() {
+        let yellow = Hsl::new(48.0, 96.0, 60.0);
+        let light = PaletteColors::from_brand(yellow, &ZINC, ThemeMode::Light);
+        assert_eq!(light.primary_foreground, ZINC.light.foreground);
+
+        for brand in [
+            yellow,
+            Hsl::new(243.0, 75.0, 59.0),
+            Hsl::new(160.0, 84.0, 39.0),
+        ] {
+            for mode in [ThemeMode::Light, ThemeMode::Dark] {
+                let neutral = ZINC.colors(mode);
+                let colors = PaletteColors::from_brand(brand, &ZINC, mode);
+                let chosen = contrast_ratio(colors.primary_foreground, colors.primary);
+                for candidate in [neutral.background, neutral.foreground] {
+                    assert!(chosen >= contrast_ratio(candidate, colors.primary));
+                }
+                let report = ContrastReport::for_palette(&colors);
+                let accent = report
+                    .checks
+                    .iter()
+                    .find(|check| check.background == "accent")
+                    .unwrap();
+                assert!(accent.passes(ContrastLevel::Aa), "{brand:?} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_brand_palettes_pass_aa() {
+        for neutral in PRESETS {
+            for h in (0..360).step_by(30) {
+                for s in [30.0, 70.0, 100.0] {
+                    for l in (10..=90).step_by(10) {
+                        let brand = Hsl::new(h as f32, s, l as f32);
+                        for mode in [ThemeMode::Light, ThemeMode::Dark] {
+                            let colors = PaletteColors::from_brand(brand, neutral, mode);
+                            let report = ContrastReport::for_palette(&colors);
+                            let failures: Vec<_> = report.failures(ContrastLevel::Aa).collect();
+                            assert!(failures.is_empty(), "{brand:?} {mode:?}: {failures:?}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,8 +1,17 @@
 //! Color utilities and conversions
+//!
+//! The functions here accept [`Hsl`] and, with the `gpui` feature, gpui's
+//! `Hsla`: any color that converts to and from [`Hsl`]. Amounts, weights and
+//! alpha run from 0 to 1 whichever type is passed, and conversions work in
+//! sRGB, so the results match CSS.
 
+#[cfg(feature = "gpui")]
 use gpui::Hsla;
 
+use crate::{ColorParseError, Hsl};
+
 /// Convert HSL values to GPUI's Hsla
+#[cfg(feature = "gpui")]
 pub fn hsl(h: f32, s: f32, l: f32) -> Hsla {
     Hsla {
         h: h / 360.0,
@@ -13,6 +22,7 @@ pub fn hsl(h: f32, s: f32, l: f32) -> Hsla {
 }
 
 /// Convert HSL with alpha to GPUI's Hsla
+#[cfg(feature = "gpui")]
 pub fn hsla(h: f32, s: f32, l: f32, a: f32) -> Hsla {
     Hsla {
         h: h / 360.0,
@@ -21,3 +31,283 @@ pub fn hsla(h: f32, s: f32, l: f32, a: f32) -> Hsla {
         a,
     }
 }
+
+#[cfg(feature = "gpui")]
+impl From<Hsla> for Hsl {
+    fn from(c: Hsla) -> Self {
+        Hsl::new(c.h * 360.0, c.s * 100.0, c.l * 100.0).with_alpha(c.a)
+    }
+}
+
+#[cfg(feature = "gpui")]
+impl From<Hsl> for Hsla {
+    fn from(c: Hsl) -> Self {
+        hsla(c.h, c.s, c.l, c.a)
+    }
+}
+
+/// Parse a color as written in theme files, e.g. `"hsl(240, 5.9%, 10%)"`,
+/// `"#7c3aed"` or `"oklch(0.54 0.25 293 / 50%)"`.
+///
+/// See [`Hsl`]'s `FromStr` implementation for the accepted syntax.
+#[cfg(feature = "gpui")]
+pub fn parse_color(s: &str) -> Result<Hsla, ColorParseError> {
+    s.parse::<Hsl>().map(Hsla::from)
+}
+
+/// Parse a color string. Accepts the same syntax as [`parse_color`].
+#[cfg(feature = "gpui")]
+pub fn parse_hsl(s: &str) -> Result<Hsla, ColorParseError> {
+    parse_color(s)
+}
+
+/// Format a color as an HSL string, the form theme files are written in.
+///
+/// Opaque colors are written as `hsl(h, s%, l%)`, others as `hsla(h, s%, l%, a)`.
+#[cfg(feature = "gpui")]
+pub fn to_hsl_string(color: Hsla) -> String {
+    // Round away float noise such as 5.8999996 from the 0..1 conversion
+    fn round(v: f32) -> f32 {
+        (v * 1000.0).round() / 1000.0
+    }
+
+    let h = round(color.h * 360.0);
+    let s = round(color.s * 100.0);
+    let l = round(color.l * 100.0);
+    if color.a >= 1.0 {
+        format!("hsl({h}, {s}%, {l}%)")
+    } else {
+        format!("hsla({h}, {s}%, {l}%, {})", round(color.a))
+    }
+}
+
+/// Raise the lightness by `amount`, e.g. `0.1` for ten percentage points.
+pub fn lighten<C: Into<Hsl> + From<Hsl>>(color: C, amount: f32) -> C {
+    let c = color.into();
+    Hsl {
+        l: (c.l + amount * 100.0).clamp(0.0, 100.0),
+        ..c
+    }
+    .into()
+}
+
+/// Lower the lightness by `amount`, e.g. `0.1` for ten percentage points.
+pub fn darken<C: Into<Hsl> + From<Hsl>>(color: C, amount: f32) -> C {
+    lighten(color, -amount)
+}
+
+/// Blend two colors in sRGB, alpha included. `weight` is the share of `b`:
+/// 0 gives `a`, 1 gives `b` and 0.5 an even mix, as in Sass's `mix`.
+pub fn mix<C: Into<Hsl> + From<Hsl>>(a: C, b: C, weight: f32) -> C {
+    let (a, b) = (a.into(), b.into());
+    let weight = weight.clamp(0.0, 1.0);
+    let (from, to) = (a.to_rgb(), b.to_rgb());
+    let [r, g, bl] = [0, 1, 2].map(|i| from[i] + (to[i] - from[i]) * weight);
+    crate::parse::rgb_to_hsl(r, g, bl, a.a + (b.a - a.a) * weight).into()
+}
+
+/// The same color with alpha set to `alpha`.
+pub fn with_alpha<C: Into<Hsl> + From<Hsl>>(color: C, alpha: f32) -> C {
+    color.into().with_alpha(alpha.clamp(0.0, 1.0)).into()
+}
+
+/// WCAG relative luminance, from 0 for black to 1 for white. Ignores alpha.
+pub fn relative_luminance(color: impl Into<Hsl>) -> f32 {
+    luminance(color.into().to_rgb())
+}
+
+/// A `#rrggbb` hex string, or `#rrggbbaa` for translucent colors.
+pub fn to_hex(color: impl Into<Hsl>) -> String {
+    let c = color.into();
+    let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let [r, g, b] = c.to_rgb().map(byte);
+    if c.a < 1.0 {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, byte(c.a))
+    } else {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+/// Parse a hex color: `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`, with or
+/// without the `#`.
+pub fn from_hex<C: From<Hsl>>(hex: &str) -> Result<C, ColorParseError> {
+    let digits = hex.trim();
+    crate::parse::parse_hex(digits.strip_prefix('#').unwrap_or(digits))
+        .map(C::from)
+        .ok_or_else(|| ColorParseError(hex.to_string()))
+}
+
+/// sRGB channels from 0 to 1. Ignores alpha.
+pub fn to_rgb(color: impl Into<Hsl>) -> [f32; 3] {
+    color.into().to_rgb()
+}
+
+/// An opaque color from sRGB channels from 0 to 1.
+pub fn from_rgb<C: From<Hsl>>([r, g, b]: [f32; 3]) -> C {
+    let channel = |v: f32| v.clamp(0.0, 1.0);
+    crate::parse::rgb_to_hsl(channel(r), channel(g), channel(b), 1.0).into()
+}
+
+/// OKLCH lightness (0 to 1), chroma and hue in degrees, as in CSS
+/// `oklch()`. Ignores alpha.
+pub fn to_oklch(color: impl Into<Hsl>) -> [f32; 3] {
+    let [r, g, b] = color.into().to_rgb().map(srgb_to_linear);
+
+    // Linear sRGB -> OKLab, per https://bottosson.github.io/posts/oklab/
+    let l = (0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b).cbrt();
+    let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+    let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+    let lightness = 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s;
+    let a = 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s;
+    let b = 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s;
+
+    let chroma = a.hypot(b);
+    // Grays have no meaningful hue
+    let hue = if chroma < 1e-4 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    };
+    [lightness, chroma, hue]
+}
+
+/// An opaque color from OKLCH lightness (0 to 1), chroma and hue in
+/// degrees. Colors outside sRGB are clipped.
+pub fn from_oklch<C: From<Hsl>>([lightness, chroma, hue]: [f32; 3]) -> C {
+    let h = hue.to_radians();
+    let (a, b) = (chroma * h.cos(), chroma * h.sin());
+
+    // OKLab -> linear sRGB
+    let l = (lightness + 0.396_337_78 * a + 0.215_803_76 * b).powi(3);
+    let m = (lightness - 0.105_561_346 * a - 0.063_854_17 * b).powi(3);
+    let s = (lightness - 0.089_484_18 * a - 1.291_485_5 * b).powi(3);
+    let r = 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s;
+    let g = -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s;
+    let b = -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s;
+
+    crate::parse::rgb_to_hsl(linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), 1.0).into()
+}
+
+/// WCAG relative luminance of sRGB channels.
+pub(crate) fn luminance(rgb: [f32; 3]) -> f32 {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+/// Undo the sRGB transfer function.
+pub(crate) fn srgb_to_linear(v: f32) -> f32 {
+    if v <= 0.04045 {
+        v / 12.92
+    } else {
+        ((v + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Apply the sRGB transfer function to a linear channel, clipped to 0..=1.
+pub(crate) fn linear_to_srgb(v: f32) -> f32 {
+    let v = v.clamp(0.0, 1.0);
+    if v <= 0.003_130_8 {
+        12.92 * v
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WHITE: Hsl = Hsl::new(0.0, 0.0, 100.0);
+    const BLACK: Hsl = Hsl::new(0.0, 0.0, 0.0);
+
+    fn assert_close(actual: Hsl, expected: Hsl) {
+        let close = |a: f32, b: f32| (a - b).abs() < 0.2;
+        assert!(
+            close(actual.h, expected.h)
+                && close(actual.s, expected.s)
+                && close(actual.l, expected.l)
+                && close(actual.a * 100.0, expected.a * 100.0),
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_lighten_and_darken_clamp() {
+        let gray = Hsl::new(240.0, 5.0, 50.0);
+        assert_eq!(lighten(gray, 0.1).l, 60.0);
+        assert_eq!(darken(gray, 0.1).l, 40.0);
+        assert_eq!(lighten(gray, 0.8).l, 100.0);
+        assert_eq!(darken(gray, 0.8).l, 0.0);
+        assert_eq!(lighten(gray, 0.1).h, 240.0);
+    }
+
+    #[test]
+    fn test_mix() {
+        assert_close(mix(BLACK, WHITE, 0.0), BLACK);
+        assert_close(mix(BLACK, WHITE, 1.0), WHITE);
+        // Mixing in sRGB, as Sass does: #000 and #fff give #808080
+        assert_eq!(to_hex(mix(BLACK, WHITE, 0.5)), "#808080");
+        let faded = mix(BLACK, WHITE.with_alpha(0.0), 0.5);
+        assert!((faded.a - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        for hex in ["#7c3aed", "#000000", "#ffffff", "#18181b", "#ef444480"] {
+            assert_eq!(to_hex(from_hex::<Hsl>(hex).unwrap()), hex);
+        }
+        assert_eq!(
+            from_hex::<Hsl>("fff").unwrap(),
+            from_hex::<Hsl>("#ffffff").unwrap()
+        );
+        assert_eq!(to_hex(with_alpha(WHITE, 0.5)), "#ffffff80");
+        assert!(from_hex::<Hsl>("#12345").is_err());
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        let violet: Hsl = from_rgb([124.0 / 255.0, 58.0 / 255.0, 237.0 / 255.0]);
+        assert_close(violet, Hsl::new(262.1, 83.3, 57.8));
+        assert_eq!(to_hex(violet), "#7c3aed");
+        let [r, g, b] = to_rgb(violet);
+        assert!((r * 255.0 - 124.0).abs() < 0.5);
+        assert!((g * 255.0 - 58.0).abs() < 0.5);
+        assert!((b * 255.0 - 237.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        // #7c3aed is oklch(0.541 0.247 293.0) in CSS
+        let violet: Hsl = from_hex("#7c3aed").unwrap();
+        let [l, c, h] = to_oklch(violet);
+        assert!((l - 0.541).abs() < 0.002, "l = {l}");
+        assert!((c - 0.247).abs() < 0.002, "c = {c}");
+        assert!((h - 293.0).abs() < 0.5, "h = {h}");
+        assert_close(from_oklch([l, c, h]), violet);
+
+        let [l, c, h] = to_oklch(WHITE);
+        assert!((l - 1.0).abs() < 1e-3 && c < 1e-3 && h == 0.0);
+    }
+
+    #[test]
+    fn test_relative_luminance() {
+        assert_eq!(relative_luminance(BLACK), 0.0);
+        assert!((relative_luminance(WHITE) - 1.0).abs() < 1e-6);
+        // Luminance ignores alpha
+        assert_eq!(
+            relative_luminance(WHITE.with_alpha(0.2)),
+            relative_luminance(WHITE)
+        );
+    }
+
+    #[cfg(feature = "gpui")]
+    #[test]
+    fn test_works_on_hsla() {
+        let primary = hsl(240.0, 5.9, 10.0);
+        let lighter: Hsla = lighten(primary, 0.5);
+        assert!((lighter.l - 0.6).abs() < 1e-5);
+        assert_eq!(with_alpha(primary, 0.5).a, 0.5);
+        assert_eq!(to_hex(primary), "#18181b");
+        assert!(crate::contrast_ratio(primary, hsl(0.0, 0.0, 100.0)) > 17.0);
+    }
+}
@@ -0,0 +1,263 @@
+//! Contrast checks for foreground/background token pairs
+//!
+//! Each pair is measured with the WCAG 2.1 contrast ratio and the APCA
+//! lightness contrast (Lc). Works on [`PaletteColors`], so it does not depend
+//! on gpui; [`contrast_report`] checks a [`crate::Theme`].
+
+use crate::colors::luminance;
+use crate::{Hsl, PaletteColors};
+
+/// Foreground/background token pairs that render text, as
+/// `(foreground, background)`.
+pub const CONTRAST_PAIRS: &[(&str, &str)] = &[
+    ("foreground", "background"),
+    ("muted_foreground", "background"),
+    ("card_foreground", "card"),
+    ("popover_foreground", "popover"),
+    ("primary_foreground", "primary"),
+    ("secondary_foreground", "secondary"),
+    ("muted_foreground", "muted"),
+    ("accent_foreground", "accent"),
+    ("destructive_foreground", "destructive"),
+    ("sidebar_foreground", "sidebar"),
+    ("sidebar_primary_foreground", "sidebar_primary"),
+    ("sidebar_accent_foreground", "sidebar_accent"),
+    ("success_foreground", "success"),
+    ("warning_foreground", "warning"),
+    ("info_foreground", "info"),
+];
+
+/// Minimum contrast a pair must reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContrastLevel {
+    /// WCAG 2.1 AA for body text: a ratio of at least 4.5:1.
+    #[default]
+    Aa,
+    /// WCAG 2.1 AAA for body text: a ratio of at least 7:1.
+    Aaa,
+    /// APCA for body text: an absolute Lc of at least 60.
+    Apca,
+}
+
+impl ContrastLevel {
+    /// All levels, as accepted by `shadcn-ui theme check --level`.
+    pub const ALL: [ContrastLevel; 3] =
+        [ContrastLevel::Aa, ContrastLevel::Aaa, ContrastLevel::Apca];
+
+    /// Look up a level by its lowercase name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|level| level.name() == name)
+    }
+
+    /// Lowercase name, e.g. `"aa"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ContrastLevel::Aa => "aa",
+            ContrastLevel::Aaa => "aaa",
+            ContrastLevel::Apca => "apca",
+        }
+    }
+
+    /// The threshold as written in reports, e.g. `4.5:1` or `Lc 60`.
+    pub fn threshold(self) -> &'static str {
+        match self {
+            ContrastLevel::Aa => "4.5:1",
+            ContrastLevel::Aaa => "7:1",
+            ContrastLevel::Apca => "Lc 60",
+        }
+    }
+}
+
+/// The contrast of one foreground/background pair.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastCheck {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    /// WCAG 2.1 contrast ratio, from 1 to 21.
+    pub ratio: f32,
+    /// APCA lightness contrast. Positive for dark text on a light
+    /// background, negative for light text on a dark one.
+    pub apca: f32,
+}
+
+impl ContrastCheck {
+    /// Whether the pair reaches `level`.
+    pub fn passes(&self, level: ContrastLevel) -> bool {
+        match level {
+            ContrastLevel::Aa => self.ratio >= 4.5,
+            ContrastLevel::Aaa => self.ratio >= 7.0,
+            ContrastLevel::Apca => self.apca.abs() >= 60.0,
+        }
+    }
+}
+
+/// Contrast of every pair in [`CONTRAST_PAIRS`] for one palette.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastReport {
+    pub checks: Vec<ContrastCheck>,
+}
+
+impl ContrastReport {
+    /// Check every pair in a palette.
+    pub fn for_palette(colors: &PaletteColors) -> Self {
+        let checks = CONTRAST_PAIRS
+            .iter()
+            .filter_map(|&(foreground, background)| {
+                let fg = colors.get(foreground)?;
+                let bg = colors.get(background)?;
+                Some(ContrastCheck {
+                    foreground,
+                    background,
+                    ratio: contrast_ratio(fg, bg),
+                    apca: apca_contrast(fg, bg),
+                })
+            })
+            .collect();
+        Self { checks }
+    }
+
+    /// Pairs that do not reach `level`.
+    pub fn failures(&self, level: ContrastLevel) -> impl Iterator<Item = &ContrastCheck> {
+        self.checks.iter().filter(move |check| !check.passes(level))
+    }
+
+    /// Whether every pair reaches `level`.
+    pub fn passes(&self, level: ContrastLevel) -> bool {
+        self.failures(level).next().is_none()
+    }
+}
+
+/// Check every foreground/background pair of a theme.
+#[cfg(feature = "gpui")]
+pub fn contrast_report(theme: &crate::Theme) -> ContrastReport {
+    ContrastReport::for_palette(&PaletteColors::from(&theme.colors))
+}
+
+/// WCAG 2.1 contrast ratio between two colors, from 1 to 21.
+///
+/// A translucent foreground is composited over the background first; the
+/// background is treated as opaque.
+pub fn contrast_ratio(foreground: impl Into<Hsl>, background: impl Into<Hsl>) -> f32 {
+    let (foreground, background) = (foreground.into(), background.into());
+    let bg = background.to_rgb();
+    let fg = blend(foreground.to_rgb(), foreground.a, bg);
+    let (a, b) = (luminance(fg), luminance(bg));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+/// APCA lightness contrast (Lc) of text on a background, following
+/// APCA-W3 0.0.98G. Roughly -108 to 106; 0 means no contrast.
+pub fn apca_contrast(foreground: impl Into<Hsl>, background: impl Into<Hsl>) -> f32 {
+    let (foreground, background) = (foreground.into(), background.into());
+    let bg = background.to_rgb();
+    let fg = blend(foreground.to_rgb(), foreground.a, bg);
+    let (text, back) = (apca_luminance(fg), apca_luminance(bg));
+    if (back - text).abs() < 0.0005 {
+        return 0.0;
+    }
+    let contrast = if back > text {
+        // Dark text on a light background
+        let s = (back.powf(0.56) - text.powf(0.57)) * 1.14;
+        if s < 0.1 { 0.0 } else { s - 0.027 }
+    } else {
+        // Light text on a dark background
+        let s = (back.powf(0.65) - text.powf(0.62)) * 1.14;
+        if s > -0.1 { 0.0 } else { s + 0.027 }
+    };
+    contrast * 100.0
+}
+
+fn blend(fg: [f32; 3], alpha: f32, bg: [f32; 3]) -> [f32; 3] {
+    let alpha = alpha.clamp(0.0, 1.0);
+    [0, 1, 2].map(|i| fg[i] * alpha + bg[i] * (1.0 - alpha))
+}
+
+/// APCA screen luminance, with the soft clamp for near-black colors.
+fn apca_luminance([r, g, b]: [f32; 3]) -> f32 {
+    let y = 0.212_672_9 * r.powf(2.4) + 0.715_152_2 * g.powf(2.4) + 0.072_175 * b.powf(2.4);
+    if y < 0.022 {
+        y + (0.022 - y).powf(1.414)
+    } else {
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PRESETS, PaletteVariant};
+
+    const BLACK: Hsl = Hsl::new(0.0, 0.0, 0.0);
+    const WHITE: Hsl = Hsl::new(0.0, 0.0, 100.0);
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        assert!((contrast_ratio(BLACK, WHITE) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(WHITE, WHITE) - 1.0).abs() < 0.01);
+        // #767676 on white is the classic 4.54:1 AA boundary
+        let gray = Hsl::new(0.0, 0.0, 46.27);
+        assert!((contrast_ratio(gray, WHITE) - 4.54).abs() < 0.02);
+        // Fully transparent text has no contrast
+        assert!((contrast_ratio(BLACK.with_alpha(0.0), WHITE) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_apca_polarity() {
+        // Reference values from the APCA-W3 test suite
+        assert!((apca_contrast(BLACK, WHITE) - 106.04).abs() < 0.1);
+        assert!((apca_contrast(WHITE, BLACK) + 107.88).abs() < 0.1);
+        assert_eq!(apca_contrast(WHITE, WHITE), 0.0);
+    }
+
+    #[test]
+    fn test_presets_meet_aa() {
+        // Every pair of every preset and accessibility variant, as checked by
+        // `shadcn-ui theme check`
+        for preset in PRESETS {
+            let variants = PaletteVariant::ALL.map(|variant| preset.with_variant(variant));
+            for palette in std::iter::once(preset).chain(&variants) {
+                for (mode, colors) in [("light", &palette.light), ("dark", &palette.dark)] {
+                    let report = ContrastReport::for_palette(colors);
+                    assert_eq!(report.checks.len(), CONTRAST_PAIRS.len());
+                    let failures: Vec<_> = report.failures(ContrastLevel::Aa).collect();
+                    assert!(failures.is_empty(), "{} {mode}: {failures:?}", preset.name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_preset_status_pairs_meet_aa() {
+        for preset in PRESETS {
+            for (mode, colors) in [("light", &preset.light), ("dark", &preset.dark)] {
+                let report = ContrastReport::for_palette(colors);
+                for status in ["success", "warning", "info"] {
+                    let check = report
+                        .checks
+                        .iter()
+                        .find(|check| check.background == status)
+                        .unwrap();
+                    assert!(
+                        check.passes(ContrastLevel::Aa),
+                        "{} {mode}: {check:?}",
+                        preset.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_unreadable_pair_fails() {
+        let mut colors = PRESETS[0].light;
+        colors.muted_foreground = Hsl::new(240.0, 4.8, 80.0);
+        let report = ContrastReport::for_palette(&colors);
+        let failures: Vec<_> = report.failures(ContrastLevel::Aa).collect();
+        assert!(
+            failures
+                .iter()
+                .any(|check| check.foreground == "muted_foreground" && check.background == "muted")
+        );
+        assert!(!report.passes(ContrastLevel::Apca));
+    }
+}
@@ -0,0 +1,164 @@
+//! Differences between two palettes
+//!
+//! [`PaletteColors::diff`] lists the tokens whose colors differ and how the
+//! contrast of each affected text pair changes; [`crate::Theme::diff`] does
+//! the same for two themes, whether built from presets or loaded from theme
+//! files. Colors are compared at 8-bit RGBA precision, so the same color
+//! written as hex in one theme and as HSL in another is not a difference.
+
+use crate::{ContrastCheck, ContrastReport, Hsl, PaletteColors, to_hex};
+
+/// A token whose color differs between two palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenDiff {
+    pub token: &'static str,
+    pub before: Hsl,
+    pub after: Hsl,
+}
+
+/// The contrast of one text pair in both palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastDelta {
+    pub before: ContrastCheck,
+    pub after: ContrastCheck,
+}
+
+impl ContrastDelta {
+    /// Change in WCAG contrast ratio; positive when contrast increased.
+    pub fn ratio(&self) -> f32 {
+        self.after.ratio - self.before.ratio
+    }
+
+    /// Change in absolute APCA Lc; positive when contrast increased.
+    pub fn apca(&self) -> f32 {
+        self.after.apca.abs() - self.before.apca.abs()
+    }
+}
+
+/// How one palette differs from another.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PaletteDiff {
+    /// Tokens that differ, in declaration order.
+    pub tokens: Vec<TokenDiff>,
+    /// Pairs from [`crate::CONTRAST_PAIRS`] whose foreground or background
+    /// differs.
+    pub contrast: Vec<ContrastDelta>,
+}
+
+impl PaletteDiff {
+    /// Whether the palettes render the same.
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    /// Whether `token` differs.
+    pub fn changed(&self, token: &str) -> bool {
+        self.tokens.iter().any(|diff| diff.token == token)
+    }
+}
+
+impl PaletteColors {
+    /// How `other` differs from this palette.
+    pub fn diff(&self, other: &PaletteColors) -> PaletteDiff {
+        let tokens: Vec<TokenDiff> = self
+            .tokens()
+            .into_iter()
+            .filter_map(|(token, before)| {
+                let after = other.get(token)?;
+                (to_hex(before) != to_hex(after)).then_some(TokenDiff {
+                    token,
+                    before,
+                    after,
+                })
+            })
+            .collect();
+
+        let before = ContrastReport::for_palette(self);
+        let after = ContrastReport::for_palette(other);
+        let mut diff = PaletteDiff {
+            tokens,
+            contrast: Vec::new(),
+        };
+        diff.contrast = before
+            .checks
+            .into_iter()
+            .zip(after.checks)
+            .filter(|(check, _)| diff.changed(check.foreground) || diff.changed(check.background))
+            .map(|(before, after)| ContrastDelta { before, after })
+            .collect();
+        diff
+    }
+}
+
+#[cfg(feature = "gpui")]
+impl crate::Theme {
+    /// How `other`'s colors differ from this theme's. Only the palettes are
+    /// compared, so diff two themes in the same mode.
+    pub fn diff(&self, other: &crate::Theme) -> PaletteDiff {
+        PaletteColors::from(&self.colors).diff(&PaletteColors::from(&other.colors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{SLATE, ZINC};
+
+    #[test]
+    fn test_identical_palettes_have_no_diff() {
+        let diff = ZINC.light.diff(&ZINC.light);
+        assert!(diff.is_empty());
+        assert!(diff.contrast.is_empty());
+    }
+
+    #[test]
+    fn test_diff_lists_changed_tokens_and_their_pairs() {
+        let mut other = ZINC.light;
+        other.primary = "#7c3aed".parse().unwrap();
+        // The same color in another syntax is not a change
+        other.background = "#ffffff".parse().unwrap();
+
+        let diff = ZINC.light.diff(&other);
+        assert_eq!(diff.tokens.len(), 1);
+        assert_eq!(diff.tokens[0].token, "primary");
+        assert_eq!(diff.tokens[0].before, ZINC.light.primary);
+        assert_eq!(diff.tokens[0].after, other.primary);
+
+        assert_eq!(diff.contrast.len(), 1);
+        let delta = diff.contrast[0];
+        assert_eq!(
+            (delta.after.foreground, delta.after.background),
+            ("primary_foreground", "primary")
+        );
+        // Violet is lighter than zinc's near-black primary
+        assert!(delta.ratio() < 0.0);
+        assert!(delta.apca() < 0.0);
+    }
+
+    #[test]
+    fn test_preset_diff_is_symmetric() {
+        let forward = ZINC.dark.diff(&SLATE.dark);
+        let backward = SLATE.dark.diff(&ZINC.dark);
+        assert!(!forward.is_empty());
+        assert_eq!(forward.tokens.len(), backward.tokens.len());
+        assert_eq!(forward.contrast.len(), backward.contrast.len());
+    }
+
+    #[cfg(feature = "gpui")]
+    #[test]
+    fn test_theme_diff_against_theme_file() {
+        use crate::{Theme, ThemeMode, zinc};
+
+        let custom = Theme::from_toml(
+            "[meta]\nname = \"acme\"\nextends = \"zinc\"\n\n[dark]\nring = \"#7c3aed\"\n",
+            ThemeMode::Dark,
+        )
+        .unwrap();
+        assert!(zinc(ThemeMode::Light).diff(&custom).changed("background"));
+
+        let diff = zinc(ThemeMode::Dark).diff(&custom);
+        let tokens: Vec<_> = diff.tokens.iter().map(|d| d.token).collect();
+        assert_eq!(tokens, ["ring"]);
+        // The ring is not a text color
+        assert!(diff.contrast.is_empty());
+    }
+}
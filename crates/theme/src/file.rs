@@ -0,0 +1,663 @@
+//! Theme files: both modes of a theme in a serializable form
+//!
+//! Uses the schema that `shadcn-ui theme create` writes to `themes/*.toml`:
+//! a `[meta]` table plus `[light]` and `[dark]` tables of color strings such
+//! as `"hsl(240, 5.9%, 10%)"`; see [`crate::parse_color`] for the accepted
+//! syntax. JSON uses the same structure.
+//!
+//! With `extends` in `[meta]`, the color tables only list overridden tokens;
+//! the rest, and the radius and density, come from the extended theme.
+//! [`ThemeFile::from_toml`] resolves `extends` against the presets, and
+//! [`crate::ThemeRegistry::load_toml`] against the registered themes.
+//!
+//! Optional `[components.light]` and `[components.dark]` tables set
+//! per-component colors by slot, e.g. `"button.destructive.bg" = "#b91c1c"`;
+//! see [`crate::ComponentOverrides`]. They are inherited through `extends`
+//! slot by slot.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use gpui::Hsla;
+
+use crate::{
+    ComponentOverrides, Density, PaletteColors, Radius, Theme, ThemeColors, ThemeMode, get_preset,
+};
+
+/// Error returned when loading, saving or switching themes.
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    #[error("invalid theme TOML: {0}")]
+    ParseToml(#[from] toml::de::Error),
+    #[error("failed to write theme TOML: {0}")]
+    WriteToml(#[from] toml::ser::Error),
+    #[error("invalid theme JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to read {}: {source}", .path.display())]
+    Read {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    #[error("no theme named `{0}` is registered")]
+    UnknownTheme(String),
+    #[error("unknown color token `{token}` in [{mode}]")]
+    UnknownToken { mode: &'static str, token: String },
+    #[error("[{mode}] does not set {} and the theme has no `extends`", .tokens.join(", "))]
+    MissingTokens {
+        mode: &'static str,
+        tokens: Vec<&'static str>,
+    },
+}
+
+/// A theme with its light and dark palettes, as stored in `themes/*.toml`.
+///
+/// Always holds complete palettes; `extends` is resolved when parsing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawThemeFile")]
+pub struct ThemeFile {
+    pub meta: ThemeMeta,
+    pub light: ThemeColors,
+    pub dark: ThemeColors,
+    #[serde(default, skip_serializing_if = "ComponentTables::is_empty")]
+    pub components: ComponentTables,
+}
+
+/// The `[components.light]` and `[components.dark]` tables of a theme file.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComponentTables {
+    #[serde(
+        default,
+        deserialize_with = "color_table::components_light",
+        skip_serializing_if = "ComponentOverrides::is_empty"
+    )]
+    pub light: ComponentOverrides,
+    #[serde(
+        default,
+        deserialize_with = "color_table::components_dark",
+        skip_serializing_if = "ComponentOverrides::is_empty"
+    )]
+    pub dark: ComponentOverrides,
+}
+
+impl ComponentTables {
+    pub fn is_empty(&self) -> bool {
+        self.light.is_empty() && self.dark.is_empty()
+    }
+}
+
+/// The `[meta]` table of a theme file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeMeta {
+    pub name: String,
+    /// Theme this one extends. `base`, written by older versions of the CLI,
+    /// is accepted as an alias.
+    #[serde(default, alias = "base", skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+    #[serde(default)]
+    pub radius: Radius,
+    #[serde(default)]
+    pub density: Density,
+}
+
+/// A theme file as written, before `extends` is resolved.
+#[derive(Deserialize)]
+pub(crate) struct RawThemeFile {
+    meta: RawThemeMeta,
+    #[serde(default, deserialize_with = "color_table::light")]
+    light: BTreeMap<String, Hsla>,
+    #[serde(default, deserialize_with = "color_table::dark")]
+    dark: BTreeMap<String, Hsla>,
+    #[serde(default)]
+    components: ComponentTables,
+}
+
+#[derive(Deserialize)]
+struct RawThemeMeta {
+    name: String,
+    #[serde(default, alias = "base")]
+    extends: Option<String>,
+    radius: Option<Radius>,
+    density: Option<Density>,
+}
+
+impl RawThemeFile {
+    /// Fill in unset tokens, radius and density from the extended theme,
+    /// looked up through `lookup`.
+    pub(crate) fn resolve(
+        self,
+        lookup: impl FnOnce(&str) -> Option<ThemeFile>,
+    ) -> Result<ThemeFile, ThemeError> {
+        let parent = match &self.meta.extends {
+            Some(name) => Some(lookup(name).ok_or_else(|| ThemeError::UnknownTheme(name.clone()))?),
+            None => None,
+        };
+        let light = resolve_colors(&self.light, "light", parent.as_ref().map(|p| &p.light))?;
+        let dark = resolve_colors(&self.dark, "dark", parent.as_ref().map(|p| &p.dark))?;
+        let meta = ThemeMeta {
+            name: self.meta.name,
+            extends: self.meta.extends,
+            radius: self
+                .meta
+                .radius
+                .or(parent.as_ref().map(|p| p.meta.radius))
+                .unwrap_or_default(),
+            density: self
+                .meta
+                .density
+                .or(parent.as_ref().map(|p| p.meta.density))
+                .unwrap_or_default(),
+        };
+        let mut components = self.components;
+        if let Some(parent) = &parent {
+            components.light.inherit(&parent.components.light);
+            components.dark.inherit(&parent.components.dark);
+        }
+        Ok(ThemeFile {
+            meta,
+            light,
+            dark,
+            components,
+        })
+    }
+}
+
+impl TryFrom<RawThemeFile> for ThemeFile {
+    type Error = ThemeError;
+
+    fn try_from(raw: RawThemeFile) -> Result<Self, ThemeError> {
+        raw.resolve(ThemeFile::from_preset)
+    }
+}
+
+/// Check one `[light]` or `[dark]` table, taking unset tokens from `parent`.
+fn resolve_colors(
+    table: &BTreeMap<String, Hsla>,
+    mode: &'static str,
+    parent: Option<&ThemeColors>,
+) -> Result<ThemeColors, ThemeError> {
+    if let Some(token) = table
+        .keys()
+        .find(|key| !PaletteColors::TOKENS.contains(&key.as_str()))
+    {
+        return Err(ThemeError::UnknownToken {
+            mode,
+            token: token.clone(),
+        });
+    }
+    let lookup = |token: &str| table.get(token).copied().or_else(|| parent?.get(token));
+    ThemeColors::try_from_fn(|token| lookup(token).ok_or(())).map_err(|()| {
+        ThemeError::MissingTokens {
+            mode,
+            tokens: PaletteColors::TOKENS
+                .iter()
+                .copied()
+                .filter(|token| lookup(token).is_none())
+                .collect(),
+        }
+    })
+}
+
+impl ThemeFile {
+    /// Parse a theme file from TOML.
+    pub fn from_toml(source: &str) -> Result<Self, ThemeError> {
+        Ok(toml::from_str(source)?)
+    }
+
+    /// Parse a theme file from JSON.
+    pub fn from_json(source: &str) -> Result<Self, ThemeError> {
+        Ok(serde_json::from_str(source)?)
+    }
+
+    /// Serialize the theme file as TOML.
+    pub fn to_toml(&self) -> Result<String, ThemeError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// Serialize the theme file as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, ThemeError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Build a theme file from a preset, e.g. to use as a starting point.
+    pub fn from_preset(name: &str) -> Option<Self> {
+        let light = get_preset(name, ThemeMode::Light)?;
+        let dark = get_preset(name, ThemeMode::Dark)?;
+        Some(Self {
+            meta: ThemeMeta {
+                name: light.name,
+                extends: Some(name.to_string()),
+                radius: light.radius,
+                density: light.density,
+            },
+            light: light.colors,
+            dark: dark.colors,
+            components: ComponentTables::default(),
+        })
+    }
+
+    /// The theme for one mode. [`ThemeMode::System`] gives the light palette.
+    pub fn theme(&self, mode: ThemeMode) -> Theme {
+        let (colors, overrides) = match mode {
+            ThemeMode::Light | ThemeMode::System => (&self.light, &self.components.light),
+            ThemeMode::Dark => (&self.dark, &self.components.dark),
+        };
+        let mut theme = Theme::new(
+            self.meta.name.clone(),
+            mode,
+            colors.clone(),
+            self.meta.radius,
+        )
+        .with_density(self.meta.density);
+        theme.overrides = overrides.clone();
+        theme
+    }
+}
+
+impl Theme {
+    /// Load one mode of a theme from a TOML theme file.
+    pub fn from_toml(source: &str, mode: ThemeMode) -> Result<Self, ThemeError> {
+        Ok(ThemeFile::from_toml(source)?.theme(mode))
+    }
+
+    /// Load one mode of a theme from a JSON theme file.
+    pub fn from_json(source: &str, mode: ThemeMode) -> Result<Self, ThemeError> {
+        Ok(ThemeFile::from_json(source)?.theme(mode))
+    }
+
+    /// Serialize this theme as a TOML theme file, with `other` as the palette
+    /// for the opposite mode. Component overrides are written for this mode
+    /// only.
+    pub fn to_toml(&self, other: &ThemeColors) -> Result<String, ThemeError> {
+        self.to_file(other).to_toml()
+    }
+
+    /// Serialize this theme as a JSON theme file, with `other` as the palette
+    /// for the opposite mode.
+    pub fn to_json(&self, other: &ThemeColors) -> Result<String, ThemeError> {
+        self.to_file(other).to_json()
+    }
+
+    fn to_file(&self, other: &ThemeColors) -> ThemeFile {
+        let mut components = ComponentTables::default();
+        let (light, dark) = match self.mode {
+            ThemeMode::Light | ThemeMode::System => {
+                components.light = self.overrides.clone();
+                (self.colors.clone(), other.clone())
+            }
+            ThemeMode::Dark => {
+                components.dark = self.overrides.clone();
+                (other.clone(), self.colors.clone())
+            }
+        };
+        ThemeFile {
+            meta: ThemeMeta {
+                name: self.name.clone(),
+                extends: None,
+                radius: self.radius,
+                density: self.density,
+            },
+            light,
+            dark,
+            components,
+        }
+    }
+}
+
+/// Deserializers for the color tables of a theme file.
+///
+/// Colors are parsed while the table is read, so that TOML and JSON errors
+/// point at the offending value and name its token.
+pub(crate) mod color_table {
+    use std::collections::BTreeMap;
+    use std::fmt;
+
+    use gpui::Hsla;
+    use serde::Deserializer;
+    use serde::de::{self, DeserializeSeed, MapAccess, Visitor};
+
+    use crate::{ComponentOverrides, parse_color};
+
+    pub fn light<'de, D: Deserializer<'de>>(d: D) -> Result<BTreeMap<String, Hsla>, D::Error> {
+        d.deserialize_map(Table { mode: "light" })
+    }
+
+    pub fn dark<'de, D: Deserializer<'de>>(d: D) -> Result<BTreeMap<String, Hsla>, D::Error> {
+        d.deserialize_map(Table { mode: "dark" })
+    }
+
+    pub fn components_light<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<ComponentOverrides, D::Error> {
+        overrides_in(d, "components.light")
+    }
+
+    pub fn components_dark<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<ComponentOverrides, D::Error> {
+        overrides_in(d, "components.dark")
+    }
+
+    /// Component overrides outside a theme file, e.g. in a serialized `Theme`.
+    pub fn overrides<'de, D: Deserializer<'de>>(d: D) -> Result<BTreeMap<String, Hsla>, D::Error> {
+        d.deserialize_map(Table { mode: "overrides" })
+    }
+
+    fn overrides_in<'de, D: Deserializer<'de>>(
+        d: D,
+        mode: &'static str,
+    ) -> Result<ComponentOverrides, D::Error> {
+        d.deserialize_map(Table { mode })
+            .map(ComponentOverrides::from_map)
+    }
+
+    struct Table {
+        mode: &'static str,
+    }
+
+    impl<'de> Visitor<'de> for Table {
+        type Value = BTreeMap<String, Hsla>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a table of colors")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let mut colors = BTreeMap::new();
+            while let Some(token) = map.next_key::<String>()? {
+                let color = map.next_value_seed(Color {
+                    mode: self.mode,
+                    token: &token,
+                })?;
+                colors.insert(token, color);
+            }
+            Ok(colors)
+        }
+    }
+
+    struct Color<'a> {
+        mode: &'static str,
+        token: &'a str,
+    }
+
+    impl<'de> DeserializeSeed<'de> for Color<'_> {
+        type Value = Hsla;
+
+        fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<Hsla, D::Error> {
+            d.deserialize_str(self)
+        }
+    }
+
+    impl Visitor<'_> for Color<'_> {
+        type Value = Hsla;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a color string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Hsla, E> {
+            parse_color(value)
+                .map_err(|err| E::custom(format!("`{}` in [{}]: {}", self.token, self.mode, err)))
+        }
+    }
+}
+
+/// Serde adapter that stores an `Hsla` as an HSL string. Reading accepts any
+/// color syntax.
+pub(crate) mod hsl_string {
+    use gpui::Hsla;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::{parse_color, to_hsl_string};
+
+    pub fn serialize<S: Serializer>(color: &Hsla, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_hsl_string(*color))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hsla, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        parse_color(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hsl, preset_names};
+
+    const CUSTOM: &str = r#"
+[meta]
+name = "acme"
+base = "zinc"
+
+[light]
+background = "hsl(0, 0%, 100%)"
+foreground = "hsl(240, 10%, 3.9%)"
+card = "hsl(0, 0%, 100%)"
+card_foreground = "hsl(240, 10%, 3.9%)"
+popover = "hsl(0, 0%, 100%)"
+popover_foreground = "hsl(240, 10%, 3.9%)"
+primary = "hsl(262, 83%, 58%)"
+primary_foreground = "hsl(0, 0%, 98%)"
+secondary = "hsl(240, 4.8%, 95.9%)"
+secondary_foreground = "hsl(240, 5.9%, 10%)"
+muted = "hsl(240, 4.8%, 95.9%)"
+muted_foreground = "hsl(240, 3.8%, 46.1%)"
+accent = "hsl(240, 4.8%, 95.9%)"
+accent_foreground = "hsl(240, 5.9%, 10%)"
+destructive = "hsl(0, 84.2%, 60.2%)"
+destructive_foreground = "hsl(0, 0%, 98%)"
+border = "hsl(240, 5.9%, 90%)"
+input = "hsl(240, 5.9%, 90%)"
+ring = "hsl(262, 83%, 58%)"
+chart_1 = "hsl(12, 76%, 61%)"
+chart_2 = "hsl(173, 58%, 39%)"
+chart_3 = "hsl(197, 37%, 24%)"
+chart_4 = "hsl(43, 74%, 66%)"
+chart_5 = "hsl(27, 87%, 67%)"
+sidebar = "hsl(240, 4.8%, 98%)"
+sidebar_foreground = "hsl(240, 5.9%, 26.1%)"
+sidebar_primary = "hsl(240, 5.9%, 10%)"
+sidebar_primary_foreground = "hsl(0, 0%, 98%)"
+sidebar_accent = "hsl(240, 4.8%, 95.9%)"
+sidebar_accent_foreground = "hsl(240, 5.9%, 10%)"
+sidebar_border = "hsl(240, 5.9%, 90%)"
+sidebar_ring = "hsl(217.2, 91.2%, 59.8%)"
+success = "hsl(142.4, 71.8%, 29.2%)"
+success_foreground = "hsl(0, 0%, 98%)"
+warning = "hsl(37.7, 92.1%, 50.2%)"
+warning_foreground = "hsl(26, 83.3%, 14.1%)"
+info = "hsl(221.2, 83.2%, 53.3%)"
+info_foreground = "hsl(0, 0%, 98%)"
+overlay = "hsla(0, 0%, 0%, 0.8)"
+selection = "hsla(217.2, 91.2%, 59.8%, 0.3)"
+
+[dark]
+background = "hsl(240, 10%, 3.9%)"
+foreground = "hsl(0, 0%, 98%)"
+card = "hsl(240, 10%, 3.9%)"
+card_foreground = "hsl(0, 0%, 98%)"
+popover = "hsl(240, 10%, 3.9%)"
+popover_foreground = "hsl(0, 0%, 98%)"
+primary = "hsl(263, 70%, 50%)"
+primary_foreground = "hsl(0, 0%, 98%)"
+secondary = "hsl(240, 3.7%, 15.9%)"
+secondary_foreground = "hsl(0, 0%, 98%)"
+muted = "hsl(240, 3.7%, 15.9%)"
+muted_foreground = "hsl(240, 5%, 64.9%)"
+accent = "hsl(240, 3.7%, 15.9%)"
+accent_foreground = "hsl(0, 0%, 98%)"
+destructive = "hsl(0, 62.8%, 30.6%)"
+destructive_foreground = "hsl(0, 0%, 98%)"
+border = "hsl(240, 3.7%, 15.9%)"
+input = "hsl(240, 3.7%, 15.9%)"
+ring = "hsla(263, 70%, 50%, 0.5)"
+chart_1 = "hsl(220, 70%, 50%)"
+chart_2 = "hsl(160, 60%, 45%)"
+chart_3 = "hsl(30, 80%, 55%)"
+chart_4 = "hsl(280, 65%, 60%)"
+chart_5 = "hsl(340, 75%, 55%)"
+sidebar = "hsl(240, 5.9%, 10%)"
+sidebar_foreground = "hsl(240, 4.8%, 95.9%)"
+sidebar_primary = "hsl(224.3, 76.3%, 48%)"
+sidebar_primary_foreground = "hsl(0, 0%, 100%)"
+sidebar_accent = "hsl(240, 3.7%, 15.9%)"
+sidebar_accent_foreground = "hsl(240, 4.8%, 95.9%)"
+sidebar_border = "hsl(240, 3.7%, 15.9%)"
+sidebar_ring = "hsl(217.2, 91.2%, 59.8%)"
+success = "hsl(142.1, 70.6%, 45.3%)"
+success_foreground = "hsl(144.9, 80.4%, 10%)"
+warning = "hsl(48, 96%, 53.1%)"
+warning_foreground = "hsl(26, 83.3%, 14.1%)"
+info = "hsl(217.2, 91.2%, 59.8%)"
+info_foreground = "hsl(222.2, 47.4%, 11.2%)"
+overlay = "hsla(0, 0%, 0%, 0.8)"
+selection = "hsla(217.2, 91.2%, 59.8%, 0.4)"
+"#;
+
+    #[test]
+    fn test_from_toml_custom_theme() {
+        let theme = Theme::from_toml(CUSTOM, ThemeMode::Light).unwrap();
+        assert_eq!(theme.name, "acme");
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.radius, Radius::Md);
+        assert_eq!(theme.colors.primary, hsl(262.0, 83.0, 58.0));
+
+        let dark = Theme::from_toml(CUSTOM, ThemeMode::Dark).unwrap();
+        assert_eq!(dark.colors.primary, hsl(263.0, 70.0, 50.0));
+        assert_eq!(dark.colors.ring.a, 0.5);
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        for name in preset_names() {
+            let file = ThemeFile::from_preset(name).unwrap();
+            let parsed = ThemeFile::from_toml(&file.to_toml().unwrap()).unwrap();
+            assert_eq!(parsed, file, "{name} does not round-trip");
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let file = ThemeFile::from_toml(CUSTOM).unwrap();
+        let parsed = ThemeFile::from_json(&file.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, file);
+    }
+
+    #[test]
+    fn test_theme_to_toml_keeps_modes() {
+        let light = get_preset("stone", ThemeMode::Light).unwrap();
+        let dark = get_preset("stone", ThemeMode::Dark).unwrap();
+        let file = ThemeFile::from_toml(&dark.to_toml(&light.colors).unwrap()).unwrap();
+        assert_eq!(file.meta.name, "stone");
+        assert_eq!(file.meta.radius, Radius::Lg);
+        assert_eq!(file.light, light.colors);
+        assert_eq!(file.dark, dark.colors);
+    }
+
+    #[test]
+    fn test_invalid_color_is_rejected() {
+        let broken = CUSTOM.replace("hsl(262, 83%, 58%)", "purple");
+        let err = ThemeFile::from_toml(&broken).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("invalid color `purple`"));
+        assert!(message.contains("`primary` in [light]"));
+        assert!(message.contains("line 13, column 11"));
+    }
+
+    #[test]
+    fn test_css_color_syntaxes() {
+        let file = ThemeFile::from_toml(
+            r##"
+[meta]
+name = "css"
+extends = "zinc"
+
+[light]
+primary = "#7c3aed"
+secondary = "rgb(124 58 237 / 50%)"
+accent = "hsl(262 83% 58%)"
+ring = "oklch(0.628 0.2577 29.23)"
+"##,
+        )
+        .unwrap();
+        assert!((file.light.primary.h * 360.0 - 262.1).abs() < 0.5);
+        assert_eq!(file.light.secondary.a, 0.5);
+        assert_eq!(file.light.accent, hsl(262.0, 83.0, 58.0));
+        assert!(file.light.ring.s > 0.99 && (file.light.ring.l - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_extends_fills_unset_tokens() {
+        let partial = r#"
+[meta]
+name = "brand"
+extends = "stone"
+density = "compact"
+
+[dark]
+primary = "hsl(263, 70%, 50%)"
+"#;
+        let file = ThemeFile::from_toml(partial).unwrap();
+        let stone = ThemeFile::from_preset("stone").unwrap();
+        assert_eq!(file.meta.extends.as_deref(), Some("stone"));
+        assert_eq!(file.meta.radius, stone.meta.radius);
+        assert_eq!(file.meta.density, Density::Compact);
+        assert_eq!(file.light, stone.light);
+        assert_eq!(file.dark.primary, hsl(263.0, 70.0, 50.0));
+        assert_eq!(file.dark.background, stone.dark.background);
+    }
+
+    #[test]
+    fn test_partial_file_needs_extends() {
+        let partial = "[meta]\nname = \"brand\"\n\n[light]\nprimary = \"hsl(0, 0%, 0%)\"\n";
+        let err = ThemeFile::from_toml(partial).unwrap_err();
+        assert!(err.to_string().contains("[light] does not set background"));
+
+        let unknown = CUSTOM.replace("primary =", "primry =");
+        let err = ThemeFile::from_toml(&unknown).unwrap_err();
+        assert!(err.to_string().contains("unknown color token `primry`"));
+
+        let missing = CUSTOM.replace("base = \"zinc\"", "extends = \"lavender\"");
+        let err = ThemeFile::from_toml(&missing).unwrap_err();
+        assert!(err.to_string().contains("no theme named `lavender`"));
+    }
+
+    #[test]
+    fn test_component_overrides() {
+        let source = r##"
+[meta]
+name = "brand"
+extends = "zinc"
+
+[components.light]
+"button.destructive.bg" = "#b91c1c"
+"card.border" = "hsl(240, 5.9%, 10%)"
+
+[components.dark]
+"button.destructive.bg" = "hsl(0 72% 51%)"
+"##;
+        let file = ThemeFile::from_toml(source).unwrap();
+        let light = file.theme(ThemeMode::Light);
+        assert_eq!(
+            light.overrides.get("card.border"),
+            Some(hsl(240.0, 5.9, 10.0))
+        );
+        let dark = file.theme(ThemeMode::Dark);
+        assert_eq!(
+            dark.slot("button.destructive.bg", dark.colors.destructive),
+            hsl(0.0, 72.0, 51.0)
+        );
+        assert_eq!(dark.overrides.get("card.border"), None);
+
+        let parsed = ThemeFile::from_json(&file.to_json().unwrap()).unwrap();
+        assert_eq!(parsed, file);
+
+        let broken = source.replace("#b91c1c", "crimson");
+        let err = ThemeFile::from_toml(&broken).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`button.destructive.bg` in [components.light]")
+        );
+    }
+}
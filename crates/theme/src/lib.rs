@@ -1,26 +1,98 @@
 //! Theme system for shadcn-ui-rs
 //!
 //! Provides runtime-switchable themes with compile-time optimization options.
+//!
+//! The `gpui` feature (on by default) provides [`Theme`] and the preset
+//! constructors. Without it, the crate only exposes the plain preset data in
+//! [`PaletteColors`] and [`PRESETS`], for tools such as the CLI. The
+//! `assets` feature (also on by default) adds embedded fonts and icons; see
+//! [`register_fonts`] and [`Assets`].
 
-use gpui::Hsla;
+#[cfg(feature = "gpui")]
+use gpui::{Global, Hsla, WindowAppearance};
 use serde::{Deserialize, Serialize};
 
+mod accessibility;
+#[cfg(feature = "assets")]
+mod assets;
+mod brand;
 mod colors;
+mod contrast;
+mod diff;
+#[cfg(feature = "gpui")]
+mod file;
+#[cfg(feature = "gpui")]
+mod overrides;
+mod palette;
+mod parse;
+#[cfg(feature = "gpui")]
 mod presets;
+#[cfg(feature = "gpui")]
+mod registry;
+mod scale;
+#[cfg(feature = "gpui")]
+mod scope;
+#[cfg(feature = "gpui")]
+mod watch;
 
+pub use accessibility::*;
+#[cfg(feature = "assets")]
+pub use assets::*;
 pub use colors::*;
+pub use contrast::*;
+pub use diff::*;
+#[cfg(feature = "gpui")]
+pub use file::{ComponentTables, ThemeError, ThemeFile, ThemeMeta};
+#[cfg(feature = "gpui")]
+pub use overrides::ComponentOverrides;
+pub use palette::*;
+pub use parse::*;
+#[cfg(feature = "gpui")]
 pub use presets::*;
+#[cfg(feature = "gpui")]
+pub use registry::{ThemeRegistry, ThemeSwitch};
+pub use scale::*;
+#[cfg(feature = "gpui")]
+pub use scope::ThemeScope;
+#[cfg(feature = "gpui")]
+pub use watch::ThemeWatcher;
 
-/// Theme mode (light or dark)
+/// Theme mode (light, dark, or following the system)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ThemeMode {
     #[default]
     Light,
     Dark,
+    /// Follow the OS appearance. Palettes treat it as light, so resolve it
+    /// with [`ThemeMode::resolve`] before building a theme.
+    System,
+}
+
+impl ThemeMode {
+    /// Resolve [`ThemeMode::System`] to light or dark; other modes are
+    /// returned unchanged.
+    pub fn resolve_with(self, system_is_dark: bool) -> ThemeMode {
+        match self {
+            ThemeMode::System if system_is_dark => ThemeMode::Dark,
+            ThemeMode::System => ThemeMode::Light,
+            mode => mode,
+        }
+    }
+
+    /// Resolve [`ThemeMode::System`] against a window appearance.
+    #[cfg(feature = "gpui")]
+    pub fn resolve(self, appearance: WindowAppearance) -> ThemeMode {
+        self.resolve_with(matches!(
+            appearance,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark
+        ))
+    }
 }
 
 /// Border radius presets
 #[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Radius {
     None,
     Sm,
@@ -31,6 +103,31 @@ pub enum Radius {
 }
 
 impl Radius {
+    /// All radius presets, smallest first.
+    pub const ALL: [Radius; 5] = [
+        Radius::None,
+        Radius::Sm,
+        Radius::Md,
+        Radius::Lg,
+        Radius::Full,
+    ];
+
+    /// Look up a radius by its lowercase name, as used in config files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|r| r.name() == name)
+    }
+
+    /// Lowercase name, e.g. `"md"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Radius::None => "none",
+            Radius::Sm => "sm",
+            Radius::Md => "md",
+            Radius::Lg => "lg",
+            Radius::Full => "full",
+        }
+    }
+
     pub fn to_px(self) -> f32 {
         match self {
             Radius::None => 0.0,
@@ -43,38 +140,259 @@ impl Radius {
 }
 
 /// Complete theme definition
-#[derive(Debug, Clone)]
+#[cfg(feature = "gpui")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Theme {
     pub name: String,
     pub mode: ThemeMode,
     pub colors: ThemeColors,
     pub radius: Radius,
+    #[serde(default)]
+    pub density: Density,
+    #[serde(default)]
+    pub typography: Typography,
+    #[serde(default)]
+    pub spacing: Spacing,
+    #[serde(default)]
+    pub controls: ControlHeights,
+    #[serde(default)]
+    pub toggles: ToggleSizes,
+    #[serde(default)]
+    pub shadows: Shadows,
+    #[serde(default)]
+    pub states: InteractionStates,
+    /// Per-component colors that take precedence over `colors`; see
+    /// [`Theme::slot`].
+    #[serde(default, skip_serializing_if = "ComponentOverrides::is_empty")]
+    pub overrides: ComponentOverrides,
+}
+
+#[cfg(feature = "gpui")]
+impl Global for Theme {}
+
+#[cfg(feature = "gpui")]
+impl Theme {
+    /// Create a theme with the default density, type scale, shadows and
+    /// states.
+    pub fn new(
+        name: impl Into<String>,
+        mode: ThemeMode,
+        colors: ThemeColors,
+        radius: Radius,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            colors,
+            radius,
+            density: Density::default(),
+            typography: Typography::default(),
+            spacing: Spacing::default(),
+            controls: ControlHeights::default(),
+            toggles: ToggleSizes::default(),
+            shadows: Shadows::default(),
+            states: InteractionStates::default(),
+            overrides: ComponentOverrides::default(),
+        }
+    }
+
+    /// Switch density, rescaling spacing, control heights and toggle sizes.
+    pub fn set_density(&mut self, density: Density) {
+        self.density = density;
+        self.spacing = Spacing::for_density(density);
+        self.controls = ControlHeights::for_density(density);
+        self.toggles = ToggleSizes::for_density(density);
+    }
+
+    /// Builder form of [`Theme::set_density`].
+    pub fn with_density(mut self, density: Density) -> Self {
+        self.set_density(density);
+        self
+    }
+
+    /// The focus ring in the theme's `ring` color, for `Styled::shadow`.
+    pub fn focus_ring(&self) -> Vec<gpui::BoxShadow> {
+        self.states
+            .focus_ring
+            .to_box_shadows(self.colors.ring, self.colors.background)
+    }
+}
+
+/// A plain HSL color: hue in degrees, saturation and lightness in percent,
+/// alpha from 0 to 1.
+///
+/// Parses from any color syntax theme files accept, e.g. `"#7c3aed".parse()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+    pub a: f32,
+}
+
+impl Hsl {
+    /// An opaque color.
+    pub const fn new(h: f32, s: f32, l: f32) -> Self {
+        Self { h, s, l, a: 1.0 }
+    }
+
+    /// The same color with a different alpha.
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+
+    /// sRGB channels from 0 to 1, ignoring alpha.
+    pub fn to_rgb(self) -> [f32; 3] {
+        let (h, s, l) = (self.h.rem_euclid(360.0), self.s / 100.0, self.l / 100.0);
+        let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0).rem_euclid(2.0) - 1.0).abs());
+        let m = l - c / 2.0;
+        let (r, g, b) = match (h / 60.0) as u32 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        [r + m, g + m, b + m].map(|v| v.clamp(0.0, 1.0))
+    }
+}
+
+/// Declares the color tokens once and generates both palette types from
+/// the list, so adding a token only means adding it here and to each preset.
+macro_rules! color_tokens {
+    ($($token:ident),* $(,)?) => {
+        /// Theme color palette
+        ///
+        /// Serializes each color as an HSL string, e.g. `"hsl(240, 5.9%, 10%)"`.
+        #[cfg(feature = "gpui")]
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        pub struct ThemeColors {
+            $(
+                #[serde(with = "file::hsl_string")]
+                pub $token: Hsla,
+            )*
+        }
+
+        /// Theme color palette as plain HSL tuples, available without gpui
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct PaletteColors {
+            $(pub $token: Hsl,)*
+        }
+
+        impl PaletteColors {
+            /// Names of all color tokens, in declaration order.
+            pub const TOKENS: &'static [&'static str] = &[$(stringify!($token)),*];
+
+            /// Look up a token by name.
+            pub fn get(&self, token: &str) -> Option<Hsl> {
+                match token {
+                    $(stringify!($token) => Some(self.$token),)*
+                    _ => None,
+                }
+            }
+
+            /// `(name, value)` pairs for every token, in declaration order.
+            pub fn tokens(&self) -> Vec<(&'static str, Hsl)> {
+                vec![$((stringify!($token), self.$token)),*]
+            }
+
+            /// Build a palette by resolving each token name through `f`.
+            pub fn try_from_fn<E>(
+                mut f: impl FnMut(&'static str) -> Result<Hsl, E>,
+            ) -> Result<Self, E> {
+                Ok(Self {
+                    $($token: f(stringify!($token))?,)*
+                })
+            }
+        }
+
+        #[cfg(feature = "gpui")]
+        impl ThemeColors {
+            /// Look up a token by name.
+            pub fn get(&self, token: &str) -> Option<Hsla> {
+                match token {
+                    $(stringify!($token) => Some(self.$token),)*
+                    _ => None,
+                }
+            }
+
+            /// Build a palette by resolving each token name through `f`.
+            pub fn try_from_fn<E>(
+                mut f: impl FnMut(&'static str) -> Result<Hsla, E>,
+            ) -> Result<Self, E> {
+                Ok(Self {
+                    $($token: f(stringify!($token))?,)*
+                })
+            }
+        }
+
+        #[cfg(feature = "gpui")]
+        impl From<&PaletteColors> for ThemeColors {
+            fn from(palette: &PaletteColors) -> Self {
+                Self {
+                    $($token: palette.$token.into(),)*
+                }
+            }
+        }
+
+        #[cfg(feature = "gpui")]
+        impl From<&ThemeColors> for PaletteColors {
+            fn from(colors: &ThemeColors) -> Self {
+                Self {
+                    $($token: colors.$token.into(),)*
+                }
+            }
+        }
+    };
 }
 
-/// Theme color palette
-#[derive(Debug, Clone)]
-pub struct ThemeColors {
-    pub background: Hsla,
-    pub foreground: Hsla,
-    pub card: Hsla,
-    pub card_foreground: Hsla,
-    pub popover: Hsla,
-    pub popover_foreground: Hsla,
-    pub primary: Hsla,
-    pub primary_foreground: Hsla,
-    pub secondary: Hsla,
-    pub secondary_foreground: Hsla,
-    pub muted: Hsla,
-    pub muted_foreground: Hsla,
-    pub accent: Hsla,
-    pub accent_foreground: Hsla,
-    pub destructive: Hsla,
-    pub destructive_foreground: Hsla,
-    pub border: Hsla,
-    pub input: Hsla,
-    pub ring: Hsla,
+color_tokens! {
+    background,
+    foreground,
+    card,
+    card_foreground,
+    popover,
+    popover_foreground,
+    primary,
+    primary_foreground,
+    secondary,
+    secondary_foreground,
+    muted,
+    muted_foreground,
+    accent,
+    accent_foreground,
+    destructive,
+    destructive_foreground,
+    border,
+    input,
+    ring,
+    chart_1,
+    chart_2,
+    chart_3,
+    chart_4,
+    chart_5,
+    sidebar,
+    sidebar_foreground,
+    sidebar_primary,
+    sidebar_primary_foreground,
+    sidebar_accent,
+    sidebar_accent_foreground,
+    sidebar_border,
+    sidebar_ring,
+    success,
+    success_foreground,
+    warning,
+    warning_foreground,
+    info,
+    info_foreground,
+    overlay,
+    selection,
+    disabled_foreground,
 }
 
+#[cfg(feature = "gpui")]
 impl Default for Theme {
     fn default() -> Self {
         presets::zinc(ThemeMode::Light)
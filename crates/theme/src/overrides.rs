@@ -0,0 +1,130 @@
+//! Per-component color overrides
+//!
+//! Components read their colors from [`ThemeColors`](crate::ThemeColors), but
+//! first consult [`Theme::slot`] with a slot name of the form
+//! `component.variant.part` (or `component.part` for components without
+//! variants), e.g. `button.destructive.bg` or `card.border`. A theme that
+//! sets a slot restyles that one part of that one component; everything else
+//! keeps following the global tokens.
+
+use std::collections::BTreeMap;
+
+use gpui::Hsla;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Theme;
+
+/// Colors for component slots, keyed by slot name.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComponentOverrides {
+    slots: BTreeMap<String, Hsla>,
+}
+
+impl ComponentOverrides {
+    /// An empty set of overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The color set for `slot`, if any.
+    pub fn get(&self, slot: &str) -> Option<Hsla> {
+        self.slots.get(slot).copied()
+    }
+
+    /// Set the color for `slot`.
+    pub fn set(&mut self, slot: impl Into<String>, color: Hsla) {
+        self.slots.insert(slot.into(), color);
+    }
+
+    /// Builder form of [`ComponentOverrides::set`].
+    pub fn with(mut self, slot: impl Into<String>, color: Hsla) -> Self {
+        self.set(slot, color);
+        self
+    }
+
+    /// Remove the override for `slot`, returning its color.
+    pub fn remove(&mut self, slot: &str) -> Option<Hsla> {
+        self.slots.remove(slot)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// `(slot, color)` pairs, sorted by slot.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Hsla)> {
+        self.slots
+            .iter()
+            .map(|(slot, color)| (slot.as_str(), *color))
+    }
+
+    /// Add the slots of `parent` that are not set here.
+    pub(crate) fn inherit(&mut self, parent: &ComponentOverrides) {
+        for (slot, color) in &parent.slots {
+            self.slots.entry(slot.clone()).or_insert(*color);
+        }
+    }
+
+    pub(crate) fn from_map(slots: BTreeMap<String, Hsla>) -> Self {
+        Self { slots }
+    }
+}
+
+impl Serialize for ComponentOverrides {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.slots
+                .iter()
+                .map(|(slot, color)| (slot, crate::to_hsl_string(*color))),
+        )
+    }
+}
+
+impl<'de> Deserialize<'de> for ComponentOverrides {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::file::color_table::overrides(deserializer).map(Self::from_map)
+    }
+}
+
+impl Theme {
+    /// The color of a component slot, such as `button.destructive.bg`: the
+    /// theme's override if it sets one, otherwise `fallback`.
+    pub fn slot(&self, slot: &str, fallback: Hsla) -> Hsla {
+        self.overrides.get(slot).unwrap_or(fallback)
+    }
+
+    /// Override the color of a component slot.
+    pub fn with_override(mut self, slot: impl Into<String>, color: Hsla) -> Self {
+        self.overrides.set(slot, color);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ThemeMode, hsl, zinc};
+
+    #[test]
+    fn test_slot_falls_back_to_token() {
+        let red = hsl(0.0, 72.2, 50.6);
+        let theme = zinc(ThemeMode::Light).with_override("button.destructive.bg", red);
+        let token = theme.colors.destructive;
+        assert_eq!(theme.slot("button.destructive.bg", token), red);
+        assert_eq!(theme.slot("button.destructive.fg", token), token);
+        assert_eq!(theme.slot("badge.destructive.bg", token), token);
+    }
+
+    #[test]
+    fn test_inherit_keeps_own_slots() {
+        let (red, blue) = (hsl(0.0, 72.2, 50.6), hsl(221.2, 83.2, 53.3));
+        let mut child = ComponentOverrides::new().with("card.border", red);
+        child.inherit(
+            &ComponentOverrides::new()
+                .with("card.border", blue)
+                .with("card.bg", blue),
+        );
+        assert_eq!(child.get("card.border"), Some(red));
+        assert_eq!(child.get("card.bg"), Some(blue));
+    }
+}
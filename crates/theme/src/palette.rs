@@ -0,0 +1,539 @@
+//! Preset palettes as plain HSL data
+//!
+//! Color values sourced from the official shadcn/ui base color definitions.
+//! This module does not depend on gpui, so the CLI can use the same table
+//! to generate code (enable the crate without default features).
+
+use std::sync::LazyLock;
+
+use crate::{Hsl, PaletteColors, Radius, ThemeMode};
+
+/// A preset's light and dark palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PresetPalette {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub radius: Radius,
+    pub light: PaletteColors,
+    pub dark: PaletteColors,
+}
+
+impl PresetPalette {
+    /// The palette for one mode. [`ThemeMode::System`] gives the light palette.
+    pub fn colors(&self, mode: ThemeMode) -> &PaletteColors {
+        match mode {
+            ThemeMode::Light | ThemeMode::System => &self.light,
+            ThemeMode::Dark => &self.dark,
+        }
+    }
+}
+
+const fn hsl(h: f32, s: f32, l: f32) -> Hsl {
+    Hsl::new(h, s, l)
+}
+
+const fn hsla(h: f32, s: f32, l: f32, a: f32) -> Hsl {
+    Hsl::new(h, s, l).with_alpha(a)
+}
+
+/// All preset palettes. Zinc, the default, comes first.
+pub const PRESETS: &[PresetPalette] = &[ZINC, SLATE, STONE, GRAY, NEUTRAL];
+
+/// All available preset names
+pub fn preset_names() -> &'static [&'static str] {
+    static NAMES: LazyLock<Vec<&'static str>> =
+        LazyLock::new(|| PRESETS.iter().map(|p| p.name).collect());
+    &NAMES
+}
+
+/// Get a preset palette by name
+pub fn preset_palette(name: &str) -> Option<&'static PresetPalette> {
+    PRESETS.iter().find(|p| p.name == name)
+}
+
+/// Zinc theme (default) - cool gray with subtle blue tint
+pub const ZINC: PresetPalette = PresetPalette {
+    name: "zinc",
+    description: "Cool gray with subtle blue tint",
+    radius: Radius::Md,
+    light: PaletteColors {
+        background: hsl(0.0, 0.0, 100.0),
+        foreground: hsl(240.0, 10.0, 3.9),
+        card: hsl(0.0, 0.0, 100.0),
+        card_foreground: hsl(240.0, 10.0, 3.9),
+        popover: hsl(0.0, 0.0, 100.0),
+        popover_foreground: hsl(240.0, 10.0, 3.9),
+        primary: hsl(240.0, 5.9, 10.0),
+        primary_foreground: hsl(0.0, 0.0, 98.0),
+        secondary: hsl(240.0, 4.8, 95.9),
+        secondary_foreground: hsl(240.0, 5.9, 10.0),
+        muted: hsl(240.0, 4.8, 95.9),
+        muted_foreground: hsl(240.0, 3.8, 45.0),
+        accent: hsl(240.0, 4.8, 95.9),
+        accent_foreground: hsl(240.0, 5.9, 10.0),
+        destructive: hsl(0.0, 72.2, 50.6),
+        destructive_foreground: hsl(0.0, 0.0, 98.0),
+        border: hsl(240.0, 5.9, 90.0),
+        input: hsl(240.0, 5.9, 90.0),
+        ring: hsl(240.0, 5.9, 10.0),
+        chart_1: hsl(12.0, 76.0, 61.0),
+        chart_2: hsl(173.0, 58.0, 39.0),
+        chart_3: hsl(197.0, 37.0, 24.0),
+        chart_4: hsl(43.0, 74.0, 66.0),
+        chart_5: hsl(27.0, 87.0, 67.0),
+        sidebar: hsl(240.0, 4.8, 98.0),
+        sidebar_foreground: hsl(240.0, 5.9, 26.1),
+        sidebar_primary: hsl(240.0, 5.9, 10.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 98.0),
+        sidebar_accent: hsl(240.0, 4.8, 95.9),
+        sidebar_accent_foreground: hsl(240.0, 5.9, 10.0),
+        sidebar_border: hsl(240.0, 5.9, 90.0),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.4, 71.8, 29.2),
+        success_foreground: hsl(0.0, 0.0, 98.0),
+        warning: hsl(37.7, 92.1, 50.2),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(221.2, 83.2, 53.3),
+        info_foreground: hsl(0.0, 0.0, 98.0),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.3),
+        disabled_foreground: hsl(240.0, 3.8, 45.0),
+    },
+    dark: PaletteColors {
+        background: hsl(240.0, 10.0, 3.9),
+        foreground: hsl(0.0, 0.0, 98.0),
+        card: hsl(240.0, 10.0, 3.9),
+        card_foreground: hsl(0.0, 0.0, 98.0),
+        popover: hsl(240.0, 10.0, 3.9),
+        popover_foreground: hsl(0.0, 0.0, 98.0),
+        primary: hsl(0.0, 0.0, 98.0),
+        primary_foreground: hsl(240.0, 5.9, 10.0),
+        secondary: hsl(240.0, 3.7, 15.9),
+        secondary_foreground: hsl(0.0, 0.0, 98.0),
+        muted: hsl(240.0, 3.7, 15.9),
+        muted_foreground: hsl(240.0, 5.0, 64.9),
+        accent: hsl(240.0, 3.7, 15.9),
+        accent_foreground: hsl(0.0, 0.0, 98.0),
+        destructive: hsl(0.0, 62.8, 30.6),
+        destructive_foreground: hsl(0.0, 0.0, 98.0),
+        border: hsl(240.0, 3.7, 15.9),
+        input: hsl(240.0, 3.7, 15.9),
+        ring: hsl(240.0, 4.9, 83.9),
+        chart_1: hsl(220.0, 70.0, 50.0),
+        chart_2: hsl(160.0, 60.0, 45.0),
+        chart_3: hsl(30.0, 80.0, 55.0),
+        chart_4: hsl(280.0, 65.0, 60.0),
+        chart_5: hsl(340.0, 75.0, 55.0),
+        sidebar: hsl(240.0, 5.9, 10.0),
+        sidebar_foreground: hsl(240.0, 4.8, 95.9),
+        sidebar_primary: hsl(224.3, 76.3, 48.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 100.0),
+        sidebar_accent: hsl(240.0, 3.7, 15.9),
+        sidebar_accent_foreground: hsl(240.0, 4.8, 95.9),
+        sidebar_border: hsl(240.0, 3.7, 15.9),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.1, 70.6, 45.3),
+        success_foreground: hsl(144.9, 80.4, 10.0),
+        warning: hsl(48.0, 96.0, 53.1),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(217.2, 91.2, 59.8),
+        info_foreground: hsl(222.2, 47.4, 11.2),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.4),
+        disabled_foreground: hsl(240.0, 5.0, 64.9),
+    },
+};
+
+/// Slate theme - strong blue-gray tint
+pub const SLATE: PresetPalette = PresetPalette {
+    name: "slate",
+    description: "Strong blue-gray tint",
+    radius: Radius::Md,
+    light: PaletteColors {
+        background: hsl(0.0, 0.0, 100.0),
+        foreground: hsl(222.2, 84.0, 4.9),
+        card: hsl(0.0, 0.0, 100.0),
+        card_foreground: hsl(222.2, 84.0, 4.9),
+        popover: hsl(0.0, 0.0, 100.0),
+        popover_foreground: hsl(222.2, 84.0, 4.9),
+        primary: hsl(222.2, 47.4, 11.2),
+        primary_foreground: hsl(210.0, 40.0, 98.0),
+        secondary: hsl(210.0, 40.0, 96.1),
+        secondary_foreground: hsl(222.2, 47.4, 11.2),
+        muted: hsl(210.0, 40.0, 96.1),
+        muted_foreground: hsl(215.4, 16.3, 45.0),
+        accent: hsl(210.0, 40.0, 96.1),
+        accent_foreground: hsl(222.2, 47.4, 11.2),
+        destructive: hsl(0.0, 72.2, 50.6),
+        destructive_foreground: hsl(210.0, 40.0, 98.0),
+        border: hsl(214.3, 31.8, 91.4),
+        input: hsl(214.3, 31.8, 91.4),
+        ring: hsl(222.2, 84.0, 4.9),
+        chart_1: hsl(12.0, 76.0, 61.0),
+        chart_2: hsl(173.0, 58.0, 39.0),
+        chart_3: hsl(197.0, 37.0, 24.0),
+        chart_4: hsl(43.0, 74.0, 66.0),
+        chart_5: hsl(27.0, 87.0, 67.0),
+        sidebar: hsl(210.0, 40.0, 98.0),
+        sidebar_foreground: hsl(222.2, 47.4, 26.1),
+        sidebar_primary: hsl(222.2, 47.4, 11.2),
+        sidebar_primary_foreground: hsl(210.0, 40.0, 98.0),
+        sidebar_accent: hsl(210.0, 40.0, 96.1),
+        sidebar_accent_foreground: hsl(222.2, 47.4, 11.2),
+        sidebar_border: hsl(214.3, 31.8, 91.4),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.4, 71.8, 29.2),
+        success_foreground: hsl(0.0, 0.0, 98.0),
+        warning: hsl(37.7, 92.1, 50.2),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(221.2, 83.2, 53.3),
+        info_foreground: hsl(0.0, 0.0, 98.0),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.3),
+        disabled_foreground: hsl(215.4, 16.3, 45.0),
+    },
+    dark: PaletteColors {
+        background: hsl(222.2, 84.0, 4.9),
+        foreground: hsl(210.0, 40.0, 98.0),
+        card: hsl(222.2, 84.0, 4.9),
+        card_foreground: hsl(210.0, 40.0, 98.0),
+        popover: hsl(222.2, 84.0, 4.9),
+        popover_foreground: hsl(210.0, 40.0, 98.0),
+        primary: hsl(210.0, 40.0, 98.0),
+        primary_foreground: hsl(222.2, 47.4, 11.2),
+        secondary: hsl(217.2, 32.6, 17.5),
+        secondary_foreground: hsl(210.0, 40.0, 98.0),
+        muted: hsl(217.2, 32.6, 17.5),
+        muted_foreground: hsl(215.0, 20.2, 65.1),
+        accent: hsl(217.2, 32.6, 17.5),
+        accent_foreground: hsl(210.0, 40.0, 98.0),
+        destructive: hsl(0.0, 62.8, 30.6),
+        destructive_foreground: hsl(210.0, 40.0, 98.0),
+        border: hsl(217.2, 32.6, 17.5),
+        input: hsl(217.2, 32.6, 17.5),
+        ring: hsl(212.7, 26.8, 83.9),
+        chart_1: hsl(220.0, 70.0, 50.0),
+        chart_2: hsl(160.0, 60.0, 45.0),
+        chart_3: hsl(30.0, 80.0, 55.0),
+        chart_4: hsl(280.0, 65.0, 60.0),
+        chart_5: hsl(340.0, 75.0, 55.0),
+        sidebar: hsl(222.2, 47.4, 10.0),
+        sidebar_foreground: hsl(210.0, 40.0, 96.1),
+        sidebar_primary: hsl(224.3, 76.3, 48.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 100.0),
+        sidebar_accent: hsl(217.2, 32.6, 17.5),
+        sidebar_accent_foreground: hsl(210.0, 40.0, 96.1),
+        sidebar_border: hsl(217.2, 32.6, 17.5),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.1, 70.6, 45.3),
+        success_foreground: hsl(144.9, 80.4, 10.0),
+        warning: hsl(48.0, 96.0, 53.1),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(217.2, 91.2, 59.8),
+        info_foreground: hsl(222.2, 47.4, 11.2),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.4),
+        disabled_foreground: hsl(215.0, 20.2, 65.1),
+    },
+};
+
+/// Stone theme - warm gray with brown tint
+pub const STONE: PresetPalette = PresetPalette {
+    name: "stone",
+    description: "Warm gray with brown tint",
+    radius: Radius::Lg,
+    light: PaletteColors {
+        background: hsl(0.0, 0.0, 100.0),
+        foreground: hsl(20.0, 14.3, 4.1),
+        card: hsl(0.0, 0.0, 100.0),
+        card_foreground: hsl(20.0, 14.3, 4.1),
+        popover: hsl(0.0, 0.0, 100.0),
+        popover_foreground: hsl(20.0, 14.3, 4.1),
+        primary: hsl(24.0, 9.8, 10.0),
+        primary_foreground: hsl(60.0, 9.1, 97.8),
+        secondary: hsl(60.0, 4.8, 95.9),
+        secondary_foreground: hsl(24.0, 9.8, 10.0),
+        muted: hsl(60.0, 4.8, 95.9),
+        muted_foreground: hsl(25.0, 5.3, 43.5),
+        accent: hsl(60.0, 4.8, 95.9),
+        accent_foreground: hsl(24.0, 9.8, 10.0),
+        destructive: hsl(0.0, 72.2, 50.6),
+        destructive_foreground: hsl(60.0, 9.1, 97.8),
+        border: hsl(20.0, 5.9, 90.0),
+        input: hsl(20.0, 5.9, 90.0),
+        ring: hsl(20.0, 14.3, 4.1),
+        chart_1: hsl(12.0, 76.0, 61.0),
+        chart_2: hsl(173.0, 58.0, 39.0),
+        chart_3: hsl(197.0, 37.0, 24.0),
+        chart_4: hsl(43.0, 74.0, 66.0),
+        chart_5: hsl(27.0, 87.0, 67.0),
+        sidebar: hsl(60.0, 4.8, 98.0),
+        sidebar_foreground: hsl(24.0, 9.8, 26.1),
+        sidebar_primary: hsl(24.0, 9.8, 10.0),
+        sidebar_primary_foreground: hsl(60.0, 9.1, 97.8),
+        sidebar_accent: hsl(60.0, 4.8, 95.9),
+        sidebar_accent_foreground: hsl(24.0, 9.8, 10.0),
+        sidebar_border: hsl(20.0, 5.9, 90.0),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.4, 71.8, 29.2),
+        success_foreground: hsl(0.0, 0.0, 98.0),
+        warning: hsl(37.7, 92.1, 50.2),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(221.2, 83.2, 53.3),
+        info_foreground: hsl(0.0, 0.0, 98.0),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.3),
+        disabled_foreground: hsl(25.0, 5.3, 43.5),
+    },
+    dark: PaletteColors {
+        background: hsl(20.0, 14.3, 4.1),
+        foreground: hsl(60.0, 9.1, 97.8),
+        card: hsl(20.0, 14.3, 4.1),
+        card_foreground: hsl(60.0, 9.1, 97.8),
+        popover: hsl(20.0, 14.3, 4.1),
+        popover_foreground: hsl(60.0, 9.1, 97.8),
+        primary: hsl(60.0, 9.1, 97.8),
+        primary_foreground: hsl(24.0, 9.8, 10.0),
+        secondary: hsl(12.0, 6.5, 15.1),
+        secondary_foreground: hsl(60.0, 9.1, 97.8),
+        muted: hsl(12.0, 6.5, 15.1),
+        muted_foreground: hsl(24.0, 5.4, 63.9),
+        accent: hsl(12.0, 6.5, 15.1),
+        accent_foreground: hsl(60.0, 9.1, 97.8),
+        destructive: hsl(0.0, 62.8, 30.6),
+        destructive_foreground: hsl(60.0, 9.1, 97.8),
+        border: hsl(12.0, 6.5, 15.1),
+        input: hsl(12.0, 6.5, 15.1),
+        ring: hsl(24.0, 5.7, 82.9),
+        chart_1: hsl(220.0, 70.0, 50.0),
+        chart_2: hsl(160.0, 60.0, 45.0),
+        chart_3: hsl(30.0, 80.0, 55.0),
+        chart_4: hsl(280.0, 65.0, 60.0),
+        chart_5: hsl(340.0, 75.0, 55.0),
+        sidebar: hsl(24.0, 9.8, 10.0),
+        sidebar_foreground: hsl(60.0, 4.8, 95.9),
+        sidebar_primary: hsl(224.3, 76.3, 48.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 100.0),
+        sidebar_accent: hsl(12.0, 6.5, 15.1),
+        sidebar_accent_foreground: hsl(60.0, 4.8, 95.9),
+        sidebar_border: hsl(12.0, 6.5, 15.1),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.1, 70.6, 45.3),
+        success_foreground: hsl(144.9, 80.4, 10.0),
+        warning: hsl(48.0, 96.0, 53.1),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(217.2, 91.2, 59.8),
+        info_foreground: hsl(222.2, 47.4, 11.2),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.4),
+        disabled_foreground: hsl(24.0, 5.4, 63.9),
+    },
+};
+
+/// Gray theme - medium blue-gray
+pub const GRAY: PresetPalette = PresetPalette {
+    name: "gray",
+    description: "Medium blue-gray",
+    radius: Radius::Sm,
+    light: PaletteColors {
+        background: hsl(0.0, 0.0, 100.0),
+        foreground: hsl(224.0, 71.4, 4.1),
+        card: hsl(0.0, 0.0, 100.0),
+        card_foreground: hsl(224.0, 71.4, 4.1),
+        popover: hsl(0.0, 0.0, 100.0),
+        popover_foreground: hsl(224.0, 71.4, 4.1),
+        primary: hsl(220.9, 39.3, 11.0),
+        primary_foreground: hsl(210.0, 20.0, 98.0),
+        secondary: hsl(220.0, 14.3, 95.9),
+        secondary_foreground: hsl(220.9, 39.3, 11.0),
+        muted: hsl(220.0, 14.3, 95.9),
+        muted_foreground: hsl(220.0, 8.9, 45.0),
+        accent: hsl(220.0, 14.3, 95.9),
+        accent_foreground: hsl(220.9, 39.3, 11.0),
+        destructive: hsl(0.0, 72.2, 50.6),
+        destructive_foreground: hsl(210.0, 20.0, 98.0),
+        border: hsl(220.0, 13.0, 91.0),
+        input: hsl(220.0, 13.0, 91.0),
+        ring: hsl(224.0, 71.4, 4.1),
+        chart_1: hsl(12.0, 76.0, 61.0),
+        chart_2: hsl(173.0, 58.0, 39.0),
+        chart_3: hsl(197.0, 37.0, 24.0),
+        chart_4: hsl(43.0, 74.0, 66.0),
+        chart_5: hsl(27.0, 87.0, 67.0),
+        sidebar: hsl(220.0, 14.3, 98.0),
+        sidebar_foreground: hsl(220.9, 39.3, 26.1),
+        sidebar_primary: hsl(220.9, 39.3, 11.0),
+        sidebar_primary_foreground: hsl(210.0, 20.0, 98.0),
+        sidebar_accent: hsl(220.0, 14.3, 95.9),
+        sidebar_accent_foreground: hsl(220.9, 39.3, 11.0),
+        sidebar_border: hsl(220.0, 13.0, 91.0),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.4, 71.8, 29.2),
+        success_foreground: hsl(0.0, 0.0, 98.0),
+        warning: hsl(37.7, 92.1, 50.2),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(221.2, 83.2, 53.3),
+        info_foreground: hsl(0.0, 0.0, 98.0),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.3),
+        disabled_foreground: hsl(220.0, 8.9, 45.0),
+    },
+    dark: PaletteColors {
+        background: hsl(224.0, 71.4, 4.1),
+        foreground: hsl(210.0, 20.0, 98.0),
+        card: hsl(224.0, 71.4, 4.1),
+        card_foreground: hsl(210.0, 20.0, 98.0),
+        popover: hsl(224.0, 71.4, 4.1),
+        popover_foreground: hsl(210.0, 20.0, 98.0),
+        primary: hsl(210.0, 20.0, 98.0),
+        primary_foreground: hsl(220.9, 39.3, 11.0),
+        secondary: hsl(215.0, 27.9, 16.9),
+        secondary_foreground: hsl(210.0, 20.0, 98.0),
+        muted: hsl(215.0, 27.9, 16.9),
+        muted_foreground: hsl(217.9, 10.6, 64.9),
+        accent: hsl(215.0, 27.9, 16.9),
+        accent_foreground: hsl(210.0, 20.0, 98.0),
+        destructive: hsl(0.0, 62.8, 30.6),
+        destructive_foreground: hsl(210.0, 20.0, 98.0),
+        border: hsl(215.0, 27.9, 16.9),
+        input: hsl(215.0, 27.9, 16.9),
+        ring: hsl(216.0, 12.2, 83.9),
+        chart_1: hsl(220.0, 70.0, 50.0),
+        chart_2: hsl(160.0, 60.0, 45.0),
+        chart_3: hsl(30.0, 80.0, 55.0),
+        chart_4: hsl(280.0, 65.0, 60.0),
+        chart_5: hsl(340.0, 75.0, 55.0),
+        sidebar: hsl(220.9, 39.3, 10.0),
+        sidebar_foreground: hsl(220.0, 14.3, 95.9),
+        sidebar_primary: hsl(224.3, 76.3, 48.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 100.0),
+        sidebar_accent: hsl(215.0, 27.9, 16.9),
+        sidebar_accent_foreground: hsl(220.0, 14.3, 95.9),
+        sidebar_border: hsl(215.0, 27.9, 16.9),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.1, 70.6, 45.3),
+        success_foreground: hsl(144.9, 80.4, 10.0),
+        warning: hsl(48.0, 96.0, 53.1),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(217.2, 91.2, 59.8),
+        info_foreground: hsl(222.2, 47.4, 11.2),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.4),
+        disabled_foreground: hsl(217.9, 10.6, 64.9),
+    },
+};
+
+/// Neutral theme - true grayscale with no color tint
+pub const NEUTRAL: PresetPalette = PresetPalette {
+    name: "neutral",
+    description: "True grayscale with no color tint",
+    radius: Radius::Md,
+    light: PaletteColors {
+        background: hsl(0.0, 0.0, 100.0),
+        foreground: hsl(0.0, 0.0, 3.9),
+        card: hsl(0.0, 0.0, 100.0),
+        card_foreground: hsl(0.0, 0.0, 3.9),
+        popover: hsl(0.0, 0.0, 100.0),
+        popover_foreground: hsl(0.0, 0.0, 3.9),
+        primary: hsl(0.0, 0.0, 9.0),
+        primary_foreground: hsl(0.0, 0.0, 98.0),
+        secondary: hsl(0.0, 0.0, 96.1),
+        secondary_foreground: hsl(0.0, 0.0, 9.0),
+        muted: hsl(0.0, 0.0, 96.1),
+        muted_foreground: hsl(0.0, 0.0, 43.5),
+        accent: hsl(0.0, 0.0, 96.1),
+        accent_foreground: hsl(0.0, 0.0, 9.0),
+        destructive: hsl(0.0, 72.2, 50.6),
+        destructive_foreground: hsl(0.0, 0.0, 98.0),
+        border: hsl(0.0, 0.0, 89.8),
+        input: hsl(0.0, 0.0, 89.8),
+        ring: hsl(0.0, 0.0, 3.9),
+        chart_1: hsl(12.0, 76.0, 61.0),
+        chart_2: hsl(173.0, 58.0, 39.0),
+        chart_3: hsl(197.0, 37.0, 24.0),
+        chart_4: hsl(43.0, 74.0, 66.0),
+        chart_5: hsl(27.0, 87.0, 67.0),
+        sidebar: hsl(0.0, 0.0, 98.0),
+        sidebar_foreground: hsl(0.0, 0.0, 26.1),
+        sidebar_primary: hsl(0.0, 0.0, 9.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 98.0),
+        sidebar_accent: hsl(0.0, 0.0, 96.1),
+        sidebar_accent_foreground: hsl(0.0, 0.0, 9.0),
+        sidebar_border: hsl(0.0, 0.0, 89.8),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.4, 71.8, 29.2),
+        success_foreground: hsl(0.0, 0.0, 98.0),
+        warning: hsl(37.7, 92.1, 50.2),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(221.2, 83.2, 53.3),
+        info_foreground: hsl(0.0, 0.0, 98.0),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.3),
+        disabled_foreground: hsl(0.0, 0.0, 43.5),
+    },
+    dark: PaletteColors {
+        background: hsl(0.0, 0.0, 3.9),
+        foreground: hsl(0.0, 0.0, 98.0),
+        card: hsl(0.0, 0.0, 3.9),
+        card_foreground: hsl(0.0, 0.0, 98.0),
+        popover: hsl(0.0, 0.0, 3.9),
+        popover_foreground: hsl(0.0, 0.0, 98.0),
+        primary: hsl(0.0, 0.0, 98.0),
+        primary_foreground: hsl(0.0, 0.0, 9.0),
+        secondary: hsl(0.0, 0.0, 14.9),
+        secondary_foreground: hsl(0.0, 0.0, 98.0),
+        muted: hsl(0.0, 0.0, 14.9),
+        muted_foreground: hsl(0.0, 0.0, 63.9),
+        accent: hsl(0.0, 0.0, 14.9),
+        accent_foreground: hsl(0.0, 0.0, 98.0),
+        destructive: hsl(0.0, 62.8, 30.6),
+        destructive_foreground: hsl(0.0, 0.0, 98.0),
+        border: hsl(0.0, 0.0, 14.9),
+        input: hsl(0.0, 0.0, 14.9),
+        ring: hsl(0.0, 0.0, 83.1),
+        chart_1: hsl(220.0, 70.0, 50.0),
+        chart_2: hsl(160.0, 60.0, 45.0),
+        chart_3: hsl(30.0, 80.0, 55.0),
+        chart_4: hsl(280.0, 65.0, 60.0),
+        chart_5: hsl(340.0, 75.0, 55.0),
+        sidebar: hsl(0.0, 0.0, 10.0),
+        sidebar_foreground: hsl(0.0, 0.0, 96.1),
+        sidebar_primary: hsl(224.3, 76.3, 48.0),
+        sidebar_primary_foreground: hsl(0.0, 0.0, 100.0),
+        sidebar_accent: hsl(0.0, 0.0, 14.9),
+        sidebar_accent_foreground: hsl(0.0, 0.0, 96.1),
+        sidebar_border: hsl(0.0, 0.0, 14.9),
+        sidebar_ring: hsl(217.2, 91.2, 59.8),
+        success: hsl(142.1, 70.6, 45.3),
+        success_foreground: hsl(144.9, 80.4, 10.0),
+        warning: hsl(48.0, 96.0, 53.1),
+        warning_foreground: hsl(26.0, 83.3, 14.1),
+        info: hsl(217.2, 91.2, 59.8),
+        info_foreground: hsl(222.2, 47.4, 11.2),
+        overlay: hsla(0.0, 0.0, 0.0, 0.8),
+        selection: hsla(217.2, 91.2, 59.8, 0.4),
+        disabled_foreground: hsl(0.0, 0.0, 63.9),
+    },
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_names_match_table() {
+        assert_eq!(preset_names().len(), PRESETS.len());
+        for name in preset_names() {
+            assert_eq!(preset_palette(name).unwrap().name, *name);
+        }
+        assert!(preset_palette("unknown").is_none());
+    }
+
+    #[test]
+    fn test_tokens_round_trip() {
+        let palette = &ZINC.light;
+        assert_eq!(palette.tokens().len(), PaletteColors::TOKENS.len());
+        let rebuilt = PaletteColors::try_from_fn(|token| palette.get(token).ok_or(token)).unwrap();
+        assert_eq!(rebuilt, *palette);
+    }
+}
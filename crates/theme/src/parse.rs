@@ -0,0 +1,253 @@
+//! Color syntax accepted in theme files
+//!
+//! Colors are parsed into [`Hsl`], so this module does not depend on gpui.
+//! The accepted forms follow CSS Color 4:
+//!
+//! - `#rgb`, `#rgba`, `#rrggbb` and `#rrggbbaa`
+//! - `rgb(r, g, b)`, `rgba(r, g, b, a)` and `rgb(r g b / a)`
+//! - `hsl(h, s%, l%)`, `hsla(h, s%, l%, a)` and `hsl(h s% l% / a)`
+//! - `oklch(l c h)` and `oklch(l c h / a)`
+//!
+//! Alpha may be a number from 0 to 1 or a percentage.
+
+use std::str::FromStr;
+
+use crate::{Hsl, from_oklch};
+
+/// Error returned when a color string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("invalid color `{0}`: expected a hex, `rgb()`, `hsl()` or `oklch()` color")]
+pub struct ColorParseError(pub String);
+
+impl FromStr for Hsl {
+    type Err = ColorParseError;
+
+    fn from_str(s: &str) -> Result<Self, ColorParseError> {
+        let err = || ColorParseError(s.to_string());
+        let trimmed = s.trim();
+        if let Some(hex) = trimmed.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(err);
+        }
+        let (function, args) = trimmed
+            .strip_suffix(')')
+            .and_then(|rest| rest.split_once('('))
+            .ok_or_else(err)?;
+        let args = split_args(args).ok_or_else(err)?;
+        match function.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => parse_rgb(&args),
+            "hsl" | "hsla" => parse_hsl_args(&args),
+            "oklch" => parse_oklch(&args),
+            _ => None,
+        }
+        .ok_or_else(err)
+    }
+}
+
+/// Split function arguments written either with commas, `(a, b, c, alpha)`,
+/// or with spaces and a slash, `(a b c / alpha)`.
+fn split_args(args: &str) -> Option<Vec<&str>> {
+    if args.contains(',') {
+        return Some(args.split(',').map(str::trim).collect());
+    }
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let mut parts: Vec<&str> = channels.split_whitespace().collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    parts.extend(alpha);
+    Some(parts)
+}
+
+fn number(part: &str) -> Option<f32> {
+    part.trim().parse::<f32>().ok().filter(|v| v.is_finite())
+}
+
+/// A number, or a percentage scaled so that `100%` is `full`.
+fn number_or_percent(part: &str, full: f32) -> Option<f32> {
+    match part.strip_suffix('%') {
+        Some(percent) => number(percent).map(|v| v / 100.0 * full),
+        None => number(part),
+    }
+}
+
+/// An optional alpha channel, clamped to 0..=1.
+fn alpha(part: Option<&&str>) -> Option<f32> {
+    match part {
+        Some(part) => number_or_percent(part, 1.0).map(|a| a.clamp(0.0, 1.0)),
+        None => Some(1.0),
+    }
+}
+
+/// A hue in degrees; `deg` and `turn` units are accepted.
+fn hue(part: &str) -> Option<f32> {
+    let degrees = if let Some(turns) = part.strip_suffix("turn") {
+        number(turns)? * 360.0
+    } else {
+        number(part.strip_suffix("deg").unwrap_or(part))?
+    };
+    Some(degrees.rem_euclid(360.0))
+}
+
+fn channel_count(args: &[&str]) -> Option<()> {
+    (args.len() == 3 || args.len() == 4).then_some(())
+}
+
+pub(crate) fn parse_hex(hex: &str) -> Option<Hsl> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digits: Vec<u8> = match hex.len() {
+        // Short forms repeat each digit: #abc is #aabbcc
+        3 | 4 => hex
+            .chars()
+            .map(|c| c.to_digit(16).unwrap() as u8 * 17)
+            .collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect(),
+        _ => return None,
+    };
+    let a = digits.get(3).map_or(1.0, |&a| a as f32 / 255.0);
+    Some(rgb_to_hsl(
+        digits[0] as f32 / 255.0,
+        digits[1] as f32 / 255.0,
+        digits[2] as f32 / 255.0,
+        a,
+    ))
+}
+
+fn parse_rgb(args: &[&str]) -> Option<Hsl> {
+    channel_count(args)?;
+    let channel = |part: &str| number_or_percent(part, 255.0).map(|v| (v / 255.0).clamp(0.0, 1.0));
+    Some(rgb_to_hsl(
+        channel(args[0])?,
+        channel(args[1])?,
+        channel(args[2])?,
+        alpha(args.get(3))?,
+    ))
+}
+
+fn parse_hsl_args(args: &[&str]) -> Option<Hsl> {
+    channel_count(args)?;
+    let percent = |part: &str| number(part.strip_suffix('%').unwrap_or(part));
+    Some(
+        Hsl::new(hue(args[0])?, percent(args[1])?, percent(args[2])?)
+            .with_alpha(alpha(args.get(3))?),
+    )
+}
+
+/// `oklch(l c h)`: lightness from 0 to 1 (or a percentage), chroma where
+/// `100%` is 0.4, and hue in degrees. Colors outside sRGB are clipped.
+fn parse_oklch(args: &[&str]) -> Option<Hsl> {
+    channel_count(args)?;
+    let l = number_or_percent(args[0], 1.0)?;
+    let c = number_or_percent(args[1], 0.4)?;
+    let h = hue(args[2])?;
+    Some(from_oklch::<Hsl>([l, c, h]).with_alpha(alpha(args.get(3))?))
+}
+
+/// Convert sRGB channels from 0 to 1 into an [`Hsl`], rounded to three
+/// decimals so converted colors are written back without float noise.
+pub(crate) fn rgb_to_hsl(r: f32, g: f32, b: f32, a: f32) -> Hsl {
+    fn round(v: f32) -> f32 {
+        (v * 1000.0).round() / 1000.0
+    }
+
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let delta = max - min;
+    if delta <= f32::EPSILON {
+        return Hsl::new(0.0, 0.0, round(l * 100.0)).with_alpha(a);
+    }
+    let s = delta / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    };
+    Hsl::new(
+        round(h * 60.0).rem_euclid(360.0),
+        round(s * 100.0),
+        round(l * 100.0),
+    )
+    .with_alpha(a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Hsl {
+        s.parse().unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    fn assert_close(actual: Hsl, expected: Hsl) {
+        let hue_diff = (actual.h - expected.h).rem_euclid(360.0);
+        let close = hue_diff.min(360.0 - hue_diff) < 0.5
+            && (actual.s - expected.s).abs() < 0.5
+            && (actual.l - expected.l).abs() < 0.5
+            && (actual.a - expected.a).abs() < 0.01;
+        assert!(close, "{actual:?} is not close to {expected:?}");
+    }
+
+    #[test]
+    fn test_hsl_syntaxes_agree() {
+        let expected = Hsl::new(240.0, 5.9, 10.0);
+        assert_eq!(parse("hsl(240, 5.9%, 10%)"), expected);
+        assert_eq!(parse("hsl(240 5.9% 10%)"), expected);
+        assert_eq!(parse("hsl(240deg 5.9% 10%)"), expected);
+        assert_eq!(parse("hsla(240, 5.9%, 10%, 0.5)"), expected.with_alpha(0.5));
+        assert_eq!(parse("hsl(240 5.9% 10% / 50%)"), expected.with_alpha(0.5));
+    }
+
+    #[test]
+    fn test_hex_and_rgb() {
+        let violet = Hsl::new(262.1, 83.3, 57.8);
+        assert_close(parse("#7c3aed"), violet);
+        assert_close(parse("#7C3AEDFF"), violet);
+        assert_close(parse("rgb(124, 58, 237)"), violet);
+        assert_close(parse("rgb(124 58 237 / 40%)"), violet.with_alpha(0.4));
+        assert_close(parse("rgba(124, 58, 237, 0.4)"), violet.with_alpha(0.4));
+        assert_close(parse("#fff"), Hsl::new(0.0, 0.0, 100.0));
+        assert_close(parse("#0008"), Hsl::new(0.0, 0.0, 0.0).with_alpha(0.533));
+    }
+
+    #[test]
+    fn test_oklch() {
+        assert_close(parse("oklch(1 0 0)"), Hsl::new(0.0, 0.0, 100.0));
+        assert_close(parse("oklch(0% 0 0)"), Hsl::new(0.0, 0.0, 0.0));
+        // sRGB red
+        assert_close(
+            parse("oklch(0.628 0.2577 29.23)"),
+            Hsl::new(0.0, 100.0, 50.0),
+        );
+        assert_close(
+            parse("oklch(62.8% 0.2577 29.23 / 0.5)"),
+            Hsl::new(0.0, 100.0, 50.0).with_alpha(0.5),
+        );
+    }
+
+    #[test]
+    fn test_invalid_colors_are_rejected() {
+        for input in [
+            "purple",
+            "#12345",
+            "#ggg",
+            "rgb(1, 2)",
+            "hsl(240 5.9% 10%",
+            "oklch(0.5 0.1)",
+            "cmyk(0, 0, 0, 0)",
+            "hsl(a, b, c)",
+        ] {
+            let err = input.parse::<Hsl>().unwrap_err();
+            assert_eq!(err, ColorParseError(input.to_string()));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Runtime theme switching
+//!
+//! [`ThemeRegistry`] is a gpui global holding named themes with both modes.
+//! [`ThemeSwitch::set_theme`] builds one of them, installs it as the global
+//! [`Theme`] and refreshes every window. With [`ThemeMode::System`], the mode
+//! follows the OS appearance; [`ThemeSwitch::follow_appearance`] keeps it in
+//! sync when the appearance changes.
+
+use std::collections::BTreeMap;
+
+use gpui::{App, Global, Window};
+
+use crate::file::RawThemeFile;
+use crate::{Theme, ThemeError, ThemeFile, ThemeMode, preset_names};
+
+/// Named themes available for switching at runtime.
+///
+/// The default registry contains the built-in presets.
+#[derive(Debug, Clone)]
+pub struct ThemeRegistry {
+    themes: BTreeMap<String, ThemeFile>,
+    active: Option<(String, ThemeMode)>,
+}
+
+impl Global for ThemeRegistry {}
+
+impl Default for ThemeRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for name in preset_names() {
+            if let Some(file) = ThemeFile::from_preset(name) {
+                registry.register(file);
+            }
+        }
+        registry
+    }
+}
+
+impl ThemeRegistry {
+    /// A registry without any themes.
+    pub fn empty() -> Self {
+        Self {
+            themes: BTreeMap::new(),
+            active: None,
+        }
+    }
+
+    /// Add a theme under its `[meta] name`, replacing any theme with that name.
+    pub fn register(&mut self, file: ThemeFile) {
+        self.themes.insert(file.meta.name.clone(), file);
+    }
+
+    /// Parse a TOML theme file and register it. `extends` may name any
+    /// theme already in the registry, not only a preset.
+    pub fn load_toml(&mut self, source: &str) -> Result<&ThemeFile, ThemeError> {
+        let raw: RawThemeFile = toml::from_str(source)?;
+        self.load(raw)
+    }
+
+    /// Parse a JSON theme file and register it, like [`Self::load_toml`].
+    pub fn load_json(&mut self, source: &str) -> Result<&ThemeFile, ThemeError> {
+        let raw: RawThemeFile = serde_json::from_str(source)?;
+        self.load(raw)
+    }
+
+    fn load(&mut self, raw: RawThemeFile) -> Result<&ThemeFile, ThemeError> {
+        let file = raw.resolve(|name| self.get(name).cloned())?;
+        let name = file.meta.name.clone();
+        self.register(file);
+        Ok(&self.themes[&name])
+    }
+
+    /// Names of all registered themes, sorted.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.themes.keys().map(String::as_str)
+    }
+
+    /// Look up a registered theme.
+    pub fn get(&self, name: &str) -> Option<&ThemeFile> {
+        self.themes.get(name)
+    }
+
+    /// Build one mode of a registered theme.
+    pub fn theme(&self, name: &str, mode: ThemeMode) -> Result<Theme, ThemeError> {
+        self.get(name)
+            .map(|file| file.theme(mode))
+            .ok_or_else(|| ThemeError::UnknownTheme(name.to_string()))
+    }
+
+    /// The theme and mode last applied with [`ThemeSwitch::set_theme`].
+    ///
+    /// The mode is the one requested, so it may be [`ThemeMode::System`].
+    pub fn active(&self) -> Option<(&str, ThemeMode)> {
+        self.active
+            .as_ref()
+            .map(|(name, mode)| (name.as_str(), *mode))
+    }
+}
+
+/// Theme switching for gpui apps.
+pub trait ThemeSwitch {
+    /// Apply a registered theme and refresh every window.
+    ///
+    /// [`ThemeMode::System`] resolves against the current OS appearance. The
+    /// density and typography of the current theme, if any, are kept.
+    /// Registers the default [`ThemeRegistry`] if the app has none yet.
+    fn set_theme(&mut self, name: &str, mode: ThemeMode) -> Result<(), ThemeError>;
+
+    /// Re-apply the active theme when `window`'s appearance changes while its
+    /// mode is [`ThemeMode::System`]. Call once per window.
+    fn follow_appearance(&mut self, window: &Window);
+}
+
+impl ThemeSwitch for App {
+    fn set_theme(&mut self, name: &str, mode: ThemeMode) -> Result<(), ThemeError> {
+        let resolved = mode.resolve(self.window_appearance());
+        apply(self, name, mode, resolved)
+    }
+
+    fn follow_appearance(&mut self, window: &Window) {
+        window
+            .observe_window_appearance(|window, cx| {
+                let Some((name, ThemeMode::System)) = cx
+                    .try_global::<ThemeRegistry>()
+                    .and_then(|registry| registry.active.clone())
+                else {
+                    return;
+                };
+                let resolved = ThemeMode::System.resolve(window.appearance());
+                // The theme was applied from this registry, so it still exists
+                apply(cx, &name, ThemeMode::System, resolved).ok();
+            })
+            .detach();
+    }
+}
+
+fn apply(cx: &mut App, name: &str, mode: ThemeMode, resolved: ThemeMode) -> Result<(), ThemeError> {
+    let mut theme = cx.default_global::<ThemeRegistry>().theme(name, resolved)?;
+    if let Some(current) = cx.try_global::<Theme>() {
+        theme.set_density(current.density);
+        theme.typography = current.typography.clone();
+    }
+    cx.global_mut::<ThemeRegistry>().active = Some((name.to_string(), mode));
+    cx.set_global(theme);
+    cx.refresh_windows();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Radius, hsl};
+
+    #[test]
+    fn test_default_registry_has_presets() {
+        let registry = ThemeRegistry::default();
+        let names: Vec<&str> = registry.names().collect();
+        assert_eq!(names.len(), preset_names().len());
+        assert!(names.contains(&"zinc"));
+        assert!(registry.active().is_none());
+    }
+
+    #[test]
+    fn test_theme_builds_requested_mode() {
+        let registry = ThemeRegistry::default();
+        let dark = registry.theme("slate", ThemeMode::Dark).unwrap();
+        assert_eq!(dark.name, "slate");
+        assert_eq!(dark.mode, ThemeMode::Dark);
+        assert!(dark.colors.background.l < 0.1);
+    }
+
+    #[test]
+    fn test_register_custom_theme() {
+        let mut registry = ThemeRegistry::empty();
+        let mut file = ThemeFile::from_preset("zinc").unwrap();
+        file.meta.name = "brand".to_string();
+        file.meta.radius = Radius::Lg;
+        file.light.primary = hsl(262.0, 83.0, 58.0);
+        registry.register(file);
+
+        let theme = registry.theme("brand", ThemeMode::Light).unwrap();
+        assert_eq!(theme.radius, Radius::Lg);
+        assert_eq!(theme.colors.primary, hsl(262.0, 83.0, 58.0));
+        assert!(matches!(
+            registry.theme("zinc", ThemeMode::Light),
+            Err(ThemeError::UnknownTheme(name)) if name == "zinc"
+        ));
+    }
+
+    #[test]
+    fn test_load_toml_extends_registered_theme() {
+        let mut registry = ThemeRegistry::default();
+        registry
+            .load_toml(
+                r#"
+[meta]
+name = "base"
+extends = "zinc"
+radius = "lg"
+
+[light]
+primary = "hsl(262, 83%, 58%)"
+"#,
+            )
+            .unwrap();
+        let brand = registry
+            .load_toml(
+                r#"
+[meta]
+name = "brand"
+extends = "base"
+
+[dark]
+primary = "hsl(263, 70%, 50%)"
+"#,
+            )
+            .unwrap();
+        assert_eq!(brand.meta.radius, Radius::Lg);
+        assert_eq!(brand.light.primary, hsl(262.0, 83.0, 58.0));
+        assert_eq!(brand.dark.primary, hsl(263.0, 70.0, 50.0));
+        assert!(registry.names().any(|name| name == "brand"));
+    }
+
+    #[test]
+    fn test_system_mode_resolves() {
+        assert_eq!(ThemeMode::System.resolve_with(true), ThemeMode::Dark);
+        assert_eq!(ThemeMode::System.resolve_with(false), ThemeMode::Light);
+        assert_eq!(ThemeMode::Light.resolve_with(true), ThemeMode::Light);
+        assert_eq!(ThemeMode::Dark.resolve_with(false), ThemeMode::Dark);
+    }
+
+    #[test]
+    fn test_set_theme_keeps_density_and_typography() {
+        use crate::Density;
+
+        let cx = gpui::TestAppContext::single();
+        cx.update(|cx| cx.set_theme("zinc", ThemeMode::Light))
+            .unwrap();
+        cx.update(|cx| {
+            let theme = cx.global_mut::<Theme>();
+            theme.set_density(Density::Compact);
+            theme.typography.font_family = "Custom Sans".to_string();
+        });
+
+        cx.update(|cx| cx.set_theme("slate", ThemeMode::Dark))
+            .unwrap();
+        cx.read(|cx| {
+            let theme = cx.global::<Theme>();
+            assert_eq!(theme.name, "slate");
+            assert_eq!(theme.density, Density::Compact);
+            assert_eq!(theme.typography.font_family, "Custom Sans");
+        });
+    }
+}
@@ -0,0 +1,423 @@
+//! Typography, spacing, control height, shadow and interaction state tokens
+//!
+//! Sizes are plain pixel values so this module does not depend on gpui.
+//! Spacing and control heights depend on the [`Density`]; the type scale,
+//! shadows and states do not.
+
+use serde::{Deserialize, Serialize};
+
+/// Layout density. Scales spacing and control heights.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    Compact,
+    #[default]
+    Default,
+    Comfortable,
+}
+
+impl Density {
+    /// All densities, tightest first.
+    pub const ALL: [Density; 3] = [Density::Compact, Density::Default, Density::Comfortable];
+
+    /// Look up a density by its lowercase name, as used in config files.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.name() == name)
+    }
+
+    /// Lowercase name, e.g. `"compact"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Density::Compact => "compact",
+            Density::Default => "default",
+            Density::Comfortable => "comfortable",
+        }
+    }
+}
+
+/// Font families and type scale. Sizes are in pixels.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Typography {
+    pub font_family: String,
+    pub mono_font_family: String,
+    pub xs: f32,
+    pub sm: f32,
+    pub base: f32,
+    pub lg: f32,
+    pub xl: f32,
+    pub xxl: f32,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            font_family: ".SystemUIFont".to_string(),
+            mono_font_family: "Menlo".to_string(),
+            xs: 12.0,
+            sm: 14.0,
+            base: 16.0,
+            lg: 18.0,
+            xl: 20.0,
+            xxl: 24.0,
+        }
+    }
+}
+
+/// Spacing scale in pixels, used for padding and gaps.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Spacing {
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub xl: f32,
+}
+
+impl Spacing {
+    /// The spacing scale for a density.
+    pub const fn for_density(density: Density) -> Self {
+        match density {
+            Density::Compact => Self {
+                xs: 2.0,
+                sm: 6.0,
+                md: 8.0,
+                lg: 12.0,
+                xl: 16.0,
+            },
+            Density::Default => Self {
+                xs: 4.0,
+                sm: 8.0,
+                md: 12.0,
+                lg: 16.0,
+                xl: 24.0,
+            },
+            Density::Comfortable => Self {
+                xs: 6.0,
+                sm: 10.0,
+                md: 16.0,
+                lg: 20.0,
+                xl: 32.0,
+            },
+        }
+    }
+}
+
+impl Default for Spacing {
+    fn default() -> Self {
+        Self::for_density(Density::Default)
+    }
+}
+
+/// Heights of interactive controls such as buttons, inputs and selects,
+/// in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ControlHeights {
+    pub xs: f32,
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+}
+
+impl ControlHeights {
+    /// The control heights for a density.
+    pub const fn for_density(density: Density) -> Self {
+        match density {
+            Density::Compact => Self {
+                xs: 20.0,
+                sm: 28.0,
+                md: 32.0,
+                lg: 36.0,
+            },
+            Density::Default => Self {
+                xs: 24.0,
+                sm: 32.0,
+                md: 36.0,
+                lg: 40.0,
+            },
+            Density::Comfortable => Self {
+                xs: 28.0,
+                sm: 36.0,
+                md: 40.0,
+                lg: 44.0,
+            },
+        }
+    }
+}
+
+impl Default for ControlHeights {
+    fn default() -> Self {
+        Self::for_density(Density::Default)
+    }
+}
+
+/// Heights and horizontal padding of toggles and toggle group items, in
+/// pixels. Toggles are square icon buttons at heart, so they run a step
+/// taller than [`ControlHeights`] with their own padding.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ToggleSizes {
+    pub sm: f32,
+    pub md: f32,
+    pub lg: f32,
+    pub padding_sm: f32,
+    pub padding_md: f32,
+    pub padding_lg: f32,
+}
+
+impl ToggleSizes {
+    /// The toggle sizes for a density.
+    pub const fn for_density(density: Density) -> Self {
+        match density {
+            Density::Compact => Self {
+                sm: 32.0,
+                md: 36.0,
+                lg: 40.0,
+                padding_sm: 8.0,
+                padding_md: 10.0,
+                padding_lg: 16.0,
+            },
+            Density::Default => Self {
+                sm: 36.0,
+                md: 40.0,
+                lg: 44.0,
+                padding_sm: 10.0,
+                padding_md: 12.0,
+                padding_lg: 20.0,
+            },
+            Density::Comfortable => Self {
+                sm: 40.0,
+                md: 44.0,
+                lg: 48.0,
+                padding_sm: 12.0,
+                padding_md: 16.0,
+                padding_lg: 24.0,
+            },
+        }
+    }
+}
+
+impl Default for ToggleSizes {
+    fn default() -> Self {
+        Self::for_density(Density::Default)
+    }
+}
+
+/// A drop shadow. Offsets and sizes are in pixels; the color is black at
+/// `opacity`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Shadow {
+    pub offset_y: f32,
+    pub blur: f32,
+    pub spread: f32,
+    pub opacity: f32,
+}
+
+#[cfg(feature = "gpui")]
+impl Shadow {
+    /// The shadow as gpui box shadows, for `Styled::shadow`.
+    pub fn to_box_shadows(self) -> Vec<gpui::BoxShadow> {
+        vec![gpui::BoxShadow {
+            color: gpui::hsla(0.0, 0.0, 0.0, self.opacity),
+            offset: gpui::point(gpui::px(0.0), gpui::px(self.offset_y)),
+            blur_radius: gpui::px(self.blur),
+            spread_radius: gpui::px(self.spread),
+        }]
+    }
+}
+
+/// Elevation shadows, from cards (`sm`) to dialogs and menus (`lg`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Shadows {
+    pub sm: Shadow,
+    pub md: Shadow,
+    pub lg: Shadow,
+}
+
+impl Default for Shadows {
+    fn default() -> Self {
+        Self {
+            sm: Shadow {
+                offset_y: 1.0,
+                blur: 2.0,
+                spread: 0.0,
+                opacity: 0.05,
+            },
+            md: Shadow {
+                offset_y: 4.0,
+                blur: 6.0,
+                spread: -1.0,
+                opacity: 0.1,
+            },
+            lg: Shadow {
+                offset_y: 10.0,
+                blur: 15.0,
+                spread: -3.0,
+                opacity: 0.1,
+            },
+        }
+    }
+}
+
+/// Opacities of a surface color while hovered and while pressed.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StateOpacity {
+    pub hover: f32,
+    pub active: f32,
+}
+
+#[cfg(feature = "gpui")]
+impl StateOpacity {
+    /// `color` as drawn while hovered.
+    pub fn hovered(self, color: gpui::Hsla) -> gpui::Hsla {
+        gpui::Hsla {
+            a: color.a * self.hover,
+            ..color
+        }
+    }
+
+    /// `color` as drawn while pressed.
+    pub fn pressed(self, color: gpui::Hsla) -> gpui::Hsla {
+        gpui::Hsla {
+            a: color.a * self.active,
+            ..color
+        }
+    }
+}
+
+/// Ring drawn around focused controls in the `ring` color, separated from
+/// the control by `offset` pixels of the background color.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FocusRing {
+    pub width: f32,
+    pub offset: f32,
+}
+
+#[cfg(feature = "gpui")]
+impl FocusRing {
+    /// The ring as gpui box shadows, for `Styled::shadow`.
+    pub fn to_box_shadows(self, color: gpui::Hsla, background: gpui::Hsla) -> Vec<gpui::BoxShadow> {
+        let ring = |color, spread: f32| gpui::BoxShadow {
+            color,
+            offset: gpui::point(gpui::px(0.0), gpui::px(0.0)),
+            blur_radius: gpui::px(0.0),
+            spread_radius: gpui::px(spread),
+        };
+        // Shadows paint first to last, so the gap covers the ring's inner part
+        vec![
+            ring(color, self.offset + self.width),
+            ring(background, self.offset),
+        ]
+    }
+}
+
+/// How interactive controls look while hovered, pressed, focused or
+/// disabled.
+///
+/// Filled surfaces fade to their state opacity, e.g. a default button's
+/// `primary` background is drawn at `primary.hover` opacity while hovered.
+/// Transparent controls such as ghost buttons and menu items show `accent`
+/// at the `accent` opacities.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InteractionStates {
+    pub primary: StateOpacity,
+    pub secondary: StateOpacity,
+    pub destructive: StateOpacity,
+    pub accent: StateOpacity,
+    pub focus_ring: FocusRing,
+    /// Opacity of disabled controls.
+    pub disabled_opacity: f32,
+}
+
+impl Default for InteractionStates {
+    fn default() -> Self {
+        Self {
+            primary: StateOpacity {
+                hover: 0.9,
+                active: 0.8,
+            },
+            secondary: StateOpacity {
+                hover: 0.8,
+                active: 0.7,
+            },
+            destructive: StateOpacity {
+                hover: 0.9,
+                active: 0.8,
+            },
+            accent: StateOpacity {
+                hover: 1.0,
+                active: 0.8,
+            },
+            focus_ring: FocusRing {
+                width: 2.0,
+                offset: 2.0,
+            },
+            disabled_opacity: 0.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_density_names_round_trip() {
+        for density in Density::ALL {
+            assert_eq!(Density::from_name(density.name()), Some(density));
+        }
+        assert_eq!(Density::from_name("cozy"), None);
+    }
+
+    #[test]
+    fn test_density_scales_increase() {
+        let [compact, default, comfortable] = Density::ALL.map(ControlHeights::for_density);
+        assert!(compact.md < default.md && default.md < comfortable.md);
+        let [compact, default, comfortable] = Density::ALL.map(Spacing::for_density);
+        assert!(compact.md < default.md && default.md < comfortable.md);
+        let [compact, default, comfortable] = Density::ALL.map(ToggleSizes::for_density);
+        assert!(compact.md < default.md && default.md < comfortable.md);
+    }
+
+    #[test]
+    fn test_default_toggle_sizes_match_original_components() {
+        let toggles = ToggleSizes::default();
+        assert_eq!([toggles.sm, toggles.md, toggles.lg], [36.0, 40.0, 44.0]);
+        assert_eq!(
+            [toggles.padding_sm, toggles.padding_md, toggles.padding_lg],
+            [10.0, 12.0, 20.0]
+        );
+    }
+
+    #[cfg(feature = "gpui")]
+    #[test]
+    fn test_state_opacity_scales_alpha() {
+        let states = InteractionStates::default();
+        let primary = gpui::hsla(0.67, 0.06, 0.1, 1.0);
+        assert_eq!(states.primary.hovered(primary).a, 0.9);
+        assert_eq!(states.primary.pressed(primary).l, primary.l);
+        let translucent = gpui::hsla(0.0, 0.0, 0.0, 0.5);
+        assert_eq!(states.secondary.pressed(translucent).a, 0.35);
+    }
+
+    #[cfg(feature = "gpui")]
+    #[test]
+    fn test_focus_ring_leaves_offset_gap() {
+        let (ring, background) = (
+            gpui::hsla(0.6, 0.9, 0.6, 1.0),
+            gpui::hsla(0.0, 0.0, 1.0, 1.0),
+        );
+        let shadows = InteractionStates::default()
+            .focus_ring
+            .to_box_shadows(ring, background);
+        assert_eq!(shadows.len(), 2);
+        assert_eq!(
+            (shadows[0].color, shadows[0].spread_radius),
+            (ring, gpui::px(4.0))
+        );
+        assert_eq!(
+            (shadows[1].color, shadows[1].spread_radius),
+            (background, gpui::px(2.0))
+        );
+    }
+}
@@ -0,0 +1,167 @@
+//! Scoped themes for parts of a window
+//!
+//! [`ThemeScope`] wraps an element and provides a different [`Theme`] to
+//! everything inside it, e.g. a dark code preview in a light window.
+//! Components resolve their theme with [`Theme::of`], which returns the
+//! innermost scope's theme and falls back to the global one. Text inside a
+//! scope uses its theme's `typography.font_family`.
+//!
+//! Scopes are kept in a gpui global while the wrapped element lays out,
+//! prepaints and paints. Content drawn in a deferred layer, such as an open
+//! popover, is laid out after the scope ends and uses the global theme.
+
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, TextStyleRefinement, Window,
+};
+
+use crate::Theme;
+
+/// Themes provided by the [`ThemeScope`]s being drawn, innermost last.
+#[derive(Debug, Default)]
+struct ThemeScopes(Vec<Theme>);
+
+impl Global for ThemeScopes {}
+
+impl ThemeScopes {
+    fn current(&self) -> Option<&Theme> {
+        self.0.last()
+    }
+}
+
+/// An element that draws its child with a different theme.
+pub struct ThemeScope {
+    theme: Option<Theme>,
+    child: AnyElement,
+}
+
+impl ThemeScope {
+    /// Draw `child` with `theme`.
+    pub fn new(theme: Theme, child: impl IntoElement) -> Self {
+        Self {
+            theme: Some(theme),
+            child: child.into_any_element(),
+        }
+    }
+
+    /// Draw `child` with the current theme changed by `f`, e.g. to switch
+    /// density or override a few slots.
+    pub fn modify(cx: &App, f: impl FnOnce(&mut Theme), child: impl IntoElement) -> Self {
+        let mut theme = Theme::of(cx).clone();
+        f(&mut theme);
+        Self::new(theme, child)
+    }
+
+    /// Run `f` on the child with this scope's theme pushed and its UI font
+    /// applied to text.
+    fn scoped<R>(
+        &mut self,
+        window: &mut Window,
+        cx: &mut App,
+        f: impl FnOnce(&mut AnyElement, &mut Window, &mut App) -> R,
+    ) -> R {
+        let Some(theme) = self.theme.take() else {
+            return f(&mut self.child, window, cx);
+        };
+        let text_style = TextStyleRefinement {
+            font_family: Some(theme.typography.font_family.clone().into()),
+            ..Default::default()
+        };
+        cx.default_global::<ThemeScopes>().0.push(theme);
+        let result =
+            window.with_text_style(Some(text_style), |window| f(&mut self.child, window, cx));
+        self.theme = cx.global_mut::<ThemeScopes>().0.pop();
+        result
+    }
+}
+
+impl IntoElement for ThemeScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for ThemeScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // Components render during layout, so they see the scoped theme here
+        let layout_id = self.scoped(window, cx, |child, window, cx| {
+            child.request_layout(window, cx)
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.scoped(window, cx, |child, window, cx| {
+            child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.scoped(window, cx, |child, window, cx| child.paint(window, cx));
+    }
+}
+
+impl Theme {
+    /// The theme to draw with: the innermost [`ThemeScope`]'s theme, or the
+    /// global theme outside any scope.
+    pub fn of(cx: &App) -> &Theme {
+        cx.try_global::<ThemeScopes>()
+            .and_then(ThemeScopes::current)
+            .unwrap_or_else(|| cx.global::<Theme>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ThemeMode, zinc};
+
+    #[test]
+    fn test_innermost_scope_wins() {
+        let mut scopes = ThemeScopes::default();
+        assert!(scopes.current().is_none());
+
+        scopes.0.push(zinc(ThemeMode::Dark));
+        scopes.0.push(zinc(ThemeMode::Light));
+        assert_eq!(scopes.current().map(|t| t.mode), Some(ThemeMode::Light));
+
+        scopes.0.pop();
+        assert_eq!(scopes.current().map(|t| t.mode), Some(ThemeMode::Dark));
+    }
+}
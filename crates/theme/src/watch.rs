@@ -0,0 +1,275 @@
+//! Theme hot reload during development
+//!
+//! [`ThemeWatcher`] polls a theme file and, whenever its contents change,
+//! loads it into the [`ThemeRegistry`] and re-applies it with
+//! [`ThemeSwitch::set_theme`], so edits to `themes/acme.toml` show up in a
+//! running app without a rebuild. Polling also follows editors that save by
+//! replacing the file, which file system events would lose track of.
+//!
+//! Apps whose components read the `Theme` in a generated `theme.rs` use
+//! [`ThemeWatcher::on_reload`] to convert the file into that type instead;
+//! `shadcn-ui` generates a `watch_theme_file` that does this.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use gpui::{App, Task};
+
+use crate::{Theme, ThemeError, ThemeFile, ThemeMode, ThemeRegistry, ThemeSwitch};
+
+/// Handles a loaded theme file, see [`ThemeWatcher::on_reload`].
+type ReloadHandler = Box<dyn Fn(&ThemeFile, &mut App) -> Result<(), ThemeError>>;
+
+/// Reloads a TOML or JSON theme file into a running app when it changes.
+pub struct ThemeWatcher {
+    path: PathBuf,
+    interval: Duration,
+    on_error: Box<dyn Fn(&ThemeError)>,
+    on_reload: ReloadHandler,
+    contents: Option<String>,
+    missing: Option<Missing>,
+}
+
+/// When a previously loaded file went missing, and whether that has been
+/// reported.
+#[derive(Debug, Clone, Copy)]
+struct Missing {
+    since: Instant,
+    reported: bool,
+}
+
+impl ThemeWatcher {
+    /// How often the file is checked unless set with [`Self::interval`].
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// How long a loaded file may be missing before it is reported. Editors
+    /// that save by deleting and recreating the file leave a short gap.
+    pub const MISSING_GRACE: Duration = Duration::from_secs(2);
+
+    /// Watch `path`. Files ending in `.json` are read as JSON, others as
+    /// TOML. Errors are printed to stderr unless set with [`Self::on_error`].
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let display = path.display().to_string();
+        Self {
+            path,
+            interval: Self::DEFAULT_INTERVAL,
+            on_error: Box::new(move |err| eprintln!("{}: {}", display, err)),
+            on_reload: Box::new(apply),
+            contents: None,
+            missing: None,
+        }
+    }
+
+    /// Check the file every `interval`.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Report read and parse errors to `f` instead of stderr. The previous
+    /// theme stays applied until the file loads again.
+    pub fn on_error(mut self, f: impl Fn(&ThemeError) + 'static) -> Self {
+        self.on_error = Box::new(f);
+        self
+    }
+
+    /// Call `f` with each loaded file instead of applying it as this
+    /// crate's [`Theme`], e.g. to convert it into a generated theme type.
+    /// The file is still registered in this crate's [`ThemeRegistry`], so
+    /// `extends` can refer to it. Errors go to the error handler.
+    pub fn on_reload(
+        mut self,
+        f: impl Fn(&ThemeFile, &mut App) -> Result<(), ThemeError> + 'static,
+    ) -> Self {
+        self.on_reload = Box::new(f);
+        self
+    }
+
+    /// Apply the theme now and again whenever the file changes.
+    ///
+    /// The theme is registered under its `[meta] name` and applied in the
+    /// active mode. While another registered theme is active, changes only
+    /// update the registry. Watching stops when the returned task is
+    /// dropped; call [`Task::detach`] to watch for the life of the app.
+    pub fn watch(mut self, cx: &mut App) -> Task<()> {
+        if let Err(err) = self.reload(cx) {
+            (self.on_error)(&err);
+        }
+        cx.spawn(async move |cx| {
+            loop {
+                cx.background_executor().timer(self.interval).await;
+                match cx.update(|cx| self.reload(cx)) {
+                    Ok(Ok(())) => {}
+                    Ok(Err(err)) => (self.on_error)(&err),
+                    // The app has quit
+                    Err(_) => break,
+                }
+            }
+        })
+    }
+
+    /// Load the file and pass it to the reload handler if it changed since
+    /// the last call.
+    fn reload(&mut self, cx: &mut App) -> Result<(), ThemeError> {
+        let Some(source) = self.poll()? else {
+            return Ok(());
+        };
+        let registry = cx.default_global::<ThemeRegistry>();
+        let file = if self.path.extension().is_some_and(|ext| ext == "json") {
+            registry.load_json(&source)?
+        } else {
+            registry.load_toml(&source)?
+        }
+        .clone();
+        (self.on_reload)(&file, cx)
+    }
+
+    /// The file's contents if they changed since the last call.
+    ///
+    /// Once the file has loaded, a missing file is only reported after
+    /// [`Self::MISSING_GRACE`], and then once until it comes back.
+    fn poll(&mut self) -> Result<Option<String>, ThemeError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(source)
+                if source.kind() == std::io::ErrorKind::NotFound && self.contents.is_some() =>
+            {
+                let missing = self.missing.get_or_insert(Missing {
+                    since: Instant::now(),
+                    reported: false,
+                });
+                if missing.reported || missing.since.elapsed() < Self::MISSING_GRACE {
+                    return Ok(None);
+                }
+                missing.reported = true;
+                return Err(ThemeError::Read {
+                    path: self.path.clone(),
+                    source,
+                });
+            }
+            Err(source) => {
+                return Err(ThemeError::Read {
+                    path: self.path.clone(),
+                    source,
+                });
+            }
+        };
+        self.missing = None;
+        if self.contents.as_ref() == Some(&contents) {
+            return Ok(None);
+        }
+        self.contents = Some(contents.clone());
+        Ok(Some(contents))
+    }
+}
+
+/// Register `file` and apply it as this crate's [`Theme`] if it is the
+/// active theme, or if no theme is active yet.
+fn apply(file: &ThemeFile, cx: &mut App) -> Result<(), ThemeError> {
+    let name = file.meta.name.clone();
+    let mode = match cx.default_global::<ThemeRegistry>().active() {
+        Some((active, mode)) if active == name => mode,
+        Some(_) => return Ok(()),
+        None => cx
+            .try_global::<Theme>()
+            .map_or(ThemeMode::Light, |t| t.mode),
+    };
+    cx.set_theme(&name, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll_reports_changes_once() {
+        let path = std::env::temp_dir().join(format!("theme-watch-{}.toml", std::process::id()));
+        std::fs::write(&path, "[meta]\nname = \"a\"\n").unwrap();
+        let mut watcher = ThemeWatcher::new(&path);
+
+        assert!(watcher.poll().unwrap().is_some());
+        assert!(watcher.poll().unwrap().is_none());
+
+        std::fs::write(&path, "[meta]\nname = \"b\"\n").unwrap();
+        assert_eq!(
+            watcher.poll().unwrap().as_deref(),
+            Some("[meta]\nname = \"b\"\n")
+        );
+
+        // A replaced file is briefly missing; that is only reported once
+        // it stays missing past the grace period.
+        std::fs::remove_file(&path).unwrap();
+        assert!(watcher.poll().unwrap().is_none());
+        watcher.missing = Some(Missing {
+            since: Instant::now() - ThemeWatcher::MISSING_GRACE,
+            reported: false,
+        });
+        assert!(matches!(watcher.poll(), Err(ThemeError::Read { .. })));
+        assert!(watcher.poll().unwrap().is_none());
+
+        std::fs::write(&path, "[meta]\nname = \"c\"\n").unwrap();
+        assert!(watcher.poll().unwrap().is_some());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_poll_reports_file_missing_from_the_start() {
+        let path = std::env::temp_dir().join("theme-watch-does-not-exist.toml");
+        let mut watcher = ThemeWatcher::new(&path);
+        assert!(matches!(watcher.poll(), Err(ThemeError::Read { .. })));
+    }
+
+    #[test]
+    fn test_reload_applies_the_file() {
+        let path = std::env::temp_dir().join(format!("theme-reload-{}.toml", std::process::id()));
+        let source = |color: &str| {
+            format!(
+                "[meta]\nname = \"watched\"\nextends = \"zinc\"\n\n[light]\nbackground = \"{color}\"\n"
+            )
+        };
+        std::fs::write(&path, source("#ff0000")).unwrap();
+        let cx = gpui::TestAppContext::single();
+        let mut watcher = ThemeWatcher::new(&path);
+
+        cx.update(|cx| watcher.reload(cx)).unwrap();
+        let red = cx.read(|cx| cx.global::<Theme>().colors.background);
+        assert_eq!(cx.read(|cx| cx.global::<Theme>().mode), ThemeMode::Light);
+
+        std::fs::write(&path, source("#0000ff")).unwrap();
+        cx.update(|cx| watcher.reload(cx)).unwrap();
+        let blue = cx.read(|cx| cx.global::<Theme>().colors.background);
+        assert_ne!(red, blue);
+        assert_eq!(
+            cx.read(|cx| cx
+                .global::<ThemeRegistry>()
+                .active()
+                .map(|(name, _)| name.to_string())),
+            Some("watched".to_string())
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reload_calls_the_reload_handler() {
+        let path = std::env::temp_dir().join(format!("theme-handler-{}.toml", std::process::id()));
+        std::fs::write(&path, "[meta]\nname = \"handled\"\nextends = \"zinc\"\n").unwrap();
+        let loaded = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+        let cx = gpui::TestAppContext::single();
+        let mut watcher = ThemeWatcher::new(&path).on_reload({
+            let loaded = loaded.clone();
+            move |file, _| {
+                loaded.borrow_mut().push(file.meta.name.clone());
+                Ok(())
+            }
+        });
+
+        cx.update(|cx| watcher.reload(cx)).unwrap();
+        cx.update(|cx| watcher.reload(cx)).unwrap();
+        assert_eq!(*loaded.borrow(), ["handled"]);
+        // The handler replaces applying the crate theme
+        assert!(cx.read(|cx| cx.try_global::<Theme>().is_none()));
+        assert!(cx.read(|cx| { cx.global::<ThemeRegistry>().get("handled").is_some() }));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -96,10 +96,10 @@ pub fn zinc(mode: ThemeMode) -> Theme {
             secondary: hsl(240.0, 4.8, 95.9),
             secondary_foreground: hsl(240.0, 5.9, 10.0),
             muted: hsl(240.0, 4.8, 95.9),
-            muted_foreground: hsl(240.0, 3.8, 46.1),
+            muted_foreground: hsl(240.0, 3.8, 45.0),
             accent: hsl(240.0, 4.8, 95.9),
             accent_foreground: hsl(240.0, 5.9, 10.0),
-            destructive: hsl(0.0, 84.2, 60.2),
+            destructive: hsl(0.0, 72.2, 50.6),
             destructive_foreground: hsl(0.0, 0.0, 98.0),
             border: hsl(240.0, 5.9, 90.0),
             input: hsl(240.0, 5.9, 90.0),